@@ -1,23 +1,37 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply, Response,
-    StdError, StdResult, SubMsg,
+    from_json, to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order,
+    Reply, Response, StdError, StdResult, Storage, SubMsg, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version};
+use cw_storage_plus::{Bound, Map};
 use cw_utils::{parse_reply_instantiate_data, Duration};
 
 use cw_paginate::{paginate_map, paginate_map_values};
 use cwd_interface::{voting, ModuleInstantiateInfo};
+use exec_control::pause::{PauseInfoResponse as SubDaoPauseInfoResponse, MAX_PAUSE_DURATION};
 use neutron_sdk::bindings::msg::NeutronMsg;
+use neutron_subdao_core::msg::QueryMsg as SubDaoQueryMsg;
 use neutron_subdao_core::types::SubDao;
+use neutron_subdao_timelock_single::msg::QueryMsg as TimelockQueryMsg;
+use neutron_subdao_timelock_single::types::ProposalStatus as TimelockProposalStatus;
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::Serialize;
 
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InitialItem, InstantiateMsg, MigrateMsg, QueryMsg};
-use crate::query::{DumpStateResponse, GetItemResponse, PauseInfoResponse};
+use crate::query::{
+    DumpStateResponse, GetItemResponse, GetTypedItemResponse, PauseInfoResponse,
+    SubDaoOverviewResponse, TimelockOverview,
+};
 use crate::state::{
-    Config, ProposalModule, ProposalModuleStatus, ACTIVE_PROPOSAL_MODULE_COUNT, CONFIG, ITEMS,
-    PAUSED, PROPOSAL_MODULES, SUBDAO_LIST, TOTAL_PROPOSAL_MODULE_COUNT, VOTING_REGISTRY_MODULE,
+    Config, ExecutionDelay, ItemSchema, ItemValue, PauseScope, ProposalModule,
+    ProposalModuleStatus, QueuedExecution, QueuedExecutionStatus, ScopedPause, SubDaoMetadata,
+    ACTIVE_PROPOSAL_MODULE_COUNT, CONFIG, EXECUTING_PROPOSAL_MODULES, EXECUTION_DELAY, ITEMS,
+    ITEM_SCHEMAS, PAUSED, PAUSED_SCOPES, PAUSE_AUTHORITY, PROPOSAL_MODULES, QUEUED_EXECUTIONS,
+    QUEUED_EXECUTION_COUNT, SUBDAO_LIST, SUBDAO_METADATA, TOTAL_PROPOSAL_MODULE_COUNT, TYPED_ITEMS,
+    VOTING_REGISTRY_MODULE,
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:cwd-core";
@@ -26,6 +40,7 @@ pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const PROPOSAL_MODULE_REPLY_ID: u64 = 0;
 const VOTE_MODULE_INSTANTIATE_REPLY_ID: u64 = 1;
 const VOTE_MODULE_UPDATE_REPLY_ID: u64 = 2;
+const PROPOSAL_MESSAGES_REPLY_ID: u64 = 3;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -81,20 +96,65 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response<NeutronMsg>, ContractError> {
-    // No actions can be performed while the DAO is paused.
+    // No actions can be performed while the DAO is paused, except for
+    // vetoes, or the veto window of queued messages could lapse during the
+    // pause.
     if let Some(expiration) = PAUSED.may_load(deps.storage)? {
-        if !expiration.is_expired(&env.block) {
+        if !expiration.is_expired(&env.block) && !matches!(msg, ExecuteMsg::VetoQueued { .. }) {
             return Err(ContractError::Paused {});
         }
     }
 
+    // Parts of the DAO can also be paused on their own. Pauses that depend
+    // on the proposal module are checked by the handlers. Proposals that
+    // only lift pauses get through, so the DAO can't be locked out by the
+    // pause authority.
+    let pause_recovery = match &msg {
+        ExecuteMsg::ExecuteProposalHook { msgs } => is_pause_recovery(&env, msgs),
+        ExecuteMsg::ExecuteQueued { id } => QUEUED_EXECUTIONS
+            .may_load(deps.storage, *id)?
+            .map_or(false, |queued| is_pause_recovery(&env, &queued.msgs)),
+        _ => false,
+    };
+    let scope = match &msg {
+        ExecuteMsg::ExecuteProposalHook { .. } | ExecuteMsg::ExecuteQueued { .. }
+            if !pause_recovery =>
+        {
+            Some(PauseScope::ProposalExecution)
+        }
+        ExecuteMsg::UpdateSubDaos { .. } | ExecuteMsg::UpdateSubDaoMetadata { .. } => {
+            Some(PauseScope::SubDaoRegistry)
+        }
+        ExecuteMsg::SetItem { .. }
+        | ExecuteMsg::RemoveItem { .. }
+        | ExecuteMsg::SetItemSchema { .. }
+        | ExecuteMsg::SetTypedItem { .. } => Some(PauseScope::Items),
+        _ => None,
+    };
+    if let Some(scope) = scope {
+        check_scope_not_paused(deps.as_ref(), &env, &scope)?;
+    }
+
     match msg {
         ExecuteMsg::ExecuteProposalHook { msgs } => {
-            execute_proposal_hook(deps.as_ref(), info.sender, msgs)
+            execute_proposal_hook(deps, env, info.sender, msgs)
         }
         ExecuteMsg::Pause { duration } => execute_pause(deps, env, info.sender, duration),
+        ExecuteMsg::PauseScope { scope, duration } => {
+            execute_pause_scope(deps, env, info.sender, scope, duration)
+        }
+        ExecuteMsg::UnpauseScope { scope } => execute_unpause_scope(deps, env, info.sender, scope),
+        ExecuteMsg::UpdatePauseAuthority { authority } => {
+            execute_update_pause_authority(deps, env, info.sender, authority)
+        }
         ExecuteMsg::RemoveItem { key } => execute_remove_item(deps, env, info.sender, key),
         ExecuteMsg::SetItem { key, addr } => execute_set_item(deps, env, info.sender, key, addr),
+        ExecuteMsg::SetItemSchema { key, schema } => {
+            execute_set_item_schema(deps, env, info.sender, key, schema)
+        }
+        ExecuteMsg::SetTypedItem { key, value } => {
+            execute_set_typed_item(deps, env, info.sender, key, value)
+        }
         ExecuteMsg::UpdateConfig { config } => {
             execute_update_config(deps, env, info.sender, config)
         }
@@ -107,6 +167,25 @@ pub fn execute(
         ExecuteMsg::UpdateSubDaos { to_add, to_remove } => {
             execute_update_sub_daos_list(deps, env, info.sender, to_add, to_remove)
         }
+        ExecuteMsg::UpdateSubDaoMetadata {
+            subdao,
+            timelocks,
+            proposal_modules,
+            parent,
+        } => execute_update_sub_dao_metadata(
+            deps,
+            env,
+            info.sender,
+            subdao,
+            timelocks,
+            proposal_modules,
+            parent,
+        ),
+        ExecuteMsg::UpdateExecutionDelay { execution_delay } => {
+            execute_update_execution_delay(deps, env, info.sender, execution_delay)
+        }
+        ExecuteMsg::ExecuteQueued { id } => execute_execute_queued(deps, env, info.sender, id),
+        ExecuteMsg::VetoQueued { id } => execute_veto_queued(deps, env, info.sender, id),
     }
 }
 
@@ -130,8 +209,129 @@ pub fn execute_pause(
         .add_attribute("until", until.to_string()))
 }
 
+pub fn execute_pause_scope(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    scope: PauseScope,
+    pause_duration: Duration,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    check_can_pause_scope(deps.as_ref(), &env, &sender)?;
+
+    // Only the DAO itself may pause scopes for longer, the pause authority
+    // is limited like the pauses of the subDAOs are.
+    if sender != env.contract.address {
+        match pause_duration {
+            Duration::Height(height) if height <= MAX_PAUSE_DURATION => {}
+            _ => {
+                return Err(ContractError::InvalidScopePauseDuration {
+                    max: MAX_PAUSE_DURATION,
+                })
+            }
+        }
+    }
+
+    let expiration = pause_duration.after(&env.block);
+    PAUSED_SCOPES.save(
+        deps.storage,
+        scope.key(),
+        &ScopedPause {
+            scope: scope.clone(),
+            expiration,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_pause_scope")
+        .add_attribute("sender", sender)
+        .add_attribute("scope", scope.key())
+        .add_attribute("until", expiration.to_string()))
+}
+
+pub fn execute_unpause_scope(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    scope: PauseScope,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    check_can_pause_scope(deps.as_ref(), &env, &sender)?;
+
+    PAUSED_SCOPES.remove(deps.storage, scope.key());
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_unpause_scope")
+        .add_attribute("sender", sender)
+        .add_attribute("scope", scope.key()))
+}
+
+pub fn execute_update_pause_authority(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    authority: Option<String>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    if sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    match authority {
+        Some(authority) => {
+            let authority = deps.api.addr_validate(&authority)?;
+            PAUSE_AUTHORITY.save(deps.storage, &authority)?;
+            Ok(Response::new()
+                .add_attribute("action", "execute_update_pause_authority")
+                .add_attribute("authority", authority))
+        }
+        None => {
+            PAUSE_AUTHORITY.remove(deps.storage);
+            Ok(Response::new()
+                .add_attribute("action", "execute_update_pause_authority")
+                .add_attribute("authority", "None"))
+        }
+    }
+}
+
+/// Scopes can be paused by the DAO itself and by the pause authority.
+fn check_can_pause_scope(deps: Deps, env: &Env, sender: &Addr) -> Result<(), ContractError> {
+    if *sender == env.contract.address {
+        return Ok(());
+    }
+    match PAUSE_AUTHORITY.may_load(deps.storage)? {
+        Some(authority) if authority == *sender => Ok(()),
+        _ => Err(ContractError::Unauthorized {}),
+    }
+}
+
+/// Returns whether all `msgs` are `UnpauseScope` or `UpdatePauseAuthority`
+/// messages to the DAO itself.
+fn is_pause_recovery(env: &Env, msgs: &[CosmosMsg<NeutronMsg>]) -> bool {
+    !msgs.is_empty()
+        && msgs.iter().all(|msg| match msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr,
+                msg,
+                funds,
+            }) if *contract_addr == env.contract.address && funds.is_empty() => matches!(
+                from_json::<ExecuteMsg>(msg),
+                Ok(ExecuteMsg::UnpauseScope { .. } | ExecuteMsg::UpdatePauseAuthority { .. })
+            ),
+            _ => false,
+        })
+}
+
+fn check_scope_not_paused(deps: Deps, env: &Env, scope: &PauseScope) -> Result<(), ContractError> {
+    if let Some(paused) = PAUSED_SCOPES.may_load(deps.storage, scope.key())? {
+        if !paused.expiration.is_expired(&env.block) {
+            return Err(ContractError::ScopePaused { scope: scope.key() });
+        }
+    }
+
+    Ok(())
+}
+
 pub fn execute_proposal_hook(
-    deps: Deps,
+    deps: DepsMut,
+    env: Env,
     sender: Addr,
     msgs: Vec<CosmosMsg<NeutronMsg>>,
 ) -> Result<Response<NeutronMsg>, ContractError> {
@@ -143,10 +343,180 @@ pub fn execute_proposal_hook(
     if module.status != ProposalModuleStatus::Enabled {
         return Err(ContractError::ModuleDisabledCannotExecute { address: sender });
     }
+    if !is_pause_recovery(&env, &msgs) {
+        check_scope_not_paused(
+            deps.as_ref(),
+            &env,
+            &PauseScope::ProposalModule {
+                address: sender.clone(),
+            },
+        )?;
+    }
+
+    let execution_delay = match EXECUTION_DELAY.may_load(deps.storage)? {
+        Some(execution_delay) => execution_delay,
+        None => {
+            let msgs = proposal_messages(deps.storage, &sender, msgs)?;
+            return Ok(Response::default()
+                .add_attribute("action", "execute_proposal_hook")
+                .add_submessages(msgs));
+        }
+    };
+
+    let id = QUEUED_EXECUTION_COUNT
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let queued = QueuedExecution {
+        id,
+        proposal_module: sender,
+        security_dao: execution_delay.security_dao,
+        msgs,
+        earliest_execution: execution_delay.delay.after(&env.block),
+        status: QueuedExecutionStatus::Queued,
+    };
+    QUEUED_EXECUTIONS.save(deps.storage, id, &queued)?;
+    QUEUED_EXECUTION_COUNT.save(deps.storage, &(id + 1))?;
 
     Ok(Response::default()
         .add_attribute("action", "execute_proposal_hook")
-        .add_messages(msgs))
+        .add_attribute("queued_execution_id", id.to_string())
+        .add_attribute("earliest_execution", queued.earliest_execution.to_string()))
+}
+
+pub fn execute_update_execution_delay(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    execution_delay: Option<ExecutionDelay>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    match execution_delay {
+        Some(execution_delay) => {
+            if matches!(
+                execution_delay.delay,
+                Duration::Height(0) | Duration::Time(0)
+            ) {
+                return Err(ContractError::ZeroExecutionDelay {});
+            }
+            deps.api
+                .addr_validate(execution_delay.security_dao.as_str())?;
+            EXECUTION_DELAY.save(deps.storage, &execution_delay)?;
+            Ok(Response::default()
+                .add_attribute("action", "execute_update_execution_delay")
+                .add_attribute("delay", execution_delay.delay.to_string())
+                .add_attribute("security_dao", execution_delay.security_dao))
+        }
+        None => {
+            EXECUTION_DELAY.remove(deps.storage);
+            Ok(Response::default()
+                .add_attribute("action", "execute_update_execution_delay")
+                .add_attribute("delay", "None"))
+        }
+    }
+}
+
+pub fn execute_execute_queued(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    id: u64,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let mut queued = load_pending_queued_execution(deps.as_ref(), id)?;
+    if !queued.earliest_execution.is_expired(&env.block) {
+        return Err(ContractError::ExecutionDelayNotElapsed { id });
+    }
+    // The module may have been disabled since it queued the messages
+    let module_enabled = PROPOSAL_MODULES
+        .may_load(deps.storage, queued.proposal_module.clone())?
+        .map_or(false, |module| {
+            module.status == ProposalModuleStatus::Enabled
+        });
+    if !module_enabled {
+        return Err(ContractError::ModuleDisabledCannotExecute {
+            address: queued.proposal_module,
+        });
+    }
+    if !is_pause_recovery(&env, &queued.msgs) {
+        check_scope_not_paused(
+            deps.as_ref(),
+            &env,
+            &PauseScope::ProposalModule {
+                address: queued.proposal_module.clone(),
+            },
+        )?;
+    }
+
+    queued.status = QueuedExecutionStatus::Executed;
+    QUEUED_EXECUTIONS.save(deps.storage, id, &queued)?;
+    let msgs = proposal_messages(deps.storage, &queued.proposal_module, queued.msgs)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_execute_queued")
+        .add_attribute("sender", sender)
+        .add_attribute("id", id.to_string())
+        .add_submessages(msgs))
+}
+
+/// Wraps the messages of a proposal of `module`. The module is recorded
+/// until the last message succeeds, so that the item handlers can check
+/// their writers against it however the item messages are sent.
+fn proposal_messages(
+    storage: &mut dyn Storage,
+    module: &Addr,
+    msgs: Vec<CosmosMsg<NeutronMsg>>,
+) -> StdResult<Vec<SubMsg<NeutronMsg>>> {
+    let mut msgs: Vec<SubMsg<NeutronMsg>> = msgs.into_iter().map(SubMsg::new).collect();
+    if let Some(last) = msgs.pop() {
+        let mut modules = EXECUTING_PROPOSAL_MODULES
+            .may_load(storage)?
+            .unwrap_or_default();
+        modules.push(module.clone());
+        EXECUTING_PROPOSAL_MODULES.save(storage, &modules)?;
+        msgs.push(SubMsg::reply_on_success(
+            last.msg,
+            PROPOSAL_MESSAGES_REPLY_ID,
+        ));
+    }
+    Ok(msgs)
+}
+
+pub fn execute_veto_queued(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    id: u64,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    // The veto right stays with the security subDAO the messages were
+    // queued under, even if the execution delay has changed since.
+    let mut queued = load_pending_queued_execution(deps.as_ref(), id)?;
+    if queued.security_dao != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    if queued.earliest_execution.is_expired(&env.block) {
+        return Err(ContractError::ExecutionDelayElapsed { id });
+    }
+
+    queued.status = QueuedExecutionStatus::Vetoed;
+    QUEUED_EXECUTIONS.save(deps.storage, id, &queued)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_veto_queued")
+        .add_attribute("sender", sender)
+        .add_attribute("id", id.to_string()))
+}
+
+fn load_pending_queued_execution(deps: Deps, id: u64) -> Result<QueuedExecution, ContractError> {
+    let queued = QUEUED_EXECUTIONS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::QueuedExecutionDoesNotExist { id })?;
+    if queued.status != QueuedExecutionStatus::Queued {
+        return Err(ContractError::QueuedExecutionNotPending { id });
+    }
+
+    Ok(queued)
 }
 
 pub fn execute_update_config(
@@ -255,6 +625,10 @@ pub fn execute_set_item(
         return Err(ContractError::Unauthorized {});
     }
 
+    if ITEM_SCHEMAS.has(deps.storage, key.clone()) {
+        return Err(ContractError::ItemIsTyped { key });
+    }
+
     ITEMS.save(deps.storage, key.clone(), &value)?;
     Ok(Response::default()
         .add_attribute("action", "execute_set_item")
@@ -272,16 +646,194 @@ pub fn execute_remove_item(
         return Err(ContractError::Unauthorized {});
     }
 
+    check_item_writer(deps.as_ref(), &key)?;
+
     if ITEMS.has(deps.storage, key.clone()) {
         ITEMS.remove(deps.storage, key.clone());
-        Ok(Response::default()
-            .add_attribute("action", "execute_remove_item")
-            .add_attribute("key", key))
+    } else if TYPED_ITEMS.has(deps.storage, key.clone()) {
+        TYPED_ITEMS.remove(deps.storage, key.clone());
     } else {
-        Err(ContractError::KeyMissing {})
+        return Err(ContractError::KeyMissing {});
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_remove_item")
+        .add_attribute("key", key))
+}
+
+pub fn execute_set_item_schema(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    key: String,
+    schema: Option<ItemSchema>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let current = check_item_writer(deps.as_ref(), &key)?;
+
+    // A key is either untyped or typed, so the schema can only be changed if
+    // the current value, if any, stays valid.
+    let value = TYPED_ITEMS.may_load(deps.storage, key.clone())?;
+    match schema {
+        Some(schema) => {
+            if ITEMS.has(deps.storage, key.clone()) {
+                return Err(ContractError::ItemSchemaConflict { key });
+            }
+            if let Some(value) = value {
+                if value.item_type() != schema.item_type {
+                    return Err(ContractError::ItemSchemaConflict { key });
+                }
+            }
+            for writer in schema.writers.iter().flatten() {
+                deps.api.addr_validate(writer.as_str())?;
+            }
+            if let Some(writers) = &schema.writers {
+                check_writers_kept(deps.as_ref(), &key, current, writers)?;
+            }
+            ITEM_SCHEMAS.save(deps.storage, key.clone(), &schema)?;
+            Ok(Response::default()
+                .add_attribute("action", "execute_set_item_schema")
+                .add_attribute("key", key)
+                .add_attribute("item_type", format!("{:?}", schema.item_type)))
+        }
+        None => {
+            if value.is_some() {
+                return Err(ContractError::ItemSchemaConflict { key });
+            }
+            ITEM_SCHEMAS.remove(deps.storage, key.clone());
+            Ok(Response::default()
+                .add_attribute("action", "execute_set_item_schema")
+                .add_attribute("key", key)
+                .add_attribute("item_type", "None"))
+        }
+    }
+}
+
+pub fn execute_set_typed_item(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    key: String,
+    value: ItemValue,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let schema = check_item_writer(deps.as_ref(), &key)?
+        .ok_or_else(|| ContractError::ItemSchemaMissing { key: key.clone() })?;
+    if value.item_type() != schema.item_type {
+        return Err(ContractError::ItemTypeMismatch {
+            key,
+            expected: schema.item_type,
+        });
+    }
+    let value = validate_item_value(deps.as_ref(), &key, value)?;
+
+    TYPED_ITEMS.save(deps.storage, key.clone(), &value)?;
+    Ok(Response::default()
+        .add_attribute("action", "execute_set_typed_item")
+        .add_attribute("key", key))
+}
+
+/// Checks that the value is well-formed, normalizing addresses.
+fn validate_item_value(
+    deps: Deps,
+    key: &str,
+    value: ItemValue,
+) -> Result<ItemValue, ContractError> {
+    let invalid = |reason: String| ContractError::InvalidItemValue {
+        key: key.to_string(),
+        reason,
+    };
+
+    match value {
+        ItemValue::Address(address) => Ok(ItemValue::Address(
+            deps.api
+                .addr_validate(&address)
+                .map_err(|err| invalid(err.to_string()))?
+                .into_string(),
+        )),
+        ItemValue::Json(json) => {
+            from_json::<IgnoredAny>(json.as_bytes()).map_err(|err| invalid(err.to_string()))?;
+            Ok(ItemValue::Json(json))
+        }
+        ItemValue::Coin(coin) if coin.denom.is_empty() => {
+            Err(invalid("coin denom cannot be empty".to_string()))
+        }
+        value => Ok(value),
     }
 }
 
+/// Returns the proposal module whose messages the DAO is executing.
+fn executing_proposal_module(deps: Deps) -> StdResult<Option<Addr>> {
+    Ok(EXECUTING_PROPOSAL_MODULES
+        .may_load(deps.storage)?
+        .and_then(|mut modules| modules.pop()))
+}
+
+/// Checks that the executing proposal module is allowed to write the item,
+/// returning the item schema.
+fn check_item_writer(deps: Deps, key: &str) -> Result<Option<ItemSchema>, ContractError> {
+    let schema = ITEM_SCHEMAS.may_load(deps.storage, key.to_string())?;
+    if let (
+        Some(ItemSchema {
+            writers: Some(writers),
+            ..
+        }),
+        Some(module),
+    ) = (&schema, executing_proposal_module(deps)?)
+    {
+        if !writers.contains(&module) {
+            return Err(ContractError::ItemWriteUnauthorized {
+                key: key.to_string(),
+                module,
+            });
+        }
+    }
+
+    Ok(schema)
+}
+
+/// Checks that the new writers of an item keep every enabled proposal module
+/// that can currently write it. Only the executing proposal module may give
+/// up its own access, so no module can claim an item for itself.
+fn check_writers_kept(
+    deps: Deps,
+    key: &str,
+    current: Option<ItemSchema>,
+    writers: &[Addr],
+) -> Result<(), ContractError> {
+    let module = match executing_proposal_module(deps)? {
+        Some(module) => module,
+        None => return Ok(()),
+    };
+
+    for proposal_module in PROPOSAL_MODULES.range(deps.storage, None, None, Order::Ascending) {
+        let (writer, proposal_module) = proposal_module?;
+        let can_write = match current.as_ref().and_then(|schema| schema.writers.as_ref()) {
+            Some(current) => current.contains(&writer),
+            None => true,
+        };
+        if proposal_module.status == ProposalModuleStatus::Enabled
+            && can_write
+            && writer != module
+            && !writers.contains(&writer)
+        {
+            return Err(ContractError::ItemWriterRemovalUnauthorized {
+                key: key.to_string(),
+                module,
+                writer,
+            });
+        }
+    }
+
+    Ok(())
+}
+
 pub fn execute_update_sub_daos_list(
     deps: DepsMut,
     env: Env,
@@ -296,6 +848,13 @@ pub fn execute_update_sub_daos_list(
     for addr in to_remove {
         let addr = deps.api.addr_validate(&addr)?;
         SUBDAO_LIST.remove(deps.storage, &addr);
+        SUBDAO_METADATA.remove(deps.storage, &addr);
+
+        // Children of a removed SubDAO become top level SubDAOs.
+        for (child, mut metadata) in sub_dao_children(deps.as_ref(), &addr)? {
+            metadata.parent = None;
+            SUBDAO_METADATA.save(deps.storage, &child, &metadata)?;
+        }
     }
 
     for subdao in to_add {
@@ -308,6 +867,75 @@ pub fn execute_update_sub_daos_list(
         .add_attribute("sender", sender))
 }
 
+pub fn execute_update_sub_dao_metadata(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    subdao: String,
+    timelocks: Vec<String>,
+    proposal_modules: Vec<String>,
+    parent: Option<String>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let subdao = deps.api.addr_validate(&subdao)?;
+    if !SUBDAO_LIST.has(deps.storage, &subdao) {
+        return Err(ContractError::SubDaoNotFound { address: subdao });
+    }
+
+    let parent = parent.map(|p| deps.api.addr_validate(&p)).transpose()?;
+    if let Some(parent) = &parent {
+        if !SUBDAO_LIST.has(deps.storage, parent) {
+            return Err(ContractError::SubDaoNotFound {
+                address: parent.clone(),
+            });
+        }
+
+        // Walk up from the new parent to make sure the SubDAO isn't its ancestor.
+        let mut ancestor = Some(parent.clone());
+        while let Some(addr) = ancestor {
+            if addr == subdao {
+                return Err(ContractError::SubDaoHierarchyCycle { address: subdao });
+            }
+            ancestor = SUBDAO_METADATA
+                .may_load(deps.storage, &addr)?
+                .and_then(|metadata| metadata.parent);
+        }
+    }
+
+    let metadata = SubDaoMetadata {
+        timelocks: timelocks
+            .iter()
+            .map(|addr| deps.api.addr_validate(addr))
+            .collect::<StdResult<Vec<Addr>>>()?,
+        proposal_modules: proposal_modules
+            .iter()
+            .map(|addr| deps.api.addr_validate(addr))
+            .collect::<StdResult<Vec<Addr>>>()?,
+        parent,
+    };
+    SUBDAO_METADATA.save(deps.storage, &subdao, &metadata)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_update_sub_dao_metadata")
+        .add_attribute("sender", sender)
+        .add_attribute("subdao", subdao))
+}
+
+/// Returns the SubDAOs whose parent is the given SubDAO along with their metadata.
+fn sub_dao_children(deps: Deps, subdao: &Addr) -> StdResult<Vec<(Addr, SubDaoMetadata)>> {
+    SUBDAO_METADATA
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|item| {
+            item.as_ref().map_or(true, |(_, metadata)| {
+                metadata.parent.as_ref() == Some(subdao)
+            })
+        })
+        .collect()
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -316,7 +944,25 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::GetItem { key } => query_get_item(deps, key),
         QueryMsg::Info {} => query_info(deps),
         QueryMsg::ListItems { start_after, limit } => query_list_items(deps, start_after, limit),
+        QueryMsg::ListItemsByPrefix {
+            prefix,
+            start_after,
+            limit,
+        } => to_json_binary(&list_by_prefix(deps, &ITEMS, prefix, start_after, limit)?),
+        QueryMsg::GetTypedItem { key } => query_get_typed_item(deps, key),
+        QueryMsg::ListTypedItems {
+            prefix,
+            start_after,
+            limit,
+        } => to_json_binary(&list_by_prefix(
+            deps,
+            &TYPED_ITEMS,
+            prefix.unwrap_or_default(),
+            start_after,
+            limit,
+        )?),
         QueryMsg::PauseInfo {} => query_paused(deps, env),
+        QueryMsg::PauseAuthority {} => query_pause_authority(deps),
         QueryMsg::ProposalModules { start_after, limit } => {
             query_proposal_modules(deps, start_after, limit)
         }
@@ -332,7 +978,12 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             query_list_sub_daos(deps, start_after, limit)
         }
         QueryMsg::GetSubDao { address } => query_sub_dao(deps, address),
+        QueryMsg::SubDaoOverview { address } => query_sub_dao_overview(deps, address),
         QueryMsg::DaoURI {} => query_dao_uri(deps),
+        QueryMsg::ExecutionDelay {} => query_execution_delay(deps),
+        QueryMsg::ListQueuedExecutions { start_after, limit } => {
+            query_list_queued_executions(deps, start_after, limit)
+        }
     }
 }
 
@@ -404,15 +1055,20 @@ pub fn query_active_proposal_modules(
 }
 
 fn get_pause_info(deps: Deps, env: Env) -> StdResult<PauseInfoResponse> {
+    let paused_scopes = PAUSED_SCOPES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, paused)| paused))
+        .filter(|item| {
+            item.as_ref()
+                .map_or(true, |paused| !paused.expiration.is_expired(&env.block))
+        })
+        .collect::<StdResult<Vec<ScopedPause>>>()?;
     Ok(match PAUSED.may_load(deps.storage)? {
-        Some(expiration) => {
-            if expiration.is_expired(&env.block) {
-                PauseInfoResponse::Unpaused {}
-            } else {
-                PauseInfoResponse::Paused { expiration }
-            }
-        }
-        None => PauseInfoResponse::Unpaused {},
+        Some(expiration) if !expiration.is_expired(&env.block) => PauseInfoResponse::Paused {
+            expiration,
+            paused_scopes,
+        },
+        _ => PauseInfoResponse::Unpaused { paused_scopes },
     })
 }
 
@@ -420,6 +1076,10 @@ pub fn query_paused(deps: Deps, env: Env) -> StdResult<Binary> {
     to_json_binary(&get_pause_info(deps, env)?)
 }
 
+pub fn query_pause_authority(deps: Deps) -> StdResult<Binary> {
+    to_json_binary(&PAUSE_AUTHORITY.may_load(deps.storage)?)
+}
+
 pub fn query_dump_state(deps: Deps, env: Env) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
     let voting_registry_module = VOTING_REGISTRY_MODULE.load(deps.storage)?;
@@ -488,6 +1148,39 @@ pub fn query_list_items(
     )?)
 }
 
+pub fn query_get_typed_item(deps: Deps, key: String) -> StdResult<Binary> {
+    to_json_binary(&GetTypedItemResponse {
+        schema: ITEM_SCHEMAS.may_load(deps.storage, key.clone())?,
+        value: TYPED_ITEMS.may_load(deps.storage, key)?,
+    })
+}
+
+/// Lists the entries of the map whose key starts with the prefix, in
+/// ascending order of keys.
+fn list_by_prefix<V>(
+    deps: Deps,
+    map: &Map<'static, String, V>,
+    prefix: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(String, V)>>
+where
+    V: Serialize + DeserializeOwned,
+{
+    let min = match start_after {
+        Some(start_after) if start_after >= prefix => Bound::exclusive(start_after),
+        _ => Bound::inclusive(prefix.clone()),
+    };
+
+    map.range(deps.storage, Some(min), None, Order::Ascending)
+        .take_while(|item| {
+            item.as_ref()
+                .map_or(true, |(key, _)| key.starts_with(&prefix))
+        })
+        .take(limit.map_or(usize::MAX, |limit| limit as usize))
+        .collect()
+}
+
 pub fn query_list_sub_daos(
     deps: Deps,
     start_after: Option<String>,
@@ -528,11 +1221,87 @@ pub fn query_sub_dao(deps: Deps, address: String) -> StdResult<Binary> {
     }
 }
 
+pub fn query_sub_dao_overview(deps: Deps, address: String) -> StdResult<Binary> {
+    let addr = deps.api.addr_validate(&address)?;
+    let charter = SUBDAO_LIST
+        .may_load(deps.storage, &addr)?
+        .ok_or_else(|| StdError::generic_err("SubDao not found"))?;
+    let metadata = SUBDAO_METADATA
+        .may_load(deps.storage, &addr)?
+        .unwrap_or(SubDaoMetadata {
+            timelocks: vec![],
+            proposal_modules: vec![],
+            parent: None,
+        });
+
+    // Failing sub-queries leave their part of the overview empty, so that a single
+    // misbehaving contract doesn't hide the rest of it.
+    let pause_info: Option<SubDaoPauseInfoResponse> = deps
+        .querier
+        .query_wasm_smart(&addr, &SubDaoQueryMsg::PauseInfo {})
+        .ok();
+
+    let timelocks = metadata
+        .timelocks
+        .into_iter()
+        .map(|timelock| {
+            let timelocked_proposals: Option<u64> = deps
+                .querier
+                .query_wasm_smart(
+                    &timelock,
+                    &TimelockQueryMsg::ProposalCount {
+                        status: Some(TimelockProposalStatus::Timelocked),
+                    },
+                )
+                .ok();
+            TimelockOverview {
+                address: timelock,
+                timelocked_proposals,
+            }
+        })
+        .collect();
+
+    let children = sub_dao_children(deps, &addr)?
+        .into_iter()
+        .map(|(child, _)| child)
+        .collect();
+
+    to_json_binary(&SubDaoOverviewResponse {
+        subdao: SubDao {
+            addr: address,
+            charter,
+        },
+        pause_info,
+        proposal_modules: metadata.proposal_modules,
+        timelocks,
+        parent: metadata.parent,
+        children,
+    })
+}
+
 pub fn query_dao_uri(deps: Deps) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
     to_json_binary(&config.dao_uri)
 }
 
+pub fn query_execution_delay(deps: Deps) -> StdResult<Binary> {
+    to_json_binary(&EXECUTION_DELAY.may_load(deps.storage)?)
+}
+
+pub fn query_list_queued_executions(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    to_json_binary(&paginate_map_values(
+        deps,
+        &QUEUED_EXECUTIONS,
+        start_after,
+        limit,
+        cosmwasm_std::Order::Ascending,
+    )?)
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
@@ -587,6 +1356,13 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response<NeutronMsg
 
             Ok(Response::default().add_attribute("voting_registry_module", voting_registry_addr))
         }
+        PROPOSAL_MESSAGES_REPLY_ID => {
+            let mut modules = EXECUTING_PROPOSAL_MODULES.load(deps.storage)?;
+            modules.pop();
+            EXECUTING_PROPOSAL_MODULES.save(deps.storage, &modules)?;
+
+            Ok(Response::default())
+        }
         _ => Err(ContractError::UnknownReplyID {}),
     }
 }
@@ -609,7 +1385,30 @@ pub(crate) fn derive_proposal_module_prefix(mut dividend: usize) -> StdResult<St
 
 #[cfg(test)]
 mod test {
-    use crate::contract::derive_proposal_module_prefix;
+    use crate::contract::{
+        derive_proposal_module_prefix, execute, execute_execute_queued, execute_pause_scope,
+        execute_proposal_hook, execute_set_item, execute_set_item_schema, execute_set_typed_item,
+        execute_unpause_scope, execute_update_execution_delay, execute_update_pause_authority,
+        execute_update_sub_dao_metadata, execute_update_sub_daos_list, execute_veto_queued, query,
+        reply, PROPOSAL_MESSAGES_REPLY_ID,
+    };
+    use crate::msg::{ExecuteMsg, QueryMsg};
+    use crate::query::{PauseInfoResponse, SubDaoOverviewResponse, TimelockOverview};
+    use crate::state::{
+        ExecutionDelay, ItemSchema, ItemType, ItemValue, PauseScope, ProposalModule,
+        ProposalModuleStatus, QueuedExecutionStatus, EXECUTING_PROPOSAL_MODULES, PAUSED,
+        PROPOSAL_MODULES, QUEUED_EXECUTIONS, SUBDAO_METADATA,
+    };
+    use crate::ContractError;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{
+        coins, from_json, to_json_binary, Addr, Attribute, BankMsg, ContractResult, CosmosMsg,
+        DepsMut, Reply, StdResult, SubMsgResponse, SubMsgResult, SystemError, SystemResult,
+        Uint128, WasmMsg, WasmQuery,
+    };
+    use cw_utils::Duration;
+    use exec_control::pause::{PauseInfoResponse as SubDaoPauseInfoResponse, MAX_PAUSE_DURATION};
+    use neutron_subdao_core::types::SubDao;
     use std::collections::HashSet;
 
     #[test]
@@ -642,4 +1441,827 @@ mod test {
             seen.insert(prefix);
         }
     }
+
+    #[test]
+    fn test_execution_delay() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let proposal_module = Addr::unchecked("proposal_module");
+        let security_dao = Addr::unchecked("security_dao");
+        PROPOSAL_MODULES
+            .save(
+                deps.as_mut().storage,
+                proposal_module.clone(),
+                &ProposalModule {
+                    address: proposal_module.clone(),
+                    prefix: "A".to_string(),
+                    status: ProposalModuleStatus::Enabled,
+                },
+            )
+            .unwrap();
+        let msgs = vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: "receiver".to_string(),
+            amount: coins(100, "untrn"),
+        })];
+
+        // Without a delay, messages are executed right away.
+        let res = execute_proposal_hook(
+            deps.as_mut(),
+            env.clone(),
+            proposal_module.clone(),
+            msgs.clone(),
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+
+        let err = execute_update_execution_delay(
+            deps.as_mut(),
+            env.clone(),
+            proposal_module.clone(),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        execute_update_execution_delay(
+            deps.as_mut(),
+            env.clone(),
+            env.contract.address.clone(),
+            Some(ExecutionDelay {
+                delay: Duration::Height(10),
+                security_dao: security_dao.clone(),
+            }),
+        )
+        .unwrap();
+
+        // With a delay the hook succeeds without executing anything, so the
+        // proposal module marks the proposal executed once it is queued.
+        for id in 0..3 {
+            let res = execute_proposal_hook(
+                deps.as_mut(),
+                env.clone(),
+                proposal_module.clone(),
+                msgs.clone(),
+            )
+            .unwrap();
+            assert!(res.messages.is_empty());
+            assert!(res
+                .attributes
+                .contains(&Attribute::new("queued_execution_id", id.to_string())));
+            assert_eq!(
+                QUEUED_EXECUTIONS
+                    .load(deps.as_ref().storage, id)
+                    .unwrap()
+                    .status,
+                QueuedExecutionStatus::Queued
+            );
+        }
+
+        let err = execute_execute_queued(deps.as_mut(), env.clone(), Addr::unchecked("anyone"), 0)
+            .unwrap_err();
+        assert_eq!(err, ContractError::ExecutionDelayNotElapsed { id: 0 });
+        let err = execute_veto_queued(deps.as_mut(), env.clone(), Addr::unchecked("anyone"), 0)
+            .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        execute_veto_queued(deps.as_mut(), env.clone(), security_dao.clone(), 0).unwrap();
+        assert_eq!(
+            QUEUED_EXECUTIONS
+                .load(deps.as_ref().storage, 0)
+                .unwrap()
+                .status,
+            QueuedExecutionStatus::Vetoed
+        );
+
+        env.block.height += 10;
+        let err = execute_execute_queued(deps.as_mut(), env.clone(), Addr::unchecked("anyone"), 0)
+            .unwrap_err();
+        assert_eq!(err, ContractError::QueuedExecutionNotPending { id: 0 });
+        let err = execute_veto_queued(deps.as_mut(), env.clone(), security_dao, 1).unwrap_err();
+        assert_eq!(err, ContractError::ExecutionDelayElapsed { id: 1 });
+        let res = execute_execute_queued(deps.as_mut(), env.clone(), Addr::unchecked("anyone"), 1)
+            .unwrap();
+        assert_eq!(res.messages.len(), 1);
+
+        // Messages of a module that got disabled since can't be executed anymore.
+        PROPOSAL_MODULES
+            .update(
+                deps.as_mut().storage,
+                proposal_module.clone(),
+                |module| -> StdResult<_> {
+                    let mut module = module.unwrap();
+                    module.status = ProposalModuleStatus::Disabled;
+                    Ok(module)
+                },
+            )
+            .unwrap();
+        let err =
+            execute_execute_queued(deps.as_mut(), env, Addr::unchecked("anyone"), 2).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::ModuleDisabledCannotExecute {
+                address: proposal_module
+            }
+        );
+        assert_eq!(
+            QUEUED_EXECUTIONS
+                .load(deps.as_ref().storage, 2)
+                .unwrap()
+                .status,
+            QueuedExecutionStatus::Queued
+        );
+    }
+
+    #[test]
+    fn test_veto_queued() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let core = env.contract.address.clone();
+        let proposal_module = Addr::unchecked("proposal_module");
+        PROPOSAL_MODULES
+            .save(
+                deps.as_mut().storage,
+                proposal_module.clone(),
+                &ProposalModule {
+                    address: proposal_module.clone(),
+                    prefix: "A".to_string(),
+                    status: ProposalModuleStatus::Enabled,
+                },
+            )
+            .unwrap();
+        let set_delay = |deps: DepsMut, security_dao: Option<&str>| {
+            execute_update_execution_delay(
+                deps,
+                mock_env(),
+                core.clone(),
+                security_dao.map(|security_dao| ExecutionDelay {
+                    delay: Duration::Height(10),
+                    security_dao: Addr::unchecked(security_dao),
+                }),
+            )
+            .unwrap();
+        };
+
+        set_delay(deps.as_mut(), Some("security_dao"));
+        for _ in 0..3 {
+            execute_proposal_hook(deps.as_mut(), env.clone(), proposal_module.clone(), vec![])
+                .unwrap();
+        }
+
+        // A new security subDAO can't veto messages queued before it was set.
+        set_delay(deps.as_mut(), Some("new_security_dao"));
+        let err = execute_veto_queued(
+            deps.as_mut(),
+            env.clone(),
+            Addr::unchecked("new_security_dao"),
+            0,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        execute_veto_queued(
+            deps.as_mut(),
+            env.clone(),
+            Addr::unchecked("security_dao"),
+            0,
+        )
+        .unwrap();
+
+        // Removing the delay doesn't remove the veto right over pending
+        // messages.
+        set_delay(deps.as_mut(), None);
+        execute_veto_queued(
+            deps.as_mut(),
+            env.clone(),
+            Addr::unchecked("security_dao"),
+            1,
+        )
+        .unwrap();
+
+        // Vetoes are allowed while the DAO is paused.
+        PAUSED
+            .save(
+                deps.as_mut().storage,
+                &Duration::Height(100).after(&env.block),
+            )
+            .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExecuteQueued { id: 2 },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Paused {});
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info("security_dao", &[]),
+            ExecuteMsg::VetoQueued { id: 2 },
+        )
+        .unwrap();
+        for id in 0..3 {
+            assert_eq!(
+                QUEUED_EXECUTIONS
+                    .load(deps.as_ref().storage, id)
+                    .unwrap()
+                    .status,
+                QueuedExecutionStatus::Vetoed
+            );
+        }
+    }
+
+    #[test]
+    fn test_typed_items() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let core = env.contract.address.clone();
+        let proposal_module = Addr::unchecked("proposal_module");
+        let other_module = Addr::unchecked("other_module");
+        for module in [&proposal_module, &other_module] {
+            PROPOSAL_MODULES
+                .save(
+                    deps.as_mut().storage,
+                    module.clone(),
+                    &ProposalModule {
+                        address: module.clone(),
+                        prefix: "A".to_string(),
+                        status: ProposalModuleStatus::Enabled,
+                    },
+                )
+                .unwrap();
+        }
+
+        execute_set_item_schema(
+            deps.as_mut(),
+            env.clone(),
+            core.clone(),
+            "registry.fee".to_string(),
+            Some(ItemSchema {
+                item_type: ItemType::Integer,
+                writers: Some(vec![proposal_module.clone()]),
+            }),
+        )
+        .unwrap();
+        execute_set_item(
+            deps.as_mut(),
+            env.clone(),
+            core.clone(),
+            "registry.name".to_string(),
+            "registry".to_string(),
+        )
+        .unwrap();
+
+        let err = execute_set_item(
+            deps.as_mut(),
+            env.clone(),
+            core.clone(),
+            "registry.fee".to_string(),
+            "100".to_string(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::ItemIsTyped {
+                key: "registry.fee".to_string()
+            }
+        );
+        let err = execute_set_typed_item(
+            deps.as_mut(),
+            env.clone(),
+            core.clone(),
+            "registry.fee".to_string(),
+            ItemValue::Json("{}".to_string()),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::ItemTypeMismatch {
+                key: "registry.fee".to_string(),
+                expected: ItemType::Integer
+            }
+        );
+        execute_set_typed_item(
+            deps.as_mut(),
+            env.clone(),
+            core.clone(),
+            "registry.fee".to_string(),
+            ItemValue::Integer(Uint128::new(100)),
+        )
+        .unwrap();
+
+        // The proposal module is recorded while its messages execute, and
+        // only the allowed one can write the item.
+        let set_fee_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: core.to_string(),
+            msg: to_json_binary(&ExecuteMsg::SetTypedItem {
+                key: "registry.fee".to_string(),
+                value: ItemValue::Integer(Uint128::new(200)),
+            })
+            .unwrap(),
+            funds: vec![],
+        });
+        let res = execute_proposal_hook(
+            deps.as_mut(),
+            env.clone(),
+            other_module.clone(),
+            vec![set_fee_msg.clone()],
+        )
+        .unwrap();
+        assert_eq!(res.messages[0].id, PROPOSAL_MESSAGES_REPLY_ID);
+        assert_eq!(
+            EXECUTING_PROPOSAL_MODULES.load(&deps.storage).unwrap(),
+            vec![other_module.clone()]
+        );
+        let err = execute_set_typed_item(
+            deps.as_mut(),
+            env.clone(),
+            core.clone(),
+            "registry.fee".to_string(),
+            ItemValue::Integer(Uint128::new(200)),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::ItemWriteUnauthorized {
+                key: "registry.fee".to_string(),
+                module: other_module.clone()
+            }
+        );
+
+        // A proposal module can't claim an item for itself, but can give up
+        // its own access.
+        let err = execute_set_item_schema(
+            deps.as_mut(),
+            env.clone(),
+            core.clone(),
+            "registry.limit".to_string(),
+            Some(ItemSchema {
+                item_type: ItemType::Integer,
+                writers: Some(vec![other_module.clone()]),
+            }),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::ItemWriterRemovalUnauthorized {
+                key: "registry.limit".to_string(),
+                module: other_module.clone(),
+                writer: proposal_module.clone()
+            }
+        );
+        execute_set_item_schema(
+            deps.as_mut(),
+            env.clone(),
+            core.clone(),
+            "registry.limit".to_string(),
+            Some(ItemSchema {
+                item_type: ItemType::Integer,
+                writers: Some(vec![proposal_module.clone()]),
+            }),
+        )
+        .unwrap();
+
+        reply(
+            deps.as_mut(),
+            env.clone(),
+            Reply {
+                id: PROPOSAL_MESSAGES_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+        assert!(EXECUTING_PROPOSAL_MODULES
+            .load(&deps.storage)
+            .unwrap()
+            .is_empty());
+
+        execute_proposal_hook(
+            deps.as_mut(),
+            env.clone(),
+            proposal_module,
+            vec![set_fee_msg],
+        )
+        .unwrap();
+        execute_set_typed_item(
+            deps.as_mut(),
+            env.clone(),
+            core.clone(),
+            "registry.fee".to_string(),
+            ItemValue::Integer(Uint128::new(200)),
+        )
+        .unwrap();
+
+        let items: Vec<(String, String)> = from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::ListItemsByPrefix {
+                    prefix: "registry.".to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            items,
+            vec![("registry.name".to_string(), "registry".to_string())]
+        );
+        let items: Vec<(String, ItemValue)> = from_json(
+            query(
+                deps.as_ref(),
+                env,
+                QueryMsg::ListTypedItems {
+                    prefix: Some("registry.".to_string()),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            items,
+            vec![(
+                "registry.fee".to_string(),
+                ItemValue::Integer(Uint128::new(200))
+            )]
+        );
+    }
+
+    #[test]
+    fn test_pause_scope() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let core = env.contract.address.clone();
+        let proposal_module = Addr::unchecked("proposal_module");
+        PROPOSAL_MODULES
+            .save(
+                deps.as_mut().storage,
+                proposal_module.clone(),
+                &ProposalModule {
+                    address: proposal_module.clone(),
+                    prefix: "A".to_string(),
+                    status: ProposalModuleStatus::Enabled,
+                },
+            )
+            .unwrap();
+        let scope = PauseScope::ProposalModule {
+            address: proposal_module.clone(),
+        };
+
+        let err = execute_pause_scope(
+            deps.as_mut(),
+            env.clone(),
+            Addr::unchecked("security_dao"),
+            scope.clone(),
+            Duration::Height(10),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // The pause authority can pause scopes too, without any execution
+        // delay being set.
+        let err = execute_update_pause_authority(
+            deps.as_mut(),
+            env.clone(),
+            Addr::unchecked("security_dao"),
+            Some("security_dao".to_string()),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        execute_update_pause_authority(
+            deps.as_mut(),
+            env.clone(),
+            core.clone(),
+            Some("security_dao".to_string()),
+        )
+        .unwrap();
+        execute_pause_scope(
+            deps.as_mut(),
+            env.clone(),
+            Addr::unchecked("security_dao"),
+            scope.clone(),
+            Duration::Height(10),
+        )
+        .unwrap();
+
+        let err =
+            execute_proposal_hook(deps.as_mut(), env.clone(), proposal_module.clone(), vec![])
+                .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::ScopePaused {
+                scope: "proposal_module:proposal_module".to_string()
+            }
+        );
+        // Other scopes keep working.
+        execute_set_item(
+            deps.as_mut(),
+            env.clone(),
+            core.clone(),
+            "key".to_string(),
+            "value".to_string(),
+        )
+        .unwrap();
+
+        let pause_info: PauseInfoResponse =
+            from_json(query(deps.as_ref(), env.clone(), QueryMsg::PauseInfo {}).unwrap()).unwrap();
+        match pause_info {
+            PauseInfoResponse::Unpaused { paused_scopes } => {
+                assert_eq!(paused_scopes.len(), 1);
+                assert_eq!(paused_scopes[0].scope, scope);
+            }
+            PauseInfoResponse::Paused { .. } => panic!("the DAO is not paused"),
+        }
+
+        execute_unpause_scope(deps.as_mut(), env.clone(), core, scope.clone()).unwrap();
+        execute_proposal_hook(deps.as_mut(), env.clone(), proposal_module.clone(), vec![]).unwrap();
+
+        // Pauses lapse on their own.
+        execute_pause_scope(
+            deps.as_mut(),
+            env.clone(),
+            Addr::unchecked("security_dao"),
+            scope,
+            Duration::Height(10),
+        )
+        .unwrap();
+        env.block.height += 10;
+        execute_proposal_hook(deps.as_mut(), env.clone(), proposal_module, vec![]).unwrap();
+        let pause_info: PauseInfoResponse =
+            from_json(query(deps.as_ref(), env.clone(), QueryMsg::PauseInfo {}).unwrap()).unwrap();
+        assert_eq!(
+            pause_info,
+            PauseInfoResponse::Unpaused {
+                paused_scopes: vec![]
+            }
+        );
+
+        // The pause authority can't pause scopes for long.
+        for duration in [Duration::Height(MAX_PAUSE_DURATION + 1), Duration::Time(10)] {
+            let err = execute_pause_scope(
+                deps.as_mut(),
+                env.clone(),
+                Addr::unchecked("security_dao"),
+                PauseScope::ProposalExecution,
+                duration,
+            )
+            .unwrap_err();
+            assert_eq!(
+                err,
+                ContractError::InvalidScopePauseDuration {
+                    max: MAX_PAUSE_DURATION
+                }
+            );
+        }
+
+        // A proposal that only lifts pauses is executed while proposal
+        // execution is paused, others are not.
+        execute_pause_scope(
+            deps.as_mut(),
+            env.clone(),
+            Addr::unchecked("security_dao"),
+            PauseScope::ProposalExecution,
+            Duration::Height(MAX_PAUSE_DURATION),
+        )
+        .unwrap();
+        let info = mock_info(proposal_module.as_str(), &[]);
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::ExecuteProposalHook { msgs: vec![] },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::ScopePaused {
+                scope: "proposal_execution".to_string()
+            }
+        );
+        let unpause = |msg: &ExecuteMsg| {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: core.to_string(),
+                msg: to_json_binary(msg).unwrap(),
+                funds: vec![],
+            })
+        };
+        let unpause_msgs = vec![
+            unpause(&ExecuteMsg::UnpauseScope {
+                scope: PauseScope::ProposalExecution,
+            }),
+            unpause(&ExecuteMsg::UpdatePauseAuthority { authority: None }),
+        ];
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::ExecuteProposalHook {
+                msgs: [
+                    unpause_msgs.clone(),
+                    vec![CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "receiver".to_string(),
+                        amount: coins(100, "untrn"),
+                    })],
+                ]
+                .concat(),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::ScopePaused {
+                scope: "proposal_execution".to_string()
+            }
+        );
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::ExecuteProposalHook { msgs: unpause_msgs },
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 2);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(core.as_str(), &[]),
+            ExecuteMsg::UnpauseScope {
+                scope: PauseScope::ProposalExecution,
+            },
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::ExecuteProposalHook { msgs: vec![] },
+        )
+        .unwrap();
+
+        // Removing the pause authority takes away its pause power.
+        execute_update_pause_authority(deps.as_mut(), env.clone(), core, None).unwrap();
+        let err = execute_pause_scope(
+            deps.as_mut(),
+            env,
+            Addr::unchecked("security_dao"),
+            PauseScope::Items,
+            Duration::Height(10),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+    }
+
+    #[test]
+    fn test_sub_dao_registry() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let core = env.contract.address.clone();
+        let subdao = |addr: &str| SubDao {
+            addr: addr.to_string(),
+            charter: None,
+        };
+        execute_update_sub_daos_list(
+            deps.as_mut(),
+            env.clone(),
+            core.clone(),
+            vec![subdao("grants"), subdao("grants_child"), subdao("other")],
+            vec![],
+        )
+        .unwrap();
+
+        let err = execute_update_sub_dao_metadata(
+            deps.as_mut(),
+            env.clone(),
+            core.clone(),
+            "unknown".to_string(),
+            vec![],
+            vec![],
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::SubDaoNotFound {
+                address: Addr::unchecked("unknown")
+            }
+        );
+        execute_update_sub_dao_metadata(
+            deps.as_mut(),
+            env.clone(),
+            core.clone(),
+            "grants".to_string(),
+            vec![
+                "grants_timelock".to_string(),
+                "grants_legacy_timelock".to_string(),
+            ],
+            vec!["grants_proposal".to_string()],
+            None,
+        )
+        .unwrap();
+        execute_update_sub_dao_metadata(
+            deps.as_mut(),
+            env.clone(),
+            core.clone(),
+            "grants_child".to_string(),
+            vec![],
+            vec![],
+            Some("grants".to_string()),
+        )
+        .unwrap();
+
+        // A SubDAO can't become a child of its descendant.
+        let err = execute_update_sub_dao_metadata(
+            deps.as_mut(),
+            env.clone(),
+            core.clone(),
+            "grants".to_string(),
+            vec![],
+            vec![],
+            Some("grants_child".to_string()),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::SubDaoHierarchyCycle {
+                address: Addr::unchecked("grants")
+            }
+        );
+
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::Smart { contract_addr, .. } if contract_addr == "grants" => {
+                SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&SubDaoPauseInfoResponse::Paused { until_height: 100 }).unwrap(),
+                ))
+            }
+            WasmQuery::Smart { contract_addr, .. } if contract_addr == "grants_timelock" => {
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&3u64).unwrap()))
+            }
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "wasm".to_string(),
+            }),
+        });
+        let overview: SubDaoOverviewResponse = from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::SubDaoOverview {
+                    address: "grants".to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            overview,
+            SubDaoOverviewResponse {
+                subdao: subdao("grants"),
+                pause_info: Some(SubDaoPauseInfoResponse::Paused { until_height: 100 }),
+                proposal_modules: vec![Addr::unchecked("grants_proposal")],
+                // The legacy timelock doesn't answer, which only hides its own count.
+                timelocks: vec![
+                    TimelockOverview {
+                        address: Addr::unchecked("grants_timelock"),
+                        timelocked_proposals: Some(3),
+                    },
+                    TimelockOverview {
+                        address: Addr::unchecked("grants_legacy_timelock"),
+                        timelocked_proposals: None,
+                    },
+                ],
+                parent: None,
+                children: vec![Addr::unchecked("grants_child")],
+            }
+        );
+
+        // A SubDAO core that can't be queried only hides the pause state.
+        let overview: SubDaoOverviewResponse = from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::SubDaoOverview {
+                    address: "grants_child".to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(overview.pause_info, None);
+        assert_eq!(overview.parent, Some(Addr::unchecked("grants")));
+
+        // Children of a removed SubDAO lose their parent.
+        execute_update_sub_daos_list(deps.as_mut(), env, core, vec![], vec!["grants".to_string()])
+            .unwrap();
+        assert_eq!(
+            SUBDAO_METADATA
+                .load(deps.as_ref().storage, &Addr::unchecked("grants_child"))
+                .unwrap()
+                .parent,
+            None
+        );
+        assert!(!SUBDAO_METADATA.has(deps.as_ref().storage, &Addr::unchecked("grants")));
+    }
 }
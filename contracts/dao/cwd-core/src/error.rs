@@ -1,3 +1,4 @@
+use crate::state::ItemType;
 use cosmwasm_std::{Addr, StdError};
 use cw_utils::ParseReplyError;
 use thiserror::Error;
@@ -25,6 +26,12 @@ pub enum ContractError {
     #[error("The contract is paused.")]
     Paused {},
 
+    #[error("The {scope} scope of the contract is paused.")]
+    ScopePaused { scope: String },
+
+    #[error("The pause authority can only pause scopes for up to {max} blocks.")]
+    InvalidScopePauseDuration { max: u64 },
+
     #[error("Execution would result in no proposal modules being active.")]
     NoActiveProposalModules {},
 
@@ -45,4 +52,54 @@ pub enum ContractError {
 
     #[error("Proposal module with address is disabled and cannot execute messages.")]
     ModuleDisabledCannotExecute { address: Addr },
+
+    #[error("Item ({key}) has no schema.")]
+    ItemSchemaMissing { key: String },
+
+    #[error("Item ({key}) has a schema and can only be set as a typed item.")]
+    ItemIsTyped { key: String },
+
+    #[error("Item ({key}) must be of type {expected:?}.")]
+    ItemTypeMismatch { key: String, expected: ItemType },
+
+    #[error("Item ({key}) has a value that does not match the new schema.")]
+    ItemSchemaConflict { key: String },
+
+    #[error("Invalid value for item ({key}): {reason}")]
+    InvalidItemValue { key: String, reason: String },
+
+    #[error("Proposal module ({module}) is not allowed to write item ({key}).")]
+    ItemWriteUnauthorized { key: String, module: Addr },
+
+    #[error(
+        "Proposal module ({module}) cannot remove the write access of ({writer}) to item ({key})."
+    )]
+    ItemWriterRemovalUnauthorized {
+        key: String,
+        module: Addr,
+        writer: Addr,
+    },
+
+    #[error("Execution delay cannot be zero.")]
+    ZeroExecutionDelay {},
+
+    #[error("Queued execution ({id}) does not exist.")]
+    QueuedExecutionDoesNotExist { id: u64 },
+
+    #[error("Queued execution ({id}) is not pending anymore.")]
+    QueuedExecutionNotPending { id: u64 },
+
+    #[error("Execution delay of queued execution ({id}) has not elapsed yet.")]
+    ExecutionDelayNotElapsed { id: u64 },
+
+    #[error(
+        "Execution delay of queued execution ({id}) has elapsed, it cannot be vetoed anymore."
+    )]
+    ExecutionDelayElapsed { id: u64 },
+
+    #[error("SubDAO ({address}) is not in the list.")]
+    SubDaoNotFound { address: Addr },
+
+    #[error("SubDAO ({address}) cannot be a parent of itself or of its ancestors.")]
+    SubDaoHierarchyCycle { address: Addr },
 }
@@ -11,7 +11,7 @@ use neutron_subdao_core::types::SubDao;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::state::Config;
+use crate::state::{Config, ExecutionDelay, ItemSchema, ItemValue, PauseScope};
 
 /// Information about an item to be stored in the items list.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -51,17 +51,44 @@ pub struct InstantiateMsg {
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
     /// Callable by proposal modules. The DAO will execute the
-    /// messages in the hook in order.
+    /// messages in the hook in order. While an execution delay is set
+    /// the messages are queued instead and the hook succeeds without
+    /// executing anything, so proposal modules consider the proposal
+    /// executed once it is queued.
     ExecuteProposalHook { msgs: Vec<CosmosMsg<NeutronMsg>> },
     /// Pauses the DAO for a set duration.
     /// When paused the DAO is unable to execute proposals
     Pause { duration: Duration },
+    /// Pauses a single part of the DAO for a set duration, leaving the
+    /// rest of it working. Callable by the core contract and by the
+    /// pause authority.
+    PauseScope {
+        scope: PauseScope,
+        duration: Duration,
+    },
+    /// Lifts the pause of a scope before it expires. Callable by the
+    /// core contract and by the pause authority.
+    UnpauseScope { scope: PauseScope },
+    /// Callable by the core contract. Sets the address allowed to pause
+    /// and unpause scopes besides the DAO itself, e.g. the security
+    /// subDAO, or removes it if `None`.
+    UpdatePauseAuthority { authority: Option<String> },
     /// Removes an item from the governance contract's item map.
     RemoveItem { key: String },
     /// Adds an item to the governance contract's item map. If the
     /// item already exists the existing value is overriden. If the
     /// item does not exist a new item is added.
     SetItem { key: String, addr: String },
+    /// Callable by the core contract. Declares the type of an item
+    /// and which proposal modules may write it, or removes the schema
+    /// if `None`.
+    SetItemSchema {
+        key: String,
+        schema: Option<ItemSchema>,
+    },
+    /// Callable by the core contract. Sets an item that has a schema.
+    /// The value must match the type declared by the schema.
+    SetTypedItem { key: String, value: ItemValue },
     /// Callable by the core contract. Replaces the current
     /// governance contract config with the provided config.
     UpdateConfig { config: Config },
@@ -83,6 +110,28 @@ pub enum ExecuteMsg {
         to_add: Vec<SubDao>,
         to_remove: Vec<String>,
     },
+    /// Callable by the core contract. Records the timelock and proposal
+    /// module addresses of a listed SubDAO along with its parent SubDAO.
+    UpdateSubDaoMetadata {
+        subdao: String,
+        timelocks: Vec<String>,
+        proposal_modules: Vec<String>,
+        parent: Option<String>,
+    },
+    /// Callable by the core contract. Sets the delay applied to the
+    /// messages of passed proposals, or removes it if `None`. Messages
+    /// already queued keep their earliest execution time.
+    UpdateExecutionDelay {
+        execution_delay: Option<ExecutionDelay>,
+    },
+    /// Executes queued messages once their execution delay has elapsed.
+    /// Callable by anyone. If a message fails the whole call fails and
+    /// the messages stay queued, so the call can be retried.
+    ExecuteQueued { id: u64 },
+    /// Callable by the security subDAO that was set when the messages
+    /// were queued. Vetoes queued messages before their execution delay
+    /// elapses. Allowed while the DAO is paused.
+    VetoQueued { id: u64 },
 }
 
 #[voting_query]
@@ -111,6 +160,25 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Lists the items whose key starts with the given prefix, in
+    /// ascending order of keys.
+    #[returns(Vec<(String, String)>)]
+    ListItemsByPrefix {
+        prefix: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Gets a typed item along with its schema.
+    #[returns(crate::query::GetTypedItemResponse)]
+    GetTypedItem { key: String },
+    /// Lists the typed items, optionally only the ones whose key
+    /// starts with the given prefix, in ascending order of keys.
+    #[returns(Vec<(String, crate::state::ItemValue)>)]
+    ListTypedItems {
+        prefix: Option<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     /// Gets all proposal modules associated with the
     /// contract. Returns Vec<ProposalModule>.
     #[returns(Vec<crate::state::ProposalModule>)]
@@ -125,9 +193,15 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
-    /// Returns information about if the contract is currently paused.
+    /// Returns information about if the contract is currently paused,
+    /// along with the scopes that are currently paused on their own and
+    /// the expiration of each pause.
     #[returns(crate::query::PauseInfoResponse)]
     PauseInfo {},
+    /// Gets the address allowed to pause scopes besides the DAO itself,
+    /// if any. Returns Option<Addr>.
+    #[returns(Option<Addr>)]
+    PauseAuthority {},
     /// Gets the contract's voting module. Returns Addr.
     #[returns(Addr)]
     VotingModule {},
@@ -141,9 +215,25 @@ pub enum QueryMsg {
     /// Returns the SubDAO for a specific address if it in the list
     #[returns(SubDao)]
     GetSubDao { address: String },
+    /// Returns the SubDAO along with its recorded modules, hierarchy,
+    /// pause state and the number of its timelocked proposals, queried
+    /// from the SubDAO core and its timelocks.
+    #[returns(crate::query::SubDaoOverviewResponse)]
+    SubDaoOverview { address: String },
     /// Implements the DAO Star standard: https://daostar.one/EIP
     #[returns(Option<String>)]
     DaoURI {},
+    /// Gets the delay applied to the messages of passed proposals, if
+    /// any. Returns Option<ExecutionDelay>.
+    #[returns(Option<crate::state::ExecutionDelay>)]
+    ExecutionDelay {},
+    /// Lists the messages of passed proposals that were queued because
+    /// of the execution delay, along with their status.
+    #[returns(Vec<crate::state::QueuedExecution>)]
+    ListQueuedExecutions {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -1,10 +1,12 @@
 use cosmwasm_std::{Addr, Uint128};
 use cw2::ContractVersion;
 use cw_utils::Expiration;
+use exec_control::pause::PauseInfoResponse as SubDaoPauseInfoResponse;
+use neutron_subdao_core::types::SubDao;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::state::{Config, ProposalModule};
+use crate::state::{Config, ItemSchema, ItemValue, ProposalModule, ScopedPause};
 
 /// Relevant state for the governance module. Returned by the
 /// `DumpState` query.
@@ -27,11 +29,44 @@ pub struct DumpStateResponse {
     pub total_proposal_module_count: u32,
 }
 
-/// Information about if the contract is currently paused.
+/// Information about if the contract is currently paused, along with
+/// the scopes that are currently paused on their own.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub enum PauseInfoResponse {
-    Paused { expiration: Expiration },
-    Unpaused {},
+    Paused {
+        expiration: Expiration,
+        #[serde(default)]
+        paused_scopes: Vec<ScopedPause>,
+    },
+    Unpaused {
+        #[serde(default)]
+        paused_scopes: Vec<ScopedPause>,
+    },
+}
+
+/// Returned by the `SubDaoOverview` query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SubDaoOverviewResponse {
+    pub subdao: SubDao,
+    /// Whether the SubDAO is currently paused, `None` if the SubDAO
+    /// core could not be queried.
+    pub pause_info: Option<SubDaoPauseInfoResponse>,
+    pub proposal_modules: Vec<Addr>,
+    pub timelocks: Vec<TimelockOverview>,
+    /// The SubDAO this SubDAO belongs to, if any.
+    pub parent: Option<Addr>,
+    /// The SubDAOs this SubDAO is the parent of.
+    pub children: Vec<Addr>,
+}
+
+/// A SubDAO timelock in the `SubDaoOverview` query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TimelockOverview {
+    pub address: Addr,
+    /// The number of proposals waiting in the timelock, `None` if the
+    /// timelock could not be queried, e.g. because it predates the
+    /// `ProposalCount` query.
+    pub timelocked_proposals: Option<u64>,
 }
 
 /// Returned by the `GetItem` query.
@@ -42,6 +77,15 @@ pub struct GetItemResponse {
     pub item: Option<String>,
 }
 
+/// Returned by the `GetTypedItem` query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct GetTypedItemResponse {
+    /// `None` if the key has no schema.
+    pub schema: Option<ItemSchema>,
+    /// `None` if the item has not been set.
+    pub value: Option<ItemValue>,
+}
+
 /// Returned by the `Cw20Balances` query.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct Cw20BalanceResponse {
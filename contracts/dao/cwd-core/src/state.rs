@@ -1,7 +1,9 @@
 use crate::ContractError;
 use cosmwasm_std::Addr;
+use cosmwasm_std::{Coin, CosmosMsg, Decimal, Uint128};
 use cw_storage_plus::{Item, Map};
-use cw_utils::Expiration;
+use cw_utils::{Duration, Expiration};
+use neutron_sdk::bindings::msg::NeutronMsg;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -54,6 +56,132 @@ pub enum ProposalModuleStatus {
     Disabled,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// A part of the DAO that can be paused on its own.
+pub enum PauseScope {
+    /// Execution of the messages of passed proposals, including queued
+    /// ones.
+    ProposalExecution,
+    /// Execution of the messages of the proposals of a single proposal
+    /// module.
+    ProposalModule { address: Addr },
+    /// Updates of the SubDAO list.
+    SubDaoRegistry,
+    /// Updates of the items, typed or not, and of their schemas.
+    Items,
+}
+
+impl PauseScope {
+    /// Returns the key of the scope in `PAUSED_SCOPES`.
+    pub fn key(&self) -> String {
+        match self {
+            PauseScope::ProposalExecution => "proposal_execution".to_string(),
+            PauseScope::ProposalModule { address } => format!("proposal_module:{}", address),
+            PauseScope::SubDaoRegistry => "subdao_registry".to_string(),
+            PauseScope::Items => "items".to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+/// Addresses of a SubDAO's modules and its place in the SubDAO hierarchy.
+pub struct SubDaoMetadata {
+    pub timelocks: Vec<Addr>,
+    pub proposal_modules: Vec<Addr>,
+    /// The SubDAO this SubDAO belongs to, if any.
+    pub parent: Option<Addr>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+/// A scope that is paused until the expiration.
+pub struct ScopedPause {
+    pub scope: PauseScope,
+    pub expiration: Expiration,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+/// Delay applied to the messages of passed proposals before they can be
+/// executed.
+pub struct ExecutionDelay {
+    /// How long the messages stay queued before anyone can execute them.
+    pub delay: Duration,
+    /// The address allowed to veto queued messages until the delay has
+    /// elapsed, i.e. the security subDAO.
+    pub security_dao: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Messages received from a proposal module while an execution delay is set.
+/// The proposal module considers the proposal executed once its messages are
+/// queued, the status here tells whether they were actually executed.
+pub struct QueuedExecution {
+    pub id: u64,
+    /// The proposal module that sent the messages.
+    pub proposal_module: Addr,
+    /// The address allowed to veto the messages, i.e. the security subDAO
+    /// of the execution delay at the time they were queued.
+    pub security_dao: Addr,
+    pub msgs: Vec<CosmosMsg<NeutronMsg>>,
+    /// The messages can be executed once this has expired.
+    pub earliest_execution: Expiration,
+    pub status: QueuedExecutionStatus,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// The status of a queued execution.
+pub enum QueuedExecutionStatus {
+    Queued,
+    Executed,
+    Vetoed,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+/// Declares the type of a typed item and who may write it.
+pub struct ItemSchema {
+    pub item_type: ItemType,
+    /// The proposal modules allowed to write the item, including its
+    /// schema. Any proposal module can if not set. A proposal module may
+    /// only remove its own access, so enabled modules can't be locked out.
+    pub writers: Option<Vec<Addr>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// The type of a typed item.
+pub enum ItemType {
+    Address,
+    Integer,
+    Decimal,
+    Json,
+    Coin,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// The value of a typed item.
+pub enum ItemValue {
+    Address(String),
+    Integer(Uint128),
+    Decimal(Decimal),
+    /// A JSON document, stored as is.
+    Json(String),
+    Coin(Coin),
+}
+
+impl ItemValue {
+    pub fn item_type(&self) -> ItemType {
+        match self {
+            ItemValue::Address(_) => ItemType::Address,
+            ItemValue::Integer(_) => ItemType::Integer,
+            ItemValue::Decimal(_) => ItemType::Decimal,
+            ItemValue::Json(_) => ItemType::Json,
+            ItemValue::Coin(_) => ItemType::Coin,
+        }
+    }
+}
+
 /// The current configuration of the module.
 pub const CONFIG: Item<Config> = Item::new("config_v2");
 
@@ -61,6 +189,13 @@ pub const CONFIG: Item<Config> = Item::new("config_v2");
 /// the DAO has never been paused.
 pub const PAUSED: Item<Expiration> = Item::new("paused");
 
+/// The scopes paused on their own, by scope key. Expired pauses are not
+/// removed.
+pub const PAUSED_SCOPES: Map<String, ScopedPause> = Map::new("paused_scopes");
+
+/// The address allowed to pause and unpause scopes besides the DAO itself.
+pub const PAUSE_AUTHORITY: Item<Addr> = Item::new("pause_authority");
+
 /// The voting module associated with this contract.
 pub const VOTING_REGISTRY_MODULE: Item<Addr> = Item::new("voting_module");
 
@@ -78,9 +213,34 @@ pub const TOTAL_PROPOSAL_MODULE_COUNT: Item<u32> = Item::new("total_proposal_mod
 // General purpose KV store for DAO associated state.
 pub const ITEMS: Map<String, String> = Map::new("items");
 
+/// The schemas of the typed items, by key.
+pub const ITEM_SCHEMAS: Map<String, ItemSchema> = Map::new("item_schemas");
+
+/// Typed items. Each key has a schema in `ITEM_SCHEMAS`, and typed keys are
+/// never present in `ITEMS`.
+pub const TYPED_ITEMS: Map<String, ItemValue> = Map::new("typed_items");
+
+/// The proposal modules whose messages the DAO is executing, innermost last.
+/// Item writes are checked against the innermost one, which is popped once
+/// its last message succeeds.
+pub const EXECUTING_PROPOSAL_MODULES: Item<Vec<Addr>> = Item::new("executing_proposal_modules");
+
 /// List of SubDAOs associated to this DAO. Each SubDAO has an optional charter.
 pub const SUBDAO_LIST: Map<&Addr, Option<String>> = Map::new("sub_daos");
 
+/// Metadata recorded for the SubDAOs in `SUBDAO_LIST`.
+pub const SUBDAO_METADATA: Map<&Addr, SubDaoMetadata> = Map::new("sub_dao_metadata");
+
+/// The delay applied to the messages of passed proposals. Not set if they
+/// are executed right away.
+pub const EXECUTION_DELAY: Item<ExecutionDelay> = Item::new("execution_delay");
+
+/// Messages of passed proposals waiting for their execution delay to elapse.
+pub const QUEUED_EXECUTIONS: Map<u64, QueuedExecution> = Map::new("queued_executions");
+
+/// The count of queued executions ever created, used as the id of the next one.
+pub const QUEUED_EXECUTION_COUNT: Item<u64> = Item::new("queued_execution_count");
+
 #[cfg(test)]
 mod tests {
     use super::Config;
@@ -1,23 +1,64 @@
+use crate::contractmanager_module_param_types::{
+    MsgUpdateParamsContractmanager, ParamsRequestContractmanager, ParamsResponseContractmanager,
+    MSG_TYPE_UPDATE_PARAMS_CONTRACTMANAGER, PARAMS_QUERY_PATH_CONTRACTMANAGER,
+};
 use crate::cron_module_param_types::{
     MsgUpdateParamsCron, ParamsRequestCron, ParamsResponseCron, MSG_TYPE_UPDATE_PARAMS_CRON,
     PARAMS_QUERY_PATH_CRON,
 };
+use crate::dex_module_param_types::{
+    MsgUpdateParamsDex, ParamsRequestDex, ParamsResponseDex, MSG_TYPE_UPDATE_PARAMS_DEX,
+    PARAMS_QUERY_PATH_DEX,
+};
+use crate::feeburner_module_param_types::{
+    MsgUpdateParamsFeeburner, ParamsRequestFeeburner, ParamsResponseFeeburner,
+    MSG_TYPE_UPDATE_PARAMS_FEEBURNER, PARAMS_QUERY_PATH_FEEBURNER,
+};
+use crate::feerefunder_module_param_types::{
+    MsgUpdateParamsFeerefunder, ParamsRequestFeerefunder, ParamsResponseFeerefunder,
+    MSG_TYPE_UPDATE_PARAMS_FEEREFUNDER, PARAMS_QUERY_PATH_FEEREFUNDER,
+};
+use crate::interchaintxs_module_param_types::{
+    MsgUpdateParamsInterchaintxs, ParamsRequestInterchaintxs, ParamsResponseInterchaintxs,
+    MSG_TYPE_UPDATE_PARAMS_INTERCHAINTXS, PARAMS_QUERY_PATH_INTERCHAINTXS,
+};
+use crate::tokenfactory_module_param_types::{
+    MsgUpdateParamsTokenfactory, ParamsRequestTokenfactory, ParamsResponseTokenfactory,
+    MSG_TYPE_UPDATE_PARAMS_TOKENFACTORY, PARAMS_QUERY_PATH_TOKENFACTORY,
+};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+    to_json_binary, to_json_vec, Addr, BankMsg, Binary, BlockInfo, Coin, CosmosMsg, Deps, DepsMut,
+    Env, MessageInfo, Order, Response, StdResult, Storage, WasmMsg,
 };
 use cw2::set_contract_version;
+use cw_paginate::paginate_map_values;
+use cw_storage_plus::Bound;
+use cw_utils::{Duration, Expiration};
 use neutron_sdk::bindings::msg::{AdminProposal, NeutronMsg, ProposalExecuteMessage};
+use neutron_sdk::proto_types::neutron::contractmanager::QueryParamsRequest as ContractmanagerQueryParamsRequest;
 use neutron_sdk::proto_types::neutron::cron::QueryParamsRequest;
+use neutron_sdk::proto_types::neutron::dex::QueryParamsRequest as DexQueryParamsRequest;
+use neutron_sdk::proto_types::neutron::feeburner::QueryParamsRequest as FeeburnerQueryParamsRequest;
+use neutron_sdk::proto_types::neutron::feerefunder::QueryParamsRequest as FeerefunderQueryParamsRequest;
+use neutron_sdk::proto_types::neutron::interchaintxs::v1::QueryParamsRequest as InterchaintxsQueryParamsRequest;
+use neutron_sdk::proto_types::osmosis::tokenfactory::v1beta1::QueryParamsRequest as TokenfactoryQueryParamsRequest;
 use neutron_sdk::stargate::aux::make_stargate_query;
 
 use crate::error::ContractError;
 use crate::msg::{
-    ExecuteMsg, InstantiateMsg, MigrateMsg, Permission, ProposalExecuteMessageJSON, QueryMsg,
-    Strategy, StrategyMsg,
+    AuditLogAction, AuditLogEntry, Batch, CheckMessagesResponse, ExecuteMsg, InstantiateMsg,
+    MessageCheck, MigrateMsg, PermissionType, PermissionUsage, ProposalExecuteMessageJSON,
+    QueryMsg, RateLimit, RateLimitsResponse, Strategy, StrategyMsg, StrategyResponse,
+    StrategySnapshot,
 };
-use crate::state::STRATEGIES;
+use crate::serde_helpers::MessageName;
+use crate::state::{
+    AUDIT_LOG, AUDIT_LOG_BY_SENDER, AUDIT_LOG_NEXT_ID, BATCHES, BATCH_NEXT_ID, PERMISSION_USAGE,
+    RATE_LIMITS, STRATEGIES, STRATEGY_EXPIRATIONS,
+};
+use serde::Serialize;
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:neutron-chain-manager";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -25,8 +66,8 @@ pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
-    _env: Env,
-    _info: MessageInfo,
+    env: Env,
+    info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
@@ -36,6 +77,20 @@ pub fn instantiate(
         msg.initial_strategy_address.clone(),
         &Strategy::AllowAll,
     )?;
+    log_action(
+        deps.storage,
+        &env.block,
+        info.sender,
+        AuditLogAction::AddStrategy {
+            address: msg.initial_strategy_address.clone(),
+            before: None,
+            after: StrategySnapshot {
+                strategy: StrategyMsg::AllowAll,
+                expiration: Expiration::Never {},
+                rate_limits: vec![],
+            },
+        },
+    )?;
 
     Ok(Response::new()
         .add_attribute("action", "instantiate")
@@ -45,55 +100,124 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response<NeutronMsg>, ContractError> {
     match msg {
-        ExecuteMsg::AddStrategy { address, strategy } => {
-            execute_add_strategy(deps, info, address, strategy)
+        ExecuteMsg::AddStrategy {
+            address,
+            strategy,
+            expiration,
+            rate_limits,
+        } => execute_add_strategy(deps, env, info, address, strategy, expiration, rate_limits),
+        ExecuteMsg::RemoveStrategy { address } => execute_remove_strategy(deps, env, info, address),
+        ExecuteMsg::ExecuteMessages { messages } => {
+            execute_execute_messages(deps, env, info, messages)
         }
-        ExecuteMsg::RemoveStrategy { address } => execute_remove_strategy(deps, info, address),
-        ExecuteMsg::ExecuteMessages { messages } => execute_execute_messages(deps, info, messages),
+        ExecuteMsg::SubmitBatch {
+            strategy_address,
+            messages,
+        } => execute_submit_batch(deps, env, info, strategy_address, messages),
+        ExecuteMsg::ApproveBatch { batch_id } => execute_approve_batch(deps, env, info, batch_id),
+        ExecuteMsg::ExecuteBatch { batch_id } => execute_execute_batch(deps, env, info, batch_id),
+        ExecuteMsg::CancelBatch { batch_id } => execute_cancel_batch(deps, env, info, batch_id),
     }
 }
 
 pub fn execute_add_strategy(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     address: Addr,
     strategy: StrategyMsg,
+    expiration: Option<Expiration>,
+    rate_limits: Option<Vec<RateLimit>>,
 ) -> Result<Response<NeutronMsg>, ContractError> {
-    is_authorized(deps.as_ref(), info.sender.clone())?;
+    is_authorized(deps.as_ref(), &env.block, info.sender.clone())?;
+
+    let expiration = expiration.unwrap_or_default();
+    if expiration.is_expired(&env.block) {
+        return Err(ContractError::InvalidExpiration {});
+    }
+
+    let strategy: Strategy = strategy.into();
+    validate_multi_party_strategy(&strategy)?;
+    let rate_limits = rate_limits.unwrap_or_default();
+    validate_rate_limits(&strategy, &rate_limits)?;
+
+    let before = load_strategy_snapshot(deps.storage, address.clone())?;
 
     // We add the new strategy, and then we check that it did not replace
-    // the only existing ALLOW_ALL strategy.
-    STRATEGIES.save(deps.storage, address.clone(), &strategy.clone().into())?;
-    if let StrategyMsg::AllowOnly(_) = strategy {
-        if no_admins_left(deps.as_ref())? {
-            return Err(ContractError::InvalidDemotion {});
-        }
+    // the only existing non-expiring ALLOW_ALL strategy.
+    STRATEGIES.save(deps.storage, address.clone(), &strategy)?;
+    match expiration {
+        Expiration::Never {} => STRATEGY_EXPIRATIONS.remove(deps.storage, address.clone()),
+        _ => STRATEGY_EXPIRATIONS.save(deps.storage, address.clone(), &expiration)?,
+    }
+    // Replacing a strategy also resets its rate limits and their usage, and
+    // drops the batches approved under the previous strategy.
+    if rate_limits.is_empty() {
+        RATE_LIMITS.remove(deps.storage, address.clone());
+    } else {
+        RATE_LIMITS.save(deps.storage, address.clone(), &rate_limits)?;
+    }
+    PERMISSION_USAGE.remove(deps.storage, address.clone());
+    remove_batches(deps.storage, &address)?;
+    if no_admins_left(deps.as_ref())? {
+        return Err(ContractError::InvalidDemotion {});
     }
 
+    let after = load_strategy_snapshot(deps.storage, address.clone())?
+        .ok_or(ContractError::Unauthorized {})?;
+    log_action(
+        deps.storage,
+        &env.block,
+        info.sender,
+        AuditLogAction::AddStrategy {
+            address: address.clone(),
+            before,
+            after,
+        },
+    )?;
+
     Ok(Response::new()
         .add_attribute("action", "execute_add_strategy")
-        .add_attribute("address", address))
+        .add_attribute("address", address)
+        .add_attribute("expiration", expiration.to_string()))
 }
 
 pub fn execute_remove_strategy(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     address: Addr,
 ) -> Result<Response<NeutronMsg>, ContractError> {
-    is_authorized(deps.as_ref(), info.sender.clone())?;
+    is_authorized(deps.as_ref(), &env.block, info.sender.clone())?;
+
+    let before = load_strategy_snapshot(deps.storage, address.clone())?;
 
     // First we remove the strategy, then we check that it was not the only
     // ALLOW_ALL strategy we had.
     STRATEGIES.remove(deps.storage, address.clone());
+    STRATEGY_EXPIRATIONS.remove(deps.storage, address.clone());
+    RATE_LIMITS.remove(deps.storage, address.clone());
+    PERMISSION_USAGE.remove(deps.storage, address.clone());
+    remove_batches(deps.storage, &address)?;
     if no_admins_left(deps.as_ref())? {
         return Err(ContractError::InvalidDemotion {});
     }
 
+    log_action(
+        deps.storage,
+        &env.block,
+        info.sender,
+        AuditLogAction::RemoveStrategy {
+            address: address.clone(),
+            before,
+        },
+    )?;
+
     Ok(Response::new()
         .add_attribute("action", "execute_remove_strategy")
         .add_attribute("address", address))
@@ -101,101 +225,702 @@ pub fn execute_remove_strategy(
 
 pub fn execute_execute_messages(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     messages: Vec<CosmosMsg<NeutronMsg>>,
 ) -> Result<Response<NeutronMsg>, ContractError> {
-    // If the sender doesn't have a strategy associated with them, abort immediately.
-    if !STRATEGIES.has(deps.storage, info.sender.clone()) {
-        return Err(ContractError::Unauthorized {});
-    }
+    // If the sender doesn't have an active strategy associated with them,
+    // abort immediately.
+    let strategy = load_active_strategy(deps.as_ref(), &env.block, info.sender.clone())?;
 
     let response = Response::new()
         .add_attribute("action", "execute_execute_messages")
         .add_attribute("address", info.sender.clone());
 
-    let strategy = STRATEGIES.load(deps.storage, info.sender)?;
-    match strategy {
-        Strategy::AllowAll => Ok(response
-            .add_attribute("strategy", "allow_all")
-            .add_messages(messages)),
+    let response = match strategy {
+        Strategy::AllowAll => response.add_attribute("strategy", "allow_all"),
         Strategy::AllowOnly(_) => {
-            check_allow_only_permissions(deps.as_ref(), strategy.clone(), messages.clone())?;
-            Ok(response
-                .add_attribute("strategy", "allow_only")
-                .add_messages(messages))
+            let used_permissions =
+                check_allow_only_permissions(deps.as_ref(), strategy.clone(), messages.clone())?;
+            apply_rate_limits(
+                deps.storage,
+                &env.block,
+                info.sender.clone(),
+                used_permissions,
+            )?;
+            response.add_attribute("strategy", "allow_only")
         }
+        Strategy::MultiParty { .. } => return Err(ContractError::ApprovalRequired {}),
+    };
+
+    log_action(
+        deps.storage,
+        &env.block,
+        info.sender,
+        AuditLogAction::ExecuteMessages {
+            strategy: strategy.strategy_type(),
+            message_types: messages.iter().map(message_type_url).collect(),
+        },
+    )?;
+
+    Ok(response.add_messages(messages))
+}
+
+pub fn execute_submit_batch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    strategy_address: Addr,
+    messages: Vec<CosmosMsg<NeutronMsg>>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let (strategy, _) = load_multi_party_strategy(
+        deps.as_ref(),
+        &env.block,
+        strategy_address.clone(),
+        &info.sender,
+    )?;
+    // There is no point in collecting approvals for a batch the strategy
+    // does not allow.
+    check_allow_only_permissions(deps.as_ref(), strategy, messages.clone())?;
+
+    let batch_id = BATCH_NEXT_ID.may_load(deps.storage)?.unwrap_or_default();
+    BATCHES.save(
+        deps.storage,
+        batch_id,
+        &Batch {
+            id: batch_id,
+            strategy_address: strategy_address.clone(),
+            proposer: info.sender.clone(),
+            messages,
+            approvals: vec![info.sender.clone()],
+            submitted_at_height: env.block.height,
+        },
+    )?;
+    BATCH_NEXT_ID.save(deps.storage, &(batch_id + 1))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_submit_batch")
+        .add_attribute("address", info.sender)
+        .add_attribute("strategy_address", strategy_address)
+        .add_attribute("batch_id", batch_id.to_string()))
+}
+
+pub fn execute_approve_batch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    batch_id: u64,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let mut batch = load_batch(deps.storage, batch_id)?;
+    load_multi_party_strategy(
+        deps.as_ref(),
+        &env.block,
+        batch.strategy_address.clone(),
+        &info.sender,
+    )?;
+    if batch.approvals.contains(&info.sender) {
+        return Err(ContractError::AlreadyApproved {});
+    }
+
+    batch.approvals.push(info.sender.clone());
+    BATCHES.save(deps.storage, batch_id, &batch)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_approve_batch")
+        .add_attribute("address", info.sender)
+        .add_attribute("batch_id", batch_id.to_string())
+        .add_attribute("approvals", batch.approvals.len().to_string()))
+}
+
+pub fn execute_execute_batch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    batch_id: u64,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let batch = load_batch(deps.storage, batch_id)?;
+    let (strategy, threshold) = load_multi_party_strategy(
+        deps.as_ref(),
+        &env.block,
+        batch.strategy_address.clone(),
+        &info.sender,
+    )?;
+    let approvals = batch.approvals.len() as u64;
+    if approvals < threshold {
+        return Err(ContractError::NotEnoughApprovals {
+            approvals,
+            threshold,
+        });
     }
+
+    // The parameters of the modules may have changed since the submission,
+    // so the permissions are checked again.
+    let used_permissions =
+        check_allow_only_permissions(deps.as_ref(), strategy, batch.messages.clone())?;
+    apply_rate_limits(
+        deps.storage,
+        &env.block,
+        batch.strategy_address.clone(),
+        used_permissions,
+    )?;
+    BATCHES.remove(deps.storage, batch_id);
+
+    log_action(
+        deps.storage,
+        &env.block,
+        info.sender.clone(),
+        AuditLogAction::ExecuteBatch {
+            batch_id,
+            strategy_address: batch.strategy_address.clone(),
+            approvals: batch.approvals,
+            message_types: batch.messages.iter().map(message_type_url).collect(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_execute_batch")
+        .add_attribute("address", info.sender)
+        .add_attribute("strategy_address", batch.strategy_address)
+        .add_attribute("batch_id", batch_id.to_string())
+        .add_messages(batch.messages))
+}
+
+pub fn execute_cancel_batch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    batch_id: u64,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let batch = load_batch(deps.storage, batch_id)?;
+    load_multi_party_strategy(
+        deps.as_ref(),
+        &env.block,
+        batch.strategy_address,
+        &info.sender,
+    )?;
+    BATCHES.remove(deps.storage, batch_id);
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_cancel_batch")
+        .add_attribute("address", info.sender)
+        .add_attribute("batch_id", batch_id.to_string()))
+}
+
+fn load_batch(storage: &dyn Storage, batch_id: u64) -> Result<Batch, ContractError> {
+    BATCHES
+        .may_load(storage, batch_id)?
+        .ok_or(ContractError::BatchNotFound { batch_id })
+}
+
+/// Loads the active MULTI_PARTY strategy of the given address along with its
+/// approval threshold, failing if the sender is not one of its approvers.
+fn load_multi_party_strategy(
+    deps: Deps,
+    block: &BlockInfo,
+    strategy_address: Addr,
+    sender: &Addr,
+) -> Result<(Strategy, u64), ContractError> {
+    let strategy = load_active_strategy(deps, block, strategy_address)?;
+    match &strategy {
+        Strategy::MultiParty {
+            approvers,
+            threshold,
+            ..
+        } if approvers.contains(sender) => {
+            let threshold = *threshold;
+            Ok((strategy, threshold))
+        }
+        _ => Err(ContractError::Unauthorized {}),
+    }
+}
+
+/// Removes the pending batches of the given address.
+fn remove_batches(storage: &mut dyn Storage, address: &Addr) -> StdResult<()> {
+    let batch_ids = BATCHES
+        .range(storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((batch_id, batch)) if batch.strategy_address == *address => Some(Ok(batch_id)),
+            Ok(_) => None,
+            Err(err) => Some(Err(err)),
+        })
+        .collect::<StdResult<Vec<u64>>>()?;
+    for batch_id in batch_ids {
+        BATCHES.remove(storage, batch_id);
+    }
+
+    Ok(())
+}
+
+/// Appends an entry to the audit log.
+fn log_action(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    sender: Addr,
+    action: AuditLogAction,
+) -> Result<(), ContractError> {
+    let id = AUDIT_LOG_NEXT_ID.may_load(storage)?.unwrap_or_default();
+    AUDIT_LOG.save(
+        storage,
+        id,
+        &AuditLogEntry {
+            id,
+            height: block.height,
+            sender: sender.clone(),
+            action,
+        },
+    )?;
+    AUDIT_LOG_BY_SENDER.save(storage, (sender, id), &())?;
+    AUDIT_LOG_NEXT_ID.save(storage, &(id + 1))?;
+
+    Ok(())
+}
+
+/// Returns the current strategy of the address along with its expiration and
+/// rate limits, if it has one.
+fn load_strategy_snapshot(
+    storage: &dyn Storage,
+    address: Addr,
+) -> StdResult<Option<StrategySnapshot>> {
+    let strategy = match STRATEGIES.may_load(storage, address.clone())? {
+        Some(strategy) => strategy,
+        None => return Ok(None),
+    };
+
+    Ok(Some(StrategySnapshot {
+        strategy: strategy.into(),
+        expiration: STRATEGY_EXPIRATIONS
+            .may_load(storage, address.clone())?
+            .unwrap_or_default(),
+        rate_limits: RATE_LIMITS.may_load(storage, address)?.unwrap_or_default(),
+    }))
+}
+
+/// Describes a message by its type URL. Neutron bindings and other messages
+/// without a well-known type URL are described by their JSON name instead,
+/// e.g. `burn_tokens`.
+fn message_type_url(msg: &CosmosMsg<NeutronMsg>) -> String {
+    let type_url = match msg {
+        CosmosMsg::Bank(BankMsg::Send { .. }) => "/cosmos.bank.v1beta1.MsgSend",
+        CosmosMsg::Wasm(WasmMsg::Execute { .. }) => "/cosmwasm.wasm.v1.MsgExecuteContract",
+        CosmosMsg::Wasm(WasmMsg::Instantiate { .. }) => "/cosmwasm.wasm.v1.MsgInstantiateContract",
+        CosmosMsg::Wasm(WasmMsg::Migrate { .. }) => "/cosmwasm.wasm.v1.MsgMigrateContract",
+        CosmosMsg::Wasm(WasmMsg::UpdateAdmin { .. }) => "/cosmwasm.wasm.v1.MsgUpdateAdmin",
+        CosmosMsg::Wasm(WasmMsg::ClearAdmin { .. }) => "/cosmwasm.wasm.v1.MsgClearAdmin",
+        CosmosMsg::Custom(NeutronMsg::AddSchedule { .. }) => "/neutron.cron.MsgAddSchedule",
+        CosmosMsg::Custom(NeutronMsg::RemoveSchedule { .. }) => "/neutron.cron.MsgRemoveSchedule",
+        CosmosMsg::Custom(NeutronMsg::SubmitAdminProposal {
+            admin_proposal: AdminProposal::ParamChangeProposal(_),
+        }) => "/cosmos.params.v1beta1.ParameterChangeProposal",
+        CosmosMsg::Custom(NeutronMsg::SubmitAdminProposal {
+            admin_proposal: AdminProposal::ProposalExecuteMessage(proposal),
+        }) => {
+            if let Ok(typed_proposal) =
+                serde_json_wasm::from_str::<ProposalExecuteMessageJSON>(proposal.message.as_str())
+            {
+                return typed_proposal.type_field;
+            }
+            return message_name(proposal);
+        }
+        CosmosMsg::Custom(neutron_msg) => return message_name(neutron_msg),
+        _ => return message_name(msg),
+    };
+
+    type_url.to_string()
+}
+
+/// Returns the name of the only top-level key of the serialized value.
+fn message_name<T: Serialize>(value: &T) -> String {
+    to_json_vec(value)
+        .ok()
+        .and_then(|json| serde_json_wasm::from_slice::<MessageName>(&json).ok())
+        .map_or_else(|| "unknown".to_string(), |MessageName(name)| name)
 }
 
-fn is_authorized(deps: Deps, address: Addr) -> Result<(), ContractError> {
-    match STRATEGIES.load(deps.storage, address) {
-        Ok(Strategy::AllowAll) => Ok(()),
+fn is_authorized(deps: Deps, block: &BlockInfo, address: Addr) -> Result<(), ContractError> {
+    match load_active_strategy(deps, block, address)? {
+        Strategy::AllowAll => Ok(()),
         _ => Err(ContractError::Unauthorized {}),
     }
 }
 
-/// This function returns true if there is no more allow_all strategies left.
+/// Loads the strategy of the given address, failing if there is none or if
+/// it has already expired.
+fn load_active_strategy(
+    deps: Deps,
+    block: &BlockInfo,
+    address: Addr,
+) -> Result<Strategy, ContractError> {
+    let strategy = STRATEGIES
+        .may_load(deps.storage, address.clone())?
+        .ok_or(ContractError::Unauthorized {})?;
+    if let Some(expiration) = STRATEGY_EXPIRATIONS.may_load(deps.storage, address)? {
+        if expiration.is_expired(block) {
+            return Err(ContractError::StrategyExpired {});
+        }
+    }
+
+    Ok(strategy)
+}
+
+/// This function returns true if there is no more non-expiring allow_all
+/// strategies left. Expiring ALLOW_ALL strategies are not taken into account,
+/// otherwise the contract could end up without any admin once they lapse.
 fn no_admins_left(deps: Deps) -> Result<bool, ContractError> {
     let not_found: bool = !STRATEGIES
         .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
         .collect::<Result<Vec<(Addr, Strategy)>, _>>()?
         .into_iter()
-        .any(|(_, strategy)| matches!(strategy, Strategy::AllowAll));
+        .any(|(addr, strategy)| {
+            matches!(strategy, Strategy::AllowAll) && !STRATEGY_EXPIRATIONS.has(deps.storage, addr)
+        });
 
     Ok(not_found)
 }
 
+/// Checks that the approvers of a MULTI_PARTY strategy are unique and that
+/// its threshold can be reached.
+fn validate_multi_party_strategy(strategy: &Strategy) -> Result<(), ContractError> {
+    if let Strategy::MultiParty {
+        approvers,
+        threshold,
+        ..
+    } = strategy
+    {
+        if approvers
+            .iter()
+            .enumerate()
+            .any(|(i, approver)| approvers[..i].contains(approver))
+        {
+            return Err(ContractError::InvalidMultiPartyStrategy {
+                reason: "duplicate approver".to_string(),
+            });
+        }
+        if *threshold == 0 || *threshold > approvers.len() as u64 {
+            return Err(ContractError::InvalidMultiPartyStrategy {
+                reason: "threshold must be between 1 and the number of approvers".to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the rate limits only refer to permissions granted by an
+/// ALLOW_ONLY or MULTI_PARTY strategy, at most once per permission, and
+/// actually limit something.
+fn validate_rate_limits(
+    strategy: &Strategy,
+    rate_limits: &[RateLimit],
+) -> Result<(), ContractError> {
+    for (i, rate_limit) in rate_limits.iter().enumerate() {
+        match strategy {
+            Strategy::AllowOnly(permissions) | Strategy::MultiParty { permissions, .. }
+                if permissions.contains_key(&rate_limit.permission_type) => {}
+            _ => {
+                return Err(ContractError::InvalidRateLimit {
+                    reason: format!(
+                        "strategy has no {:?} permission",
+                        rate_limit.permission_type
+                    ),
+                })
+            }
+        }
+        if rate_limits[..i]
+            .iter()
+            .any(|r| r.permission_type == rate_limit.permission_type)
+        {
+            return Err(ContractError::InvalidRateLimit {
+                reason: format!("duplicate limit for {:?}", rate_limit.permission_type),
+            });
+        }
+        if rate_limit.quota.is_none() && rate_limit.cooldown.is_none() {
+            return Err(ContractError::InvalidRateLimit {
+                reason: "either a quota or a cooldown must be set".to_string(),
+            });
+        }
+        if let Some(quota) = &rate_limit.quota {
+            if quota.max_messages == 0 || is_zero_duration(quota.window) {
+                return Err(ContractError::InvalidRateLimit {
+                    reason: "quota must allow messages over a non-empty window".to_string(),
+                });
+            }
+        }
+        if let Some(cooldown) = rate_limit.cooldown {
+            if is_zero_duration(cooldown) {
+                return Err(ContractError::InvalidRateLimit {
+                    reason: "cooldown must not be empty".to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn is_zero_duration(duration: Duration) -> bool {
+    matches!(duration, Duration::Height(0) | Duration::Time(0))
+}
+
+/// Records the usage of every rate limited permission in the batch. Fails if
+/// the batch exceeds a quota or uses a permission that is still cooling down.
+fn apply_rate_limits(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    address: Addr,
+    used_permissions: Vec<PermissionType>,
+) -> Result<(), ContractError> {
+    if let Some(all_usage) = check_rate_limits(storage, block, address.clone(), used_permissions)? {
+        PERMISSION_USAGE.save(storage, address, &all_usage)?;
+    }
+
+    Ok(())
+}
+
+/// Computes the usage of the rate limited permissions after the batch, without
+/// saving it. Returns `None` if the address has no rate limits.
+fn check_rate_limits(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    address: Addr,
+    used_permissions: Vec<PermissionType>,
+) -> Result<Option<Vec<PermissionUsage>>, ContractError> {
+    let rate_limits = RATE_LIMITS
+        .may_load(storage, address.clone())?
+        .unwrap_or_default();
+    if rate_limits.is_empty() {
+        return Ok(None);
+    }
+
+    let mut all_usage = PERMISSION_USAGE
+        .may_load(storage, address.clone())?
+        .unwrap_or_default();
+    for rate_limit in rate_limits {
+        let messages = used_permissions
+            .iter()
+            .filter(|p| **p == rate_limit.permission_type)
+            .count() as u64;
+        if messages == 0 {
+            continue;
+        }
+
+        let index = match all_usage
+            .iter()
+            .position(|u| u.permission_type == rate_limit.permission_type)
+        {
+            Some(index) => index,
+            None => {
+                all_usage.push(PermissionUsage::new(rate_limit.permission_type.clone()));
+                all_usage.len() - 1
+            }
+        };
+        record_usage(&mut all_usage[index], &rate_limit, block, messages)?;
+    }
+
+    Ok(Some(all_usage))
+}
+
+fn record_usage(
+    usage: &mut PermissionUsage,
+    rate_limit: &RateLimit,
+    block: &BlockInfo,
+    messages: u64,
+) -> Result<(), ContractError> {
+    if let Some(cooldown_expiration) = usage.cooldown_expiration {
+        if !cooldown_expiration.is_expired(block) {
+            return Err(ContractError::CooldownActive {
+                permission_type: rate_limit.permission_type.clone(),
+            });
+        }
+    }
+
+    if let Some(quota) = &rate_limit.quota {
+        // Start a new window if there is none yet or the current one is over.
+        if usage.window_expiration.map_or(true, |window_expiration| {
+            window_expiration.is_expired(block)
+        }) {
+            usage.messages_in_window = 0;
+            usage.window_expiration = Some(quota.window.after(block));
+        }
+        usage.messages_in_window = usage.messages_in_window.saturating_add(messages);
+        if usage.messages_in_window > quota.max_messages {
+            return Err(ContractError::RateLimitExceeded {
+                permission_type: rate_limit.permission_type.clone(),
+            });
+        }
+    }
+
+    if let Some(cooldown) = rate_limit.cooldown {
+        usage.cooldown_expiration = Some(cooldown.after(block));
+    }
+
+    Ok(())
+}
+
 /// For every message, check whether we have the permission to execute it.
 /// Any missing permission aborts the execution. Trying to execute any
-/// unknown message aborts the execution.
+/// unknown message aborts the execution. Returns the permission used by
+/// each message.
 fn check_allow_only_permissions(
     deps: Deps,
     strategy: Strategy,
     messages: Vec<CosmosMsg<NeutronMsg>>,
-) -> Result<(), ContractError> {
-    for msg in messages.clone() {
-        if let CosmosMsg::Custom(neutron_msg) = msg {
-            check_neutron_msg(deps, strategy.clone(), neutron_msg)?
-        } else {
-            return Err(ContractError::Unauthorized {});
+) -> Result<Vec<PermissionType>, ContractError> {
+    let mut used_permissions = Vec::with_capacity(messages.len());
+    let mut sent = vec![];
+    for msg in messages {
+        used_permissions.push(check_allow_only_message(
+            deps,
+            strategy.clone(),
+            msg,
+            &mut sent,
+        )?);
+    }
+
+    Ok(used_permissions)
+}
+
+/// Checks a single message. `sent` holds the funds sent by the previous
+/// messages, so that the bank send caps apply to the whole message list, and
+/// is extended with the funds of the message if it is allowed.
+fn check_allow_only_message(
+    deps: Deps,
+    strategy: Strategy,
+    msg: CosmosMsg<NeutronMsg>,
+    sent: &mut Vec<(String, Coin)>,
+) -> Result<PermissionType, ContractError> {
+    match msg {
+        CosmosMsg::Custom(neutron_msg) => check_neutron_msg(deps, strategy, neutron_msg),
+        CosmosMsg::Wasm(wasm_msg) => check_wasm_msg(&strategy, wasm_msg, sent),
+        CosmosMsg::Bank(bank_msg) => check_bank_msg(&strategy, bank_msg, sent),
+        _ => Err(ContractError::Unauthorized {}),
+    }
+}
+
+/// Returns the type of the permission needed to execute the message, or
+/// `None` if the message can only be executed with an ALLOW_ALL strategy.
+fn required_permission_type(msg: &CosmosMsg<NeutronMsg>) -> Option<PermissionType> {
+    match msg {
+        CosmosMsg::Custom(NeutronMsg::AddSchedule { .. })
+        | CosmosMsg::Custom(NeutronMsg::RemoveSchedule { .. }) => {
+            Some(PermissionType::CronPermission)
         }
+        CosmosMsg::Custom(NeutronMsg::SubmitAdminProposal { admin_proposal }) => {
+            match admin_proposal {
+                AdminProposal::ParamChangeProposal(_) => {
+                    Some(PermissionType::ParamChangePermission)
+                }
+                AdminProposal::ProposalExecuteMessage(proposal) => {
+                    let typed_proposal: ProposalExecuteMessageJSON =
+                        serde_json_wasm::from_str(proposal.message.as_str()).ok()?;
+                    update_params_permission_type(typed_proposal.type_field.as_str())
+                }
+                _ => None,
+            }
+        }
+        CosmosMsg::Wasm(WasmMsg::Execute { .. }) => Some(PermissionType::WasmExecutePermission),
+        CosmosMsg::Bank(BankMsg::Send { .. }) => Some(PermissionType::BankSendPermission),
+        _ => None,
     }
+}
 
-    Ok(())
+/// Maps a `MsgUpdateParams` type URL to the permission needed to submit it.
+fn update_params_permission_type(type_url: &str) -> Option<PermissionType> {
+    match type_url {
+        MSG_TYPE_UPDATE_PARAMS_CRON => Some(PermissionType::UpdateParamsPermission),
+        MSG_TYPE_UPDATE_PARAMS_TOKENFACTORY => {
+            Some(PermissionType::TokenfactoryUpdateParamsPermission)
+        }
+        MSG_TYPE_UPDATE_PARAMS_DEX => Some(PermissionType::DexUpdateParamsPermission),
+        MSG_TYPE_UPDATE_PARAMS_INTERCHAINTXS => {
+            Some(PermissionType::InterchaintxsUpdateParamsPermission)
+        }
+        MSG_TYPE_UPDATE_PARAMS_FEEBURNER => Some(PermissionType::FeeburnerUpdateParamsPermission),
+        MSG_TYPE_UPDATE_PARAMS_FEEREFUNDER => {
+            Some(PermissionType::FeerefunderUpdateParamsPermission)
+        }
+        MSG_TYPE_UPDATE_PARAMS_CONTRACTMANAGER => {
+            Some(PermissionType::ContractmanagerUpdateParamsPermission)
+        }
+        _ => None,
+    }
+}
+
+/// Only `WasmMsg::Execute` messages to allow-listed contracts are supported.
+/// Funds attached to the message are a transfer to the contract, so they must
+/// be allowed by the bank send permission as well.
+fn check_wasm_msg(
+    strategy: &Strategy,
+    wasm_msg: WasmMsg,
+    sent: &mut Vec<(String, Coin)>,
+) -> Result<PermissionType, ContractError> {
+    match wasm_msg {
+        WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        } => {
+            let MessageName(msg_name) = serde_json_wasm::from_slice(msg.as_slice())?;
+            if !strategy.has_wasm_execute_permission(&contract_addr, &msg_name) {
+                return Err(ContractError::Unauthorized {});
+            }
+            if !funds.is_empty() && !strategy.has_bank_send_permission(&contract_addr, &funds, sent)
+            {
+                return Err(ContractError::Unauthorized {});
+            }
+            sent.extend(funds.into_iter().map(|coin| (contract_addr.clone(), coin)));
+            Ok(PermissionType::WasmExecutePermission)
+        }
+        _ => Err(ContractError::Unauthorized {}),
+    }
+}
+
+fn check_bank_msg(
+    strategy: &Strategy,
+    bank_msg: BankMsg,
+    sent: &mut Vec<(String, Coin)>,
+) -> Result<PermissionType, ContractError> {
+    match bank_msg {
+        BankMsg::Send { to_address, amount } => {
+            if !strategy.has_bank_send_permission(&to_address, &amount, sent) {
+                return Err(ContractError::Unauthorized {});
+            }
+            sent.extend(amount.into_iter().map(|coin| (to_address.clone(), coin)));
+            Ok(PermissionType::BankSendPermission)
+        }
+        _ => Err(ContractError::Unauthorized {}),
+    }
 }
 
 fn check_neutron_msg(
     deps: Deps,
     strategy: Strategy,
     neutron_msg: NeutronMsg,
-) -> Result<(), ContractError> {
+) -> Result<PermissionType, ContractError> {
     match neutron_msg {
         NeutronMsg::AddSchedule { .. } => {
             if !strategy.has_cron_add_schedule_permission() {
                 return Err(ContractError::Unauthorized {});
             }
+            Ok(PermissionType::CronPermission)
         }
         NeutronMsg::RemoveSchedule { name: _ } => {
             if !strategy.has_cron_remove_schedule_permission() {
                 return Err(ContractError::Unauthorized {});
             }
+            Ok(PermissionType::CronPermission)
         }
         NeutronMsg::SubmitAdminProposal { admin_proposal } => {
-            check_submit_admin_proposal_message(deps, strategy, admin_proposal)?;
-        }
-        _ => {
-            return Err(ContractError::Unauthorized {});
+            check_submit_admin_proposal_message(deps, strategy, admin_proposal)
         }
+        _ => Err(ContractError::Unauthorized {}),
     }
-
-    Ok(())
 }
 
 fn check_submit_admin_proposal_message(
     deps: Deps,
     strategy: Strategy,
     proposal: AdminProposal,
-) -> Result<(), ContractError> {
+) -> Result<PermissionType, ContractError> {
     match proposal {
         AdminProposal::ParamChangeProposal(proposal) => {
             for param_change in proposal.param_changes {
@@ -203,16 +928,13 @@ fn check_submit_admin_proposal_message(
                     return Err(ContractError::Unauthorized {});
                 }
             }
+            Ok(PermissionType::ParamChangePermission)
         }
         AdminProposal::ProposalExecuteMessage(proposal) => {
-            check_proposal_execute_message(deps, strategy.clone(), proposal)?;
-        }
-        _ => {
-            return Err(ContractError::Unauthorized {});
+            check_proposal_execute_message(deps, strategy.clone(), proposal)
         }
+        _ => Err(ContractError::Unauthorized {}),
     }
-
-    Ok(())
 }
 
 /// Processes ProposalExecuteMessage messages. Message type has to be checked
@@ -222,17 +944,43 @@ fn check_proposal_execute_message(
     deps: Deps,
     strategy: Strategy,
     proposal: ProposalExecuteMessage,
-) -> Result<(), ContractError> {
+) -> Result<PermissionType, ContractError> {
     let typed_proposal: ProposalExecuteMessageJSON =
         serde_json_wasm::from_str(proposal.message.as_str())?;
 
-    if typed_proposal.type_field.as_str() == MSG_TYPE_UPDATE_PARAMS_CRON {
-        check_cron_update_msg_params(deps, strategy, proposal)?;
-        Ok(())
-    } else {
-        Err(ContractError::Unauthorized {})
+    match typed_proposal.type_field.as_str() {
+        MSG_TYPE_UPDATE_PARAMS_CRON => {
+            check_cron_update_msg_params(deps, strategy, proposal)?;
+            Ok(PermissionType::UpdateParamsPermission)
+        }
+        MSG_TYPE_UPDATE_PARAMS_TOKENFACTORY => {
+            check_tokenfactory_update_msg_params(deps, strategy, proposal)?;
+            Ok(PermissionType::TokenfactoryUpdateParamsPermission)
+        }
+        MSG_TYPE_UPDATE_PARAMS_DEX => {
+            check_dex_update_msg_params(deps, strategy, proposal)?;
+            Ok(PermissionType::DexUpdateParamsPermission)
+        }
+        MSG_TYPE_UPDATE_PARAMS_INTERCHAINTXS => {
+            check_interchaintxs_update_msg_params(deps, strategy, proposal)?;
+            Ok(PermissionType::InterchaintxsUpdateParamsPermission)
+        }
+        MSG_TYPE_UPDATE_PARAMS_FEEBURNER => {
+            check_feeburner_update_msg_params(deps, strategy, proposal)?;
+            Ok(PermissionType::FeeburnerUpdateParamsPermission)
+        }
+        MSG_TYPE_UPDATE_PARAMS_FEEREFUNDER => {
+            check_feerefunder_update_msg_params(deps, strategy, proposal)?;
+            Ok(PermissionType::FeerefunderUpdateParamsPermission)
+        }
+        MSG_TYPE_UPDATE_PARAMS_CONTRACTMANAGER => {
+            check_contractmanager_update_msg_params(deps, strategy, proposal)?;
+            Ok(PermissionType::ContractmanagerUpdateParamsPermission)
+        }
+        _ => Err(ContractError::Unauthorized {}),
     }
 }
+
 /// Checks that the strategy owner is authorised to change the parameters of the
 /// cron module. We query the current values for each parameter & compare them to
 /// the values in the proposal; all modifications must be allowed by the strategy.
@@ -249,15 +997,211 @@ fn check_cron_update_msg_params(
         .ok_or(ContractError::Unauthorized {})?;
 
     let cron_params = get_cron_params(deps, ParamsRequestCron {})?;
-    if cron_params.params.limit != msg_update_params.params.limit
-        && !cron_update_param_permission.limit
-    {
-        return Err(ContractError::Unauthorized {});
-    }
+    check_param_change(
+        &cron_params.params.limit,
+        &msg_update_params.params.limit,
+        cron_update_param_permission.limit,
+    )?;
+    check_param_change(
+        &cron_params.params.security_address,
+        &msg_update_params.params.security_address,
+        cron_update_param_permission.security_address,
+    )?;
 
-    if cron_params.params.security_address != msg_update_params.params.security_address
-        && !cron_update_param_permission.security_address
-    {
+    Ok(())
+}
+
+/// Checks that the strategy owner is authorised to change the parameters of the
+/// tokenfactory module, the same way it is done for the cron module.
+fn check_tokenfactory_update_msg_params(
+    deps: Deps,
+    strategy: Strategy,
+    proposal: ProposalExecuteMessage,
+) -> Result<(), ContractError> {
+    let msg_update_params: MsgUpdateParamsTokenfactory =
+        serde_json_wasm::from_str(proposal.message.as_str())?;
+
+    let permission = strategy
+        .get_tokenfactory_update_param_permission()
+        .ok_or(ContractError::Unauthorized {})?;
+
+    let tokenfactory_params = get_tokenfactory_params(deps, ParamsRequestTokenfactory {})?;
+    check_param_change(
+        &tokenfactory_params.params.denom_creation_fee,
+        &msg_update_params.params.denom_creation_fee,
+        permission.denom_creation_fee,
+    )?;
+    check_param_change(
+        &tokenfactory_params.params.denom_creation_gas_consume,
+        &msg_update_params.params.denom_creation_gas_consume,
+        permission.denom_creation_gas_consume,
+    )?;
+    check_param_change(
+        &tokenfactory_params.params.fee_collector_address,
+        &msg_update_params.params.fee_collector_address,
+        permission.fee_collector_address,
+    )?;
+    check_param_change(
+        &tokenfactory_params.params.whitelisted_hooks,
+        &msg_update_params.params.whitelisted_hooks,
+        permission.whitelisted_hooks,
+    )?;
+
+    Ok(())
+}
+
+/// Checks that the strategy owner is authorised to change the parameters of the
+/// dex module, the same way it is done for the cron module.
+fn check_dex_update_msg_params(
+    deps: Deps,
+    strategy: Strategy,
+    proposal: ProposalExecuteMessage,
+) -> Result<(), ContractError> {
+    let msg_update_params: MsgUpdateParamsDex =
+        serde_json_wasm::from_str(proposal.message.as_str())?;
+
+    let permission = strategy
+        .get_dex_update_param_permission()
+        .ok_or(ContractError::Unauthorized {})?;
+
+    let dex_params = get_dex_params(deps, ParamsRequestDex {})?;
+    check_param_change(
+        &dex_params.params.fee_tiers,
+        &msg_update_params.params.fee_tiers,
+        permission.fee_tiers,
+    )?;
+    check_param_change(
+        &dex_params.params.paused,
+        &msg_update_params.params.paused,
+        permission.paused,
+    )?;
+    check_param_change(
+        &dex_params.params.max_jits_per_block,
+        &msg_update_params.params.max_jits_per_block,
+        permission.max_jits_per_block,
+    )?;
+    check_param_change(
+        &dex_params.params.good_til_purge_allowance,
+        &msg_update_params.params.good_til_purge_allowance,
+        permission.good_til_purge_allowance,
+    )?;
+
+    Ok(())
+}
+
+/// Checks that the strategy owner is authorised to change the parameters of the
+/// interchaintxs module, the same way it is done for the cron module.
+fn check_interchaintxs_update_msg_params(
+    deps: Deps,
+    strategy: Strategy,
+    proposal: ProposalExecuteMessage,
+) -> Result<(), ContractError> {
+    let msg_update_params: MsgUpdateParamsInterchaintxs =
+        serde_json_wasm::from_str(proposal.message.as_str())?;
+
+    let permission = strategy
+        .get_interchaintxs_update_param_permission()
+        .ok_or(ContractError::Unauthorized {})?;
+
+    let interchaintxs_params = get_interchaintxs_params(deps, ParamsRequestInterchaintxs {})?;
+    check_param_change(
+        &interchaintxs_params.params.msg_submit_tx_max_messages,
+        &msg_update_params.params.msg_submit_tx_max_messages,
+        permission.msg_submit_tx_max_messages,
+    )?;
+    check_param_change(
+        &interchaintxs_params.params.register_fee,
+        &msg_update_params.params.register_fee,
+        permission.register_fee,
+    )?;
+
+    Ok(())
+}
+
+/// Checks that the strategy owner is authorised to change the parameters of the
+/// feeburner module, the same way it is done for the cron module.
+fn check_feeburner_update_msg_params(
+    deps: Deps,
+    strategy: Strategy,
+    proposal: ProposalExecuteMessage,
+) -> Result<(), ContractError> {
+    let msg_update_params: MsgUpdateParamsFeeburner =
+        serde_json_wasm::from_str(proposal.message.as_str())?;
+
+    let permission = strategy
+        .get_feeburner_update_param_permission()
+        .ok_or(ContractError::Unauthorized {})?;
+
+    let feeburner_params = get_feeburner_params(deps, ParamsRequestFeeburner {})?;
+    check_param_change(
+        &feeburner_params.params.neutron_denom,
+        &msg_update_params.params.neutron_denom,
+        permission.neutron_denom,
+    )?;
+    check_param_change(
+        &feeburner_params.params.treasury_address,
+        &msg_update_params.params.treasury_address,
+        permission.treasury_address,
+    )?;
+
+    Ok(())
+}
+
+/// Checks that the strategy owner is authorised to change the parameters of the
+/// feerefunder module, the same way it is done for the cron module.
+fn check_feerefunder_update_msg_params(
+    deps: Deps,
+    strategy: Strategy,
+    proposal: ProposalExecuteMessage,
+) -> Result<(), ContractError> {
+    let msg_update_params: MsgUpdateParamsFeerefunder =
+        serde_json_wasm::from_str(proposal.message.as_str())?;
+
+    let permission = strategy
+        .get_feerefunder_update_param_permission()
+        .ok_or(ContractError::Unauthorized {})?;
+
+    let feerefunder_params = get_feerefunder_params(deps, ParamsRequestFeerefunder {})?;
+    check_param_change(
+        &feerefunder_params.params.min_fee,
+        &msg_update_params.params.min_fee,
+        permission.min_fee,
+    )?;
+
+    Ok(())
+}
+
+/// Checks that the strategy owner is authorised to change the parameters of the
+/// contractmanager module, the same way it is done for the cron module.
+fn check_contractmanager_update_msg_params(
+    deps: Deps,
+    strategy: Strategy,
+    proposal: ProposalExecuteMessage,
+) -> Result<(), ContractError> {
+    let msg_update_params: MsgUpdateParamsContractmanager =
+        serde_json_wasm::from_str(proposal.message.as_str())?;
+
+    let permission = strategy
+        .get_contractmanager_update_param_permission()
+        .ok_or(ContractError::Unauthorized {})?;
+
+    let contractmanager_params = get_contractmanager_params(deps, ParamsRequestContractmanager {})?;
+    check_param_change(
+        &contractmanager_params.params.sudo_call_gas_limit,
+        &msg_update_params.params.sudo_call_gas_limit,
+        permission.sudo_call_gas_limit,
+    )?;
+
+    Ok(())
+}
+
+/// Fails if a parameter is modified without the permission to do so.
+fn check_param_change<T: PartialEq<U>, U>(
+    current_value: &T,
+    new_value: &U,
+    allowed: bool,
+) -> Result<(), ContractError> {
+    if current_value != new_value && !allowed {
         return Err(ContractError::Unauthorized {});
     }
 
@@ -269,27 +1213,241 @@ pub fn get_cron_params(deps: Deps, req: ParamsRequestCron) -> StdResult<ParamsRe
     make_stargate_query(deps, PARAMS_QUERY_PATH_CRON, QueryParamsRequest::from(req))
 }
 
+/// Queries the parameters of the tokenfactory module.
+pub fn get_tokenfactory_params(
+    deps: Deps,
+    req: ParamsRequestTokenfactory,
+) -> StdResult<ParamsResponseTokenfactory> {
+    make_stargate_query(
+        deps,
+        PARAMS_QUERY_PATH_TOKENFACTORY,
+        TokenfactoryQueryParamsRequest::from(req),
+    )
+}
+
+/// Queries the parameters of the dex module.
+pub fn get_dex_params(deps: Deps, req: ParamsRequestDex) -> StdResult<ParamsResponseDex> {
+    make_stargate_query(
+        deps,
+        PARAMS_QUERY_PATH_DEX,
+        DexQueryParamsRequest::from(req),
+    )
+}
+
+/// Queries the parameters of the interchaintxs module.
+pub fn get_interchaintxs_params(
+    deps: Deps,
+    req: ParamsRequestInterchaintxs,
+) -> StdResult<ParamsResponseInterchaintxs> {
+    make_stargate_query(
+        deps,
+        PARAMS_QUERY_PATH_INTERCHAINTXS,
+        InterchaintxsQueryParamsRequest::from(req),
+    )
+}
+
+/// Queries the parameters of the feeburner module.
+pub fn get_feeburner_params(
+    deps: Deps,
+    req: ParamsRequestFeeburner,
+) -> StdResult<ParamsResponseFeeburner> {
+    make_stargate_query(
+        deps,
+        PARAMS_QUERY_PATH_FEEBURNER,
+        FeeburnerQueryParamsRequest::from(req),
+    )
+}
+
+/// Queries the parameters of the feerefunder module.
+pub fn get_feerefunder_params(
+    deps: Deps,
+    req: ParamsRequestFeerefunder,
+) -> StdResult<ParamsResponseFeerefunder> {
+    make_stargate_query(
+        deps,
+        PARAMS_QUERY_PATH_FEEREFUNDER,
+        FeerefunderQueryParamsRequest::from(req),
+    )
+}
+
+/// Queries the parameters of the contractmanager module.
+pub fn get_contractmanager_params(
+    deps: Deps,
+    req: ParamsRequestContractmanager,
+) -> StdResult<ParamsResponseContractmanager> {
+    make_stargate_query(
+        deps,
+        PARAMS_QUERY_PATH_CONTRACTMANAGER,
+        ContractmanagerQueryParamsRequest::from(req),
+    )
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Strategies {} => to_json_binary(&query_strategies(deps)?),
+        QueryMsg::RateLimits { address } => to_json_binary(&query_rate_limits(deps, address)?),
+        QueryMsg::CheckMessages { sender, messages } => {
+            to_json_binary(&query_check_messages(deps, env, sender, messages)?)
+        }
+        QueryMsg::AuditLog {
+            sender,
+            start_before,
+            limit,
+        } => to_json_binary(&query_audit_log(deps, sender, start_before, limit)?),
+        QueryMsg::PendingBatches {
+            strategy_address,
+            start_after,
+            limit,
+        } => to_json_binary(&query_pending_batches(
+            deps,
+            strategy_address,
+            start_after,
+            limit,
+        )?),
     }
 }
 
+pub fn query_pending_batches(
+    deps: Deps,
+    strategy_address: Option<Addr>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Batch>> {
+    BATCHES
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .map(|item| item.map(|(_, batch)| batch))
+        .filter(|item| match (item, &strategy_address) {
+            (Ok(batch), Some(strategy_address)) => batch.strategy_address == *strategy_address,
+            _ => true,
+        })
+        .take(limit.map_or(usize::MAX, |limit| limit as usize))
+        .collect()
+}
+
+pub fn query_audit_log(
+    deps: Deps,
+    sender: Option<Addr>,
+    start_before: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<AuditLogEntry>> {
+    match sender {
+        Some(sender) => {
+            let ids = AUDIT_LOG_BY_SENDER
+                .prefix(sender)
+                .keys(
+                    deps.storage,
+                    None,
+                    start_before.map(Bound::exclusive),
+                    Order::Descending,
+                )
+                .take(limit.map_or(usize::MAX, |limit| limit as usize))
+                .collect::<StdResult<Vec<u64>>>()?;
+            ids.into_iter()
+                .map(|id| AUDIT_LOG.load(deps.storage, id))
+                .collect()
+        }
+        None => paginate_map_values(deps, &AUDIT_LOG, start_before, limit, Order::Descending),
+    }
+}
+
+/// Checks the messages the same way `ExecuteMessages` would, without
+/// executing them.
+pub fn query_check_messages(
+    deps: Deps,
+    env: Env,
+    sender: Addr,
+    messages: Vec<CosmosMsg<NeutronMsg>>,
+) -> StdResult<CheckMessagesResponse> {
+    let rejected = |msg: &CosmosMsg<NeutronMsg>, error: String| MessageCheck {
+        allowed: false,
+        missing_permission: required_permission_type(msg),
+        error: Some(error),
+    };
+
+    let strategy = match load_active_strategy(deps, &env.block, sender.clone()) {
+        Ok(strategy) => strategy,
+        Err(err) => {
+            return Ok(CheckMessagesResponse {
+                allowed: false,
+                messages: messages
+                    .iter()
+                    .map(|msg| rejected(msg, err.to_string()))
+                    .collect(),
+                rate_limit_error: None,
+            })
+        }
+    };
+    if let Strategy::AllowAll = strategy {
+        return Ok(CheckMessagesResponse {
+            allowed: true,
+            messages: messages.iter().map(|_| MessageCheck::allowed()).collect(),
+            rate_limit_error: None,
+        });
+    }
+
+    let mut checks = Vec::with_capacity(messages.len());
+    let mut used_permissions = Vec::with_capacity(messages.len());
+    let mut sent = vec![];
+    for msg in messages {
+        match check_allow_only_message(deps, strategy.clone(), msg.clone(), &mut sent) {
+            Ok(permission_type) => {
+                used_permissions.push(permission_type);
+                checks.push(MessageCheck::allowed());
+            }
+            Err(err) => checks.push(rejected(&msg, err.to_string())),
+        }
+    }
+
+    // Rate limits only matter if every message of the batch is allowed.
+    let mut rate_limit_error = None;
+    if used_permissions.len() == checks.len() {
+        if let Err(err) = check_rate_limits(deps.storage, &env.block, sender, used_permissions) {
+            rate_limit_error = Some(err.to_string());
+        }
+    }
+
+    Ok(CheckMessagesResponse {
+        allowed: checks.iter().all(|check| check.allowed) && rate_limit_error.is_none(),
+        messages: checks,
+        rate_limit_error,
+    })
+}
+
+pub fn query_rate_limits(deps: Deps, address: Addr) -> StdResult<RateLimitsResponse> {
+    Ok(RateLimitsResponse {
+        rate_limits: RATE_LIMITS
+            .may_load(deps.storage, address.clone())?
+            .unwrap_or_default(),
+        usage: PERMISSION_USAGE
+            .may_load(deps.storage, address)?
+            .unwrap_or_default(),
+    })
+}
+
 /// No pagination is added because it's unlikely that there is going
 /// to be more than 10 strategies.
-pub fn query_strategies(deps: Deps) -> StdResult<Vec<(Addr, StrategyMsg)>> {
-    let all_strategies: Vec<(Addr, StrategyMsg)> = STRATEGIES
+pub fn query_strategies(deps: Deps) -> StdResult<Vec<StrategyResponse>> {
+    let all_strategies: Vec<StrategyResponse> = STRATEGIES
         .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .map(|v| match v {
-            Ok((addr, Strategy::AllowAll)) => Ok((addr, StrategyMsg::AllowAll)),
-            Ok((addr, Strategy::AllowOnly(permissions))) => Ok((
-                addr,
-                StrategyMsg::AllowOnly(permissions.values().cloned().collect::<Vec<Permission>>()),
-            )),
-            Err(e) => Err(e),
+        .map(|v| {
+            let (addr, strategy) = v?;
+            let strategy: StrategyMsg = strategy.into();
+            let expiration = STRATEGY_EXPIRATIONS
+                .may_load(deps.storage, addr.clone())?
+                .unwrap_or_default();
+            Ok(StrategyResponse {
+                address: addr,
+                strategy,
+                expiration,
+            })
         })
-        .collect::<Result<Vec<(Addr, StrategyMsg)>, _>>()?;
+        .collect::<StdResult<Vec<StrategyResponse>>>()?;
     Ok(all_strategies)
 }
 
@@ -0,0 +1,44 @@
+use crate::serde_helpers::deserialize_u64;
+use neutron_sdk::proto_types::neutron::contractmanager::QueryParamsRequest;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub const PARAMS_QUERY_PATH_CONTRACTMANAGER: &str = "/neutron.contractmanager.Query/Params";
+pub const MSG_TYPE_UPDATE_PARAMS_CONTRACTMANAGER: &str = "/neutron.contractmanager.MsgUpdateParams";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MsgUpdateParamsContractmanager {
+    pub params: MsgParamsContractmanager,
+}
+
+/// Current Contractmanager module parameters, as returned by the params query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ParamsContractmanager {
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub sudo_call_gas_limit: u64,
+}
+
+/// Contractmanager module parameters submitted in a `MsgUpdateParams`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct MsgParamsContractmanager {
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub sudo_call_gas_limit: u64,
+}
+
+/// The types below are used for querying contractmanager module parameters via stargate.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema, ::prost::Message)]
+pub struct ParamsRequestContractmanager {}
+
+impl From<ParamsRequestContractmanager> for QueryParamsRequest {
+    fn from(_: ParamsRequestContractmanager) -> QueryParamsRequest {
+        QueryParamsRequest {}
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ParamsResponseContractmanager {
+    pub params: ParamsContractmanager,
+}
@@ -1,8 +1,7 @@
+use crate::serde_helpers::deserialize_u64;
 use neutron_sdk::proto_types::neutron::cron::QueryParamsRequest;
 use schemars::JsonSchema;
-use serde::de::{self, Visitor};
-use serde::{Deserialize, Deserializer, Serialize};
-use std::fmt;
+use serde::{Deserialize, Serialize};
 
 pub const PARAMS_QUERY_PATH_CRON: &str = "/neutron.cron.Query/Params";
 pub const MSG_TYPE_UPDATE_PARAMS_CRON: &str = "/neutron.cron.MsgUpdateParams";
@@ -10,49 +9,25 @@ pub const MSG_TYPE_UPDATE_PARAMS_CRON: &str = "/neutron.cron.MsgUpdateParams";
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct MsgUpdateParamsCron {
-    pub params: ParamsCron,
+    pub params: MsgParamsCron,
 }
 
+/// Current Cron module parameters, as returned by the params query.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct ParamsCron {
     pub security_address: String,
-    #[serde(deserialize_with = "deserialize_cron_limit")]
+    #[serde(deserialize_with = "deserialize_u64")]
     pub limit: u64,
 }
 
-/// Unfortunately, stargate returns a string instead of a number for the
-/// limit parameter, so we need to have a custom deserializer for this
-/// field.
-fn deserialize_cron_limit<'de, D>(deserializer: D) -> Result<u64, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    struct StringOrNumberVisitor;
-
-    impl<'de> Visitor<'de> for StringOrNumberVisitor {
-        type Value = u64;
-
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("a string or a number")
-        }
-
-        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
-        where
-            E: de::Error,
-        {
-            Ok(value)
-        }
-
-        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
-        where
-            E: de::Error,
-        {
-            value.parse::<u64>().map_err(de::Error::custom)
-        }
-    }
-
-    deserializer.deserialize_any(StringOrNumberVisitor)
+/// Cron module parameters submitted in a `MsgUpdateParams`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct MsgParamsCron {
+    pub security_address: String,
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub limit: u64,
 }
 
 /// The types below are used for querying cron module parameters via stargate.
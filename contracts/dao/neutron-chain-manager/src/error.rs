@@ -1,3 +1,4 @@
+use crate::msg::PermissionType;
 use cosmwasm_std::StdError;
 use thiserror::Error;
 
@@ -17,6 +18,38 @@ pub enum ContractError {
     #[error("An invalid demotion was attempted")]
     InvalidDemotion {},
 
+    #[error("Strategy has expired")]
+    StrategyExpired {},
+
+    #[error("Strategy expiration must be in the future")]
+    InvalidExpiration {},
+
+    #[error("Invalid rate limit: {reason}")]
+    InvalidRateLimit { reason: String },
+
+    #[error("Rate limit exceeded for permission {permission_type:?}")]
+    RateLimitExceeded { permission_type: PermissionType },
+
+    #[error("Permission {permission_type:?} is cooling down")]
+    CooldownActive { permission_type: PermissionType },
+
+    #[error("Invalid multi-party strategy: {reason}")]
+    InvalidMultiPartyStrategy { reason: String },
+
+    // This error is returned when an address with a MULTI_PARTY strategy
+    // tries to execute messages without going through a batch.
+    #[error("Messages must be submitted as a batch and approved")]
+    ApprovalRequired {},
+
+    #[error("Batch {batch_id} not found")]
+    BatchNotFound { batch_id: u64 },
+
+    #[error("Batch has already been approved by the sender")]
+    AlreadyApproved {},
+
+    #[error("Batch has {approvals} approvals out of the {threshold} required")]
+    NotEnoughApprovals { approvals: u64, threshold: u64 },
+
     // A variant for serde_json_wasm deserialization errors.
     #[error("Deserialization error: {0}")]
     DeserializationError(String),
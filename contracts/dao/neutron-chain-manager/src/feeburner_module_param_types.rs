@@ -0,0 +1,43 @@
+use neutron_sdk::proto_types::neutron::feeburner::QueryParamsRequest;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub const PARAMS_QUERY_PATH_FEEBURNER: &str = "/neutron.feeburner.Query/Params";
+pub const MSG_TYPE_UPDATE_PARAMS_FEEBURNER: &str = "/neutron.feeburner.MsgUpdateParams";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MsgUpdateParamsFeeburner {
+    pub params: MsgParamsFeeburner,
+}
+
+/// Current Feeburner module parameters, as returned by the params query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ParamsFeeburner {
+    pub neutron_denom: String,
+    pub treasury_address: String,
+}
+
+/// Feeburner module parameters submitted in a `MsgUpdateParams`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct MsgParamsFeeburner {
+    pub neutron_denom: String,
+    pub treasury_address: String,
+}
+
+/// The types below are used for querying feeburner module parameters via stargate.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema, ::prost::Message)]
+pub struct ParamsRequestFeeburner {}
+
+impl From<ParamsRequestFeeburner> for QueryParamsRequest {
+    fn from(_: ParamsRequestFeeburner) -> QueryParamsRequest {
+        QueryParamsRequest {}
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ParamsResponseFeeburner {
+    pub params: ParamsFeeburner,
+}
@@ -0,0 +1,66 @@
+use cosmwasm_std::Coin;
+use neutron_sdk::proto_types::neutron::feerefunder::QueryParamsRequest;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub const PARAMS_QUERY_PATH_FEEREFUNDER: &str = "/neutron.feerefunder.Query/Params";
+pub const MSG_TYPE_UPDATE_PARAMS_FEEREFUNDER: &str = "/neutron.feerefunder.MsgUpdateParams";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MsgUpdateParamsFeerefunder {
+    pub params: MsgParamsFeerefunder,
+}
+
+/// Current Feerefunder module parameters, as returned by the params query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ParamsFeerefunder {
+    pub min_fee: Fee,
+}
+
+/// Feerefunder module parameters submitted in a `MsgUpdateParams`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct MsgParamsFeerefunder {
+    pub min_fee: MsgFee,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Fee {
+    pub recv_fee: Vec<Coin>,
+    pub ack_fee: Vec<Coin>,
+    pub timeout_fee: Vec<Coin>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct MsgFee {
+    pub recv_fee: Vec<Coin>,
+    pub ack_fee: Vec<Coin>,
+    pub timeout_fee: Vec<Coin>,
+}
+
+impl PartialEq<MsgFee> for Fee {
+    fn eq(&self, other: &MsgFee) -> bool {
+        self.recv_fee == other.recv_fee
+            && self.ack_fee == other.ack_fee
+            && self.timeout_fee == other.timeout_fee
+    }
+}
+
+/// The types below are used for querying feerefunder module parameters via stargate.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema, ::prost::Message)]
+pub struct ParamsRequestFeerefunder {}
+
+impl From<ParamsRequestFeerefunder> for QueryParamsRequest {
+    fn from(_: ParamsRequestFeerefunder) -> QueryParamsRequest {
+        QueryParamsRequest {}
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ParamsResponseFeerefunder {
+    pub params: ParamsFeerefunder,
+}
@@ -0,0 +1,47 @@
+use crate::serde_helpers::deserialize_u64;
+use cosmwasm_std::Coin;
+use neutron_sdk::proto_types::neutron::interchaintxs::v1::QueryParamsRequest;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub const PARAMS_QUERY_PATH_INTERCHAINTXS: &str = "/neutron.interchaintxs.v1.Query/Params";
+pub const MSG_TYPE_UPDATE_PARAMS_INTERCHAINTXS: &str = "/neutron.interchaintxs.v1.MsgUpdateParams";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MsgUpdateParamsInterchaintxs {
+    pub params: MsgParamsInterchaintxs,
+}
+
+/// Current Interchaintxs module parameters, as returned by the params query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ParamsInterchaintxs {
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub msg_submit_tx_max_messages: u64,
+    pub register_fee: Vec<Coin>,
+}
+
+/// Interchaintxs module parameters submitted in a `MsgUpdateParams`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct MsgParamsInterchaintxs {
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub msg_submit_tx_max_messages: u64,
+    pub register_fee: Vec<Coin>,
+}
+
+/// The types below are used for querying interchaintxs module parameters via stargate.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema, ::prost::Message)]
+pub struct ParamsRequestInterchaintxs {}
+
+impl From<ParamsRequestInterchaintxs> for QueryParamsRequest {
+    fn from(_: ParamsRequestInterchaintxs) -> QueryParamsRequest {
+        QueryParamsRequest {}
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ParamsResponseInterchaintxs {
+    pub params: ParamsInterchaintxs,
+}
@@ -3,6 +3,13 @@ mod error;
 pub mod msg;
 pub mod state;
 
+mod contractmanager_module_param_types;
 mod cron_module_param_types;
+mod dex_module_param_types;
+mod feeburner_module_param_types;
+mod feerefunder_module_param_types;
+mod interchaintxs_module_param_types;
+mod serde_helpers;
 #[cfg(test)]
 mod testing;
+mod tokenfactory_module_param_types;
@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, CosmosMsg};
+use cosmwasm_std::{Addr, Coin, CosmosMsg, Uint128};
+use cw_utils::{Duration, Expiration};
 use neutron_sdk::bindings::msg::{NeutronMsg, ParamChange};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -19,6 +20,12 @@ pub enum ExecuteMsg {
     AddStrategy {
         address: Addr,
         strategy: StrategyMsg,
+        /// When set, the strategy lapses at the given height or time and
+        /// can no longer be used.
+        expiration: Option<Expiration>,
+        /// Limits on how often the permissions of an ALLOW_ONLY strategy can
+        /// be used.
+        rate_limits: Option<Vec<RateLimit>>,
     },
     RemoveStrategy {
         address: Addr,
@@ -26,13 +33,202 @@ pub enum ExecuteMsg {
     ExecuteMessages {
         messages: Vec<CosmosMsg<NeutronMsg>>,
     },
+    /// Proposes a batch of messages on behalf of an address with a
+    /// MULTI_PARTY strategy. The sender must be one of the approvers of the
+    /// strategy, and the submission counts as their approval.
+    SubmitBatch {
+        strategy_address: Addr,
+        messages: Vec<CosmosMsg<NeutronMsg>>,
+    },
+    ApproveBatch {
+        batch_id: u64,
+    },
+    /// Executes a batch once it has been approved by enough approvers. Can be
+    /// called by any of the approvers.
+    ExecuteBatch {
+        batch_id: u64,
+    },
+    CancelBatch {
+        batch_id: u64,
+    },
 }
 
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
-    #[returns(Vec < StrategyMsg >)]
+    #[returns(Vec < StrategyResponse >)]
     Strategies {},
+    #[returns(RateLimitsResponse)]
+    RateLimits { address: Addr },
+    /// Checks whether the messages would be executed if the sender submitted
+    /// them through `ExecuteMessages`. For a MULTI_PARTY strategy, checks
+    /// whether they would be executed as an approved batch.
+    #[returns(CheckMessagesResponse)]
+    CheckMessages {
+        sender: Addr,
+        messages: Vec<CosmosMsg<NeutronMsg>>,
+    },
+    /// Lists the history of executed messages and strategy changes, most
+    /// recent first, optionally only the actions performed by one address.
+    #[returns(Vec < AuditLogEntry >)]
+    AuditLog {
+        sender: Option<Addr>,
+        start_before: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Lists the batches waiting for approval or execution, optionally only
+    /// the ones of one MULTI_PARTY strategy.
+    #[returns(Vec < Batch >)]
+    PendingBatches {
+        strategy_address: Option<Addr>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct Batch {
+    pub id: u64,
+    /// The address with the MULTI_PARTY strategy the messages are executed
+    /// on behalf of.
+    pub strategy_address: Addr,
+    pub proposer: Addr,
+    pub messages: Vec<CosmosMsg<NeutronMsg>>,
+    pub approvals: Vec<Addr>,
+    pub submitted_at_height: u64,
+}
+
+#[cw_serde]
+pub struct StrategyResponse {
+    pub address: Addr,
+    pub strategy: StrategyMsg,
+    /// The moment the strategy lapses, `Never` for permanent strategies.
+    pub expiration: Expiration,
+}
+
+#[cw_serde]
+pub struct AuditLogEntry {
+    pub id: u64,
+    pub height: u64,
+    /// The address that performed the action.
+    pub sender: Addr,
+    pub action: AuditLogAction,
+}
+
+#[cw_serde]
+pub enum AuditLogAction {
+    ExecuteMessages {
+        strategy: StrategyType,
+        /// Type URL of each executed message.
+        message_types: Vec<String>,
+    },
+    AddStrategy {
+        address: Addr,
+        before: Option<StrategySnapshot>,
+        after: StrategySnapshot,
+    },
+    RemoveStrategy {
+        address: Addr,
+        before: Option<StrategySnapshot>,
+    },
+    ExecuteBatch {
+        batch_id: u64,
+        strategy_address: Addr,
+        approvals: Vec<Addr>,
+        /// Type URL of each executed message.
+        message_types: Vec<String>,
+    },
+}
+
+/// The state of the strategy of an address at a given moment.
+#[cw_serde]
+pub struct StrategySnapshot {
+    pub strategy: StrategyMsg,
+    pub expiration: Expiration,
+    pub rate_limits: Vec<RateLimit>,
+}
+
+#[cw_serde]
+pub enum StrategyType {
+    AllowAll,
+    AllowOnly,
+    MultiParty,
+}
+
+#[cw_serde]
+pub struct CheckMessagesResponse {
+    /// Whether the whole batch would be executed.
+    pub allowed: bool,
+    /// The result of the check of each message, in the same order.
+    pub messages: Vec<MessageCheck>,
+    /// Set if the batch would exceed one of the sender's rate limits.
+    pub rate_limit_error: Option<String>,
+}
+
+#[cw_serde]
+pub struct MessageCheck {
+    pub allowed: bool,
+    /// The permission needed to execute the message, if it was rejected. Not
+    /// set for messages that only an ALLOW_ALL strategy can execute.
+    pub missing_permission: Option<PermissionType>,
+    /// The reason the message was rejected.
+    pub error: Option<String>,
+}
+
+impl MessageCheck {
+    pub fn allowed() -> Self {
+        MessageCheck {
+            allowed: true,
+            missing_permission: None,
+            error: None,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct RateLimitsResponse {
+    pub rate_limits: Vec<RateLimit>,
+    pub usage: Vec<PermissionUsage>,
+}
+
+/// Restricts how often messages covered by a single permission can be
+/// executed.
+#[cw_serde]
+pub struct RateLimit {
+    pub permission_type: PermissionType,
+    /// Maximum number of messages allowed within a window.
+    pub quota: Option<Quota>,
+    /// Minimum gap between two `ExecuteMessages` calls using the permission.
+    pub cooldown: Option<Duration>,
+}
+
+#[cw_serde]
+pub struct Quota {
+    pub max_messages: u64,
+    pub window: Duration,
+}
+
+/// Tracks how a rate limited permission has been used so far.
+#[cw_serde]
+pub struct PermissionUsage {
+    pub permission_type: PermissionType,
+    /// Number of messages executed within the current window.
+    pub messages_in_window: u64,
+    /// The moment the current window ends and the counter is reset.
+    pub window_expiration: Option<Expiration>,
+    /// The moment the permission can be used again.
+    pub cooldown_expiration: Option<Expiration>,
+}
+
+impl PermissionUsage {
+    pub fn new(permission_type: PermissionType) -> Self {
+        PermissionUsage {
+            permission_type,
+            messages_in_window: 0,
+            window_expiration: None,
+            cooldown_expiration: None,
+        }
+    }
 }
 
 #[cw_serde]
@@ -45,6 +241,13 @@ pub struct MigrateMsg {}
 pub enum StrategyMsg {
     AllowAll,
     AllowOnly(Vec<Permission>),
+    /// Grants the same permissions as ALLOW_ONLY, but messages can only be
+    /// executed in batches approved by at least `threshold` of the `approvers`.
+    MultiParty {
+        approvers: Vec<Addr>,
+        threshold: u64,
+        permissions: Vec<Permission>,
+    },
 }
 
 #[derive(JsonSchema)]
@@ -54,6 +257,32 @@ pub enum Strategy {
     AllowAll,
     // the macro param required because serde allows only string as keys of hashmap during serialisation
     AllowOnly(#[serde_as(as = "HashMap<JsonString, _>")] HashMap<PermissionType, Permission>),
+    MultiParty {
+        approvers: Vec<Addr>,
+        threshold: u64,
+        #[serde_as(as = "HashMap<JsonString, _>")]
+        permissions: HashMap<PermissionType, Permission>,
+    },
+}
+
+impl From<Strategy> for StrategyMsg {
+    fn from(value: Strategy) -> Self {
+        match value {
+            Strategy::AllowAll => StrategyMsg::AllowAll,
+            Strategy::AllowOnly(permissions) => {
+                StrategyMsg::AllowOnly(permissions.into_values().collect())
+            }
+            Strategy::MultiParty {
+                approvers,
+                threshold,
+                permissions,
+            } => StrategyMsg::MultiParty {
+                approvers,
+                threshold,
+                permissions: permissions.into_values().collect(),
+            },
+        }
+    }
 }
 
 impl From<StrategyMsg> for Strategy {
@@ -61,21 +290,41 @@ impl From<StrategyMsg> for Strategy {
         match value {
             StrategyMsg::AllowAll => Strategy::AllowAll,
             StrategyMsg::AllowOnly(list_permissions) => {
-                let mut perms: HashMap<PermissionType, Permission> = HashMap::new();
-                for p in list_permissions {
-                    perms.insert(p.clone().into(), p);
-                }
-                Strategy::AllowOnly(perms)
+                Strategy::AllowOnly(permissions_by_type(list_permissions))
             }
+            StrategyMsg::MultiParty {
+                approvers,
+                threshold,
+                permissions,
+            } => Strategy::MultiParty {
+                approvers,
+                threshold,
+                permissions: permissions_by_type(permissions),
+            },
         }
     }
 }
 
+fn permissions_by_type(list_permissions: Vec<Permission>) -> HashMap<PermissionType, Permission> {
+    let mut perms: HashMap<PermissionType, Permission> = HashMap::new();
+    for p in list_permissions {
+        perms.insert(p.clone().into(), p);
+    }
+    perms
+}
+
 impl Strategy {
+    pub fn strategy_type(&self) -> StrategyType {
+        match self {
+            Strategy::AllowAll => StrategyType::AllowAll,
+            Strategy::AllowOnly(_) => StrategyType::AllowOnly,
+            Strategy::MultiParty { .. } => StrategyType::MultiParty,
+        }
+    }
     pub fn has_cron_add_schedule_permission(&self) -> bool {
         match self {
             Strategy::AllowAll => true,
-            Strategy::AllowOnly(permissions) => {
+            Strategy::AllowOnly(permissions) | Strategy::MultiParty { permissions, .. } => {
                 match permissions.get(&PermissionType::CronPermission) {
                     Some(Permission::CronPermission(permission)) => permission.add_schedule,
                     _ => false,
@@ -86,7 +335,7 @@ impl Strategy {
     pub fn has_cron_remove_schedule_permission(&self) -> bool {
         match self {
             Strategy::AllowAll => true,
-            Strategy::AllowOnly(permissions) => {
+            Strategy::AllowOnly(permissions) | Strategy::MultiParty { permissions, .. } => {
                 match permissions.get(&PermissionType::CronPermission) {
                     Some(Permission::CronPermission(permission)) => permission.remove_schedule,
                     _ => false,
@@ -94,10 +343,80 @@ impl Strategy {
             }
         }
     }
+    /// Checks whether the given top-level message can be executed on the given
+    /// contract.
+    pub fn has_wasm_execute_permission(&self, contract_addr: &str, msg_name: &str) -> bool {
+        match self {
+            Strategy::AllowAll => true,
+            Strategy::AllowOnly(permissions) | Strategy::MultiParty { permissions, .. } => {
+                match permissions.get(&PermissionType::WasmExecutePermission) {
+                    Some(Permission::WasmExecutePermission(permission)) => {
+                        permission.contracts.iter().any(|contract| {
+                            contract.contract_address == contract_addr
+                                && contract.allowed_messages.as_ref().map_or(true, |messages| {
+                                    messages.iter().any(|message| message == msg_name)
+                                })
+                        })
+                    }
+                    _ => false,
+                }
+            }
+        }
+    }
+    /// Checks whether the given funds can be sent to the given address, on top
+    /// of the funds `sent` by the previous messages of the same execution,
+    /// each with its recipient.
+    pub fn has_bank_send_permission(
+        &self,
+        to_address: &str,
+        amount: &[Coin],
+        sent: &[(String, Coin)],
+    ) -> bool {
+        match self {
+            Strategy::AllowAll => true,
+            Strategy::AllowOnly(permissions) | Strategy::MultiParty { permissions, .. } => {
+                match permissions.get(&PermissionType::BankSendPermission) {
+                    Some(Permission::BankSendPermission(permission)) => {
+                        let recipient_max_amounts = if permission.recipients.is_empty() {
+                            None
+                        } else {
+                            match permission
+                                .recipients
+                                .iter()
+                                .find(|recipient| recipient.address == to_address)
+                            {
+                                Some(recipient) => recipient.max_amounts.as_ref(),
+                                None => return false,
+                            }
+                        };
+
+                        sum_by_denom(amount).into_iter().all(|(denom, amount)| {
+                            let total = |recipient: Option<&str>| {
+                                sent.iter()
+                                    .filter(|(to, coin)| {
+                                        coin.denom == denom
+                                            && recipient
+                                                .map_or(true, |recipient| recipient == to.as_str())
+                                    })
+                                    .fold(amount, |total, (_, coin)| {
+                                        total.saturating_add(coin.amount)
+                                    })
+                            };
+                            within_cap(&permission.max_amounts, &denom, total(None))
+                                && recipient_max_amounts.map_or(true, |caps| {
+                                    within_cap(caps, &denom, total(Some(to_address)))
+                                })
+                        })
+                    }
+                    _ => false,
+                }
+            }
+        }
+    }
     pub fn has_param_change_permission(&self, param_change: ParamChange) -> bool {
         match self {
             Strategy::AllowAll => true,
-            Strategy::AllowOnly(permissions) => {
+            Strategy::AllowOnly(permissions) | Strategy::MultiParty { permissions, .. } => {
                 match permissions.get(&PermissionType::ParamChangePermission) {
                     Some(Permission::ParamChangePermission(param_change_permissions)) => {
                         for param_change_permission in param_change_permissions.params.clone() {
@@ -121,15 +440,121 @@ impl Strategy {
                 security_address: true,
                 limit: true,
             }),
-            Strategy::AllowOnly(permissions) => {
+            Strategy::AllowOnly(permissions) | Strategy::MultiParty { permissions, .. } => {
                 match permissions.get(&PermissionType::UpdateParamsPermission) {
-                    Some(Permission::UpdateParamsPermission(update_params_permission)) => {
-                        match update_params_permission {
-                            UpdateParamsPermission::CronUpdateParamsPermission(
-                                cron_update_params,
-                            ) => Some(cron_update_params.clone()),
-                        }
-                    }
+                    Some(Permission::UpdateParamsPermission(
+                        UpdateParamsPermission::CronUpdateParamsPermission(cron_update_params),
+                    )) => Some(cron_update_params.clone()),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    pub fn get_tokenfactory_update_param_permission(
+        &self,
+    ) -> Option<TokenfactoryUpdateParamsPermission> {
+        match self {
+            Strategy::AllowAll => Some(TokenfactoryUpdateParamsPermission {
+                denom_creation_fee: true,
+                denom_creation_gas_consume: true,
+                fee_collector_address: true,
+                whitelisted_hooks: true,
+            }),
+            Strategy::AllowOnly(permissions) | Strategy::MultiParty { permissions, .. } => {
+                match permissions.get(&PermissionType::TokenfactoryUpdateParamsPermission) {
+                    Some(Permission::UpdateParamsPermission(
+                        UpdateParamsPermission::TokenfactoryUpdateParamsPermission(permission),
+                    )) => Some(permission.clone()),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    pub fn get_dex_update_param_permission(&self) -> Option<DexUpdateParamsPermission> {
+        match self {
+            Strategy::AllowAll => Some(DexUpdateParamsPermission {
+                fee_tiers: true,
+                paused: true,
+                max_jits_per_block: true,
+                good_til_purge_allowance: true,
+            }),
+            Strategy::AllowOnly(permissions) | Strategy::MultiParty { permissions, .. } => {
+                match permissions.get(&PermissionType::DexUpdateParamsPermission) {
+                    Some(Permission::UpdateParamsPermission(
+                        UpdateParamsPermission::DexUpdateParamsPermission(permission),
+                    )) => Some(permission.clone()),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    pub fn get_interchaintxs_update_param_permission(
+        &self,
+    ) -> Option<InterchaintxsUpdateParamsPermission> {
+        match self {
+            Strategy::AllowAll => Some(InterchaintxsUpdateParamsPermission {
+                msg_submit_tx_max_messages: true,
+                register_fee: true,
+            }),
+            Strategy::AllowOnly(permissions) | Strategy::MultiParty { permissions, .. } => {
+                match permissions.get(&PermissionType::InterchaintxsUpdateParamsPermission) {
+                    Some(Permission::UpdateParamsPermission(
+                        UpdateParamsPermission::InterchaintxsUpdateParamsPermission(permission),
+                    )) => Some(permission.clone()),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    pub fn get_feeburner_update_param_permission(&self) -> Option<FeeburnerUpdateParamsPermission> {
+        match self {
+            Strategy::AllowAll => Some(FeeburnerUpdateParamsPermission {
+                neutron_denom: true,
+                treasury_address: true,
+            }),
+            Strategy::AllowOnly(permissions) | Strategy::MultiParty { permissions, .. } => {
+                match permissions.get(&PermissionType::FeeburnerUpdateParamsPermission) {
+                    Some(Permission::UpdateParamsPermission(
+                        UpdateParamsPermission::FeeburnerUpdateParamsPermission(permission),
+                    )) => Some(permission.clone()),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    pub fn get_feerefunder_update_param_permission(
+        &self,
+    ) -> Option<FeerefunderUpdateParamsPermission> {
+        match self {
+            Strategy::AllowAll => Some(FeerefunderUpdateParamsPermission { min_fee: true }),
+            Strategy::AllowOnly(permissions) | Strategy::MultiParty { permissions, .. } => {
+                match permissions.get(&PermissionType::FeerefunderUpdateParamsPermission) {
+                    Some(Permission::UpdateParamsPermission(
+                        UpdateParamsPermission::FeerefunderUpdateParamsPermission(permission),
+                    )) => Some(permission.clone()),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    pub fn get_contractmanager_update_param_permission(
+        &self,
+    ) -> Option<ContractmanagerUpdateParamsPermission> {
+        match self {
+            Strategy::AllowAll => Some(ContractmanagerUpdateParamsPermission {
+                sudo_call_gas_limit: true,
+            }),
+            Strategy::AllowOnly(permissions) | Strategy::MultiParty { permissions, .. } => {
+                match permissions.get(&PermissionType::ContractmanagerUpdateParamsPermission) {
+                    Some(Permission::UpdateParamsPermission(
+                        UpdateParamsPermission::ContractmanagerUpdateParamsPermission(permission),
+                    )) => Some(permission.clone()),
                     _ => None,
                 }
             }
@@ -137,6 +562,26 @@ impl Strategy {
     }
 }
 
+/// Sums up the amounts of the same denom, keeping the order of first
+/// appearance.
+fn sum_by_denom(amount: &[Coin]) -> Vec<(String, Uint128)> {
+    let mut sums: Vec<(String, Uint128)> = vec![];
+    for coin in amount {
+        match sums.iter_mut().find(|(denom, _)| *denom == coin.denom) {
+            Some((_, sum)) => *sum = sum.saturating_add(coin.amount),
+            None => sums.push((coin.denom.clone(), coin.amount)),
+        }
+    }
+    sums
+}
+
+/// Checks that the amount does not exceed the cap for its denom. Denoms
+/// without a cap can not be sent at all.
+fn within_cap(caps: &[Coin], denom: &str, amount: Uint128) -> bool {
+    caps.iter()
+        .any(|cap| cap.denom == denom && amount <= cap.amount)
+}
+
 #[cw_serde]
 #[derive(Eq)]
 pub enum Permission {
@@ -145,14 +590,42 @@ pub enum Permission {
     // For new-style parameter updates.
     UpdateParamsPermission(UpdateParamsPermission),
     CronPermission(CronPermission),
+    WasmExecutePermission(WasmExecutePermission),
+    BankSendPermission(BankSendPermission),
 }
 
 impl From<Permission> for PermissionType {
     fn from(value: Permission) -> Self {
         match value {
             Permission::ParamChangePermission(_) => PermissionType::ParamChangePermission,
-            Permission::UpdateParamsPermission(_) => PermissionType::UpdateParamsPermission,
+            Permission::UpdateParamsPermission(update_params_permission) => {
+                match update_params_permission {
+                    UpdateParamsPermission::CronUpdateParamsPermission(_) => {
+                        PermissionType::UpdateParamsPermission
+                    }
+                    UpdateParamsPermission::TokenfactoryUpdateParamsPermission(_) => {
+                        PermissionType::TokenfactoryUpdateParamsPermission
+                    }
+                    UpdateParamsPermission::DexUpdateParamsPermission(_) => {
+                        PermissionType::DexUpdateParamsPermission
+                    }
+                    UpdateParamsPermission::InterchaintxsUpdateParamsPermission(_) => {
+                        PermissionType::InterchaintxsUpdateParamsPermission
+                    }
+                    UpdateParamsPermission::FeeburnerUpdateParamsPermission(_) => {
+                        PermissionType::FeeburnerUpdateParamsPermission
+                    }
+                    UpdateParamsPermission::FeerefunderUpdateParamsPermission(_) => {
+                        PermissionType::FeerefunderUpdateParamsPermission
+                    }
+                    UpdateParamsPermission::ContractmanagerUpdateParamsPermission(_) => {
+                        PermissionType::ContractmanagerUpdateParamsPermission
+                    }
+                }
+            }
             Permission::CronPermission(_) => PermissionType::CronPermission,
+            Permission::WasmExecutePermission(_) => PermissionType::WasmExecutePermission,
+            Permission::BankSendPermission(_) => PermissionType::BankSendPermission,
         }
     }
 }
@@ -161,8 +634,18 @@ impl From<Permission> for PermissionType {
 #[derive(Hash, Eq)]
 pub enum PermissionType {
     ParamChangePermission,
+    // Cron module parameters, the name is kept for compatibility with the
+    // strategies that are already stored.
     UpdateParamsPermission,
     CronPermission,
+    WasmExecutePermission,
+    BankSendPermission,
+    TokenfactoryUpdateParamsPermission,
+    DexUpdateParamsPermission,
+    InterchaintxsUpdateParamsPermission,
+    FeeburnerUpdateParamsPermission,
+    FeerefunderUpdateParamsPermission,
+    ContractmanagerUpdateParamsPermission,
 }
 
 #[cw_serde]
@@ -184,6 +667,12 @@ pub struct ParamPermission {
 #[derive(Eq)]
 pub enum UpdateParamsPermission {
     CronUpdateParamsPermission(CronUpdateParamsPermission),
+    TokenfactoryUpdateParamsPermission(TokenfactoryUpdateParamsPermission),
+    DexUpdateParamsPermission(DexUpdateParamsPermission),
+    InterchaintxsUpdateParamsPermission(InterchaintxsUpdateParamsPermission),
+    FeeburnerUpdateParamsPermission(FeeburnerUpdateParamsPermission),
+    FeerefunderUpdateParamsPermission(FeerefunderUpdateParamsPermission),
+    ContractmanagerUpdateParamsPermission(ContractmanagerUpdateParamsPermission),
 }
 
 #[cw_serde]
@@ -194,6 +683,56 @@ pub struct CronUpdateParamsPermission {
     pub limit: bool,
 }
 
+#[cw_serde]
+#[derive(Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct TokenfactoryUpdateParamsPermission {
+    pub denom_creation_fee: bool,
+    pub denom_creation_gas_consume: bool,
+    pub fee_collector_address: bool,
+    pub whitelisted_hooks: bool,
+}
+
+#[cw_serde]
+#[derive(Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct DexUpdateParamsPermission {
+    pub fee_tiers: bool,
+    pub paused: bool,
+    pub max_jits_per_block: bool,
+    pub good_til_purge_allowance: bool,
+}
+
+#[cw_serde]
+#[derive(Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct InterchaintxsUpdateParamsPermission {
+    pub msg_submit_tx_max_messages: bool,
+    pub register_fee: bool,
+}
+
+#[cw_serde]
+#[derive(Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct FeeburnerUpdateParamsPermission {
+    pub neutron_denom: bool,
+    pub treasury_address: bool,
+}
+
+#[cw_serde]
+#[derive(Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct FeerefunderUpdateParamsPermission {
+    pub min_fee: bool,
+}
+
+#[cw_serde]
+#[derive(Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct ContractmanagerUpdateParamsPermission {
+    pub sudo_call_gas_limit: bool,
+}
+
 #[cw_serde]
 #[derive(Eq)]
 #[serde(rename_all = "snake_case")]
@@ -202,6 +741,46 @@ pub struct CronPermission {
     pub remove_schedule: bool,
 }
 
+#[cw_serde]
+#[derive(Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct WasmExecutePermission {
+    pub contracts: Vec<WasmExecuteContractPermission>,
+}
+
+#[cw_serde]
+#[derive(Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct WasmExecuteContractPermission {
+    pub contract_address: String,
+    /// Top-level keys of the execute messages that can be sent to the
+    /// contract, e.g. `update_config`. Any message is allowed if not set.
+    pub allowed_messages: Option<Vec<String>>,
+}
+
+#[cw_serde]
+#[derive(Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct BankSendPermission {
+    /// Maximum amount of each denom that can be sent by a single execution,
+    /// summed over all its messages. Denoms that are not listed can not be
+    /// sent.
+    pub max_amounts: Vec<Coin>,
+    /// Addresses funds can be sent to. Funds can be sent to any address if
+    /// empty.
+    pub recipients: Vec<BankSendRecipientPermission>,
+}
+
+#[cw_serde]
+#[derive(Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct BankSendRecipientPermission {
+    pub address: String,
+    /// Caps applied to this recipient on top of the per-denom caps, summed
+    /// over all the messages of an execution.
+    pub max_amounts: Option<Vec<Coin>>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct ProposalExecuteMessageJSON {
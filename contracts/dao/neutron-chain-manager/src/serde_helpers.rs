@@ -0,0 +1,104 @@
+use serde::de::{self, IgnoredAny, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+
+/// Unfortunately, stargate returns strings instead of numbers for 64-bit
+/// integers, while messages submitted by users usually contain plain
+/// numbers, so we need a custom deserializer that accepts both.
+pub(crate) fn deserialize_u64<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct StringOrNumberVisitor;
+
+    impl<'de> Visitor<'de> for StringOrNumberVisitor {
+        type Value = u64;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a string or a number")
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(value)
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            value.parse::<u64>().map_err(de::Error::custom)
+        }
+    }
+
+    deserializer.deserialize_any(StringOrNumberVisitor)
+}
+
+/// Same as `deserialize_u64`, but for lists of 64-bit integers.
+pub(crate) fn deserialize_u64_vec<'de, D>(deserializer: D) -> Result<Vec<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct StringOrNumber(u64);
+
+    impl<'de> Deserialize<'de> for StringOrNumber {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_u64(deserializer).map(StringOrNumber)
+        }
+    }
+
+    Ok(Vec::<StringOrNumber>::deserialize(deserializer)?
+        .into_iter()
+        .map(|value| value.0)
+        .collect())
+}
+
+/// The name of a CosmWasm execute message, i.e. its only top-level key, or
+/// the message itself for unit variants serialized as plain strings.
+pub(crate) struct MessageName(pub String);
+
+impl<'de> Deserialize<'de> for MessageName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct MessageNameVisitor;
+
+        impl<'de> Visitor<'de> for MessageNameVisitor {
+            type Value = MessageName;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string or an object with a single key")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(MessageName(value.to_string()))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let name: String = map
+                    .next_key()?
+                    .ok_or_else(|| de::Error::custom("empty message"))?;
+                map.next_value::<IgnoredAny>()?;
+                if map.next_key::<String>()?.is_some() {
+                    return Err(de::Error::custom("message has more than one top-level key"));
+                }
+
+                Ok(MessageName(name))
+            }
+        }
+
+        deserializer.deserialize_any(MessageNameVisitor)
+    }
+}
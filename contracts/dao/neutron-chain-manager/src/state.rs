@@ -1,6 +1,38 @@
-use crate::msg::Strategy;
+use crate::msg::{AuditLogEntry, Batch, PermissionUsage, RateLimit, Strategy};
 use cosmwasm_std::Addr;
-use cw_storage_plus::Map;
+use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
 
 /// Defines a mapping from an address to a strategy associated with the address.
 pub const STRATEGIES: Map<Addr, Strategy> = Map::new("chain-manager-strategies");
+
+/// Defines a mapping from an address to the moment its strategy lapses.
+/// Strategies without an entry never expire.
+pub const STRATEGY_EXPIRATIONS: Map<Addr, Expiration> =
+    Map::new("chain-manager-strategy-expirations");
+
+/// Defines a mapping from an address to the rate limits applied to the
+/// permissions of its ALLOW_ONLY strategy.
+pub const RATE_LIMITS: Map<Addr, Vec<RateLimit>> = Map::new("chain-manager-rate-limits");
+
+/// Defines a mapping from an address to the recorded usage of its rate
+/// limited permissions.
+pub const PERMISSION_USAGE: Map<Addr, Vec<PermissionUsage>> =
+    Map::new("chain-manager-permission-usage");
+
+/// The id of the next audit log entry.
+pub const AUDIT_LOG_NEXT_ID: Item<u64> = Item::new("chain-manager-audit-log-next-id");
+
+/// Defines a mapping from an id to an entry of the history of executed
+/// messages and strategy changes.
+pub const AUDIT_LOG: Map<u64, AuditLogEntry> = Map::new("chain-manager-audit-log");
+
+/// Indexes the audit log entries by the address that performed the action.
+pub const AUDIT_LOG_BY_SENDER: Map<(Addr, u64), ()> = Map::new("chain-manager-audit-log-by-sender");
+
+/// The id of the next batch submitted for a MULTI_PARTY strategy.
+pub const BATCH_NEXT_ID: Item<u64> = Item::new("chain-manager-batch-next-id");
+
+/// Defines a mapping from an id to a batch of messages waiting for approval
+/// or execution.
+pub const BATCHES: Map<u64, Batch> = Map::new("chain-manager-batches");
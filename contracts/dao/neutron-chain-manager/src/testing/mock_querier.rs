@@ -1,8 +1,15 @@
-use crate::cron_module_param_types::{ParamsCron, ParamsResponseCron};
+use crate::contractmanager_module_param_types::{
+    ParamsContractmanager, ParamsResponseContractmanager,
+};
+use crate::dex_module_param_types::{ParamsDex, ParamsResponseDex};
+use crate::feeburner_module_param_types::{ParamsFeeburner, ParamsResponseFeeburner};
+use crate::feerefunder_module_param_types::{Fee, ParamsFeerefunder, ParamsResponseFeerefunder};
+use crate::interchaintxs_module_param_types::{ParamsInterchaintxs, ParamsResponseInterchaintxs};
+use crate::tokenfactory_module_param_types::{ParamsResponseTokenfactory, ParamsTokenfactory};
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
 use cosmwasm_std::{
-    from_json, to_json_binary, ContractResult, Empty, OwnedDeps, Querier, QuerierResult,
-    QueryRequest, SystemError, SystemResult,
+    coin, from_json, to_json_binary, Binary, ContractResult, Empty, OwnedDeps, Querier,
+    QuerierResult, QueryRequest, SystemError, SystemResult,
 };
 use std::marker::PhantomData;
 
@@ -42,10 +49,71 @@ impl WasmMockQuerier {
         match &request {
             QueryRequest::Stargate { path, data: _ } => match path.as_str() {
                 "/neutron.cron.Query/Params" => {
-                    let resp = to_json_binary(&ParamsResponseCron {
-                        params: ParamsCron {
-                            security_address: "neutron_dao_address".to_string(),
-                            limit: 10,
+                    // Contains a field the contract doesn't know about, as after a chain
+                    // upgrade, which must not break the permission checks.
+                    let resp = Binary::from(
+                        br#"{"params":{"security_address":"neutron_dao_address","limit":"10",
+                        "max_execution_gas":"1000000"}}"#
+                            .as_slice(),
+                    );
+                    SystemResult::Ok(ContractResult::Ok(resp))
+                }
+                "/osmosis.tokenfactory.v1beta1.Query/Params" => {
+                    let resp = to_json_binary(&ParamsResponseTokenfactory {
+                        params: ParamsTokenfactory {
+                            denom_creation_fee: vec![coin(1_000_000, "untrn")],
+                            denom_creation_gas_consume: 0,
+                            fee_collector_address: "neutron_dao_address".to_string(),
+                            whitelisted_hooks: vec![],
+                        },
+                    });
+                    SystemResult::Ok(ContractResult::from(resp))
+                }
+                "/neutron.dex.Query/Params" => {
+                    let resp = to_json_binary(&ParamsResponseDex {
+                        params: ParamsDex {
+                            fee_tiers: vec![1, 2, 3, 4, 5],
+                            paused: false,
+                            max_jits_per_block: 25,
+                            good_til_purge_allowance: 540000,
+                        },
+                    });
+                    SystemResult::Ok(ContractResult::from(resp))
+                }
+                "/neutron.interchaintxs.v1.Query/Params" => {
+                    let resp = to_json_binary(&ParamsResponseInterchaintxs {
+                        params: ParamsInterchaintxs {
+                            msg_submit_tx_max_messages: 16,
+                            register_fee: vec![coin(1_000_000, "untrn")],
+                        },
+                    });
+                    SystemResult::Ok(ContractResult::from(resp))
+                }
+                "/neutron.feeburner.Query/Params" => {
+                    let resp = to_json_binary(&ParamsResponseFeeburner {
+                        params: ParamsFeeburner {
+                            neutron_denom: "untrn".to_string(),
+                            treasury_address: "neutron_dao_address".to_string(),
+                        },
+                    });
+                    SystemResult::Ok(ContractResult::from(resp))
+                }
+                "/neutron.feerefunder.Query/Params" => {
+                    let resp = to_json_binary(&ParamsResponseFeerefunder {
+                        params: ParamsFeerefunder {
+                            min_fee: Fee {
+                                recv_fee: vec![],
+                                ack_fee: vec![coin(1000, "untrn")],
+                                timeout_fee: vec![coin(1000, "untrn")],
+                            },
+                        },
+                    });
+                    SystemResult::Ok(ContractResult::from(resp))
+                }
+                "/neutron.contractmanager.Query/Params" => {
+                    let resp = to_json_binary(&ParamsResponseContractmanager {
+                        params: ParamsContractmanager {
+                            sudo_call_gas_limit: 1_000_000,
                         },
                     });
                     SystemResult::Ok(ContractResult::from(resp))
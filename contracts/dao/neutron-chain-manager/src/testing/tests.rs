@@ -1,15 +1,38 @@
 use crate::contract::{
-    execute_add_strategy, execute_execute_messages, execute_remove_strategy, instantiate,
+    execute_add_strategy, execute_approve_batch, execute_cancel_batch, execute_execute_batch,
+    execute_execute_messages, execute_remove_strategy, execute_submit_batch, instantiate,
+    query_audit_log, query_check_messages, query_pending_batches, query_rate_limits,
+    query_strategies,
+};
+use crate::error::ContractError::{
+    AlreadyApproved, ApprovalRequired, CooldownActive, DeserializationError, InvalidDemotion,
+    InvalidExpiration, InvalidMultiPartyStrategy, InvalidRateLimit, NotEnoughApprovals,
+    RateLimitExceeded, StrategyExpired, Unauthorized,
+};
+use crate::msg::Permission::{
+    BankSendPermission, CronPermission, ParamChangePermission, UpdateParamsPermission,
+    WasmExecutePermission,
 };
-use crate::error::ContractError::{InvalidDemotion, Unauthorized};
-use crate::msg::InstantiateMsg;
-use crate::msg::Permission::{CronPermission, ParamChangePermission, UpdateParamsPermission};
 use crate::msg::UpdateParamsPermission::CronUpdateParamsPermission as CronUpdateParamsPermissionEnumField;
+use crate::msg::UpdateParamsPermission::{
+    DexUpdateParamsPermission as DexUpdateParamsPermissionEnumField,
+    TokenfactoryUpdateParamsPermission as TokenfactoryUpdateParamsPermissionEnumField,
+};
+use crate::msg::{
+    AuditLogAction, InstantiateMsg, MessageCheck, PermissionType, Quota, RateLimit,
+    StrategyResponse, StrategySnapshot, StrategyType,
+};
+use crate::msg::{
+    BankSendPermission as BankSendPermissionType, BankSendRecipientPermission,
+    WasmExecuteContractPermission, WasmExecutePermission as WasmExecutePermissionType,
+};
 use crate::msg::{CronPermission as CronPermissionType, CronUpdateParamsPermission, StrategyMsg};
+use crate::msg::{DexUpdateParamsPermission, TokenfactoryUpdateParamsPermission};
 use crate::msg::{ParamChangePermission as ParamChangePermissionType, ParamPermission};
 use crate::testing::mock_querier::mock_dependencies;
 use cosmwasm_std::testing::{mock_env, mock_info};
-use cosmwasm_std::{Addr, BankMsg, Coin, CosmosMsg, Uint128};
+use cosmwasm_std::{coins, Addr, BankMsg, Binary, Coin, CosmosMsg, Uint128, WasmMsg};
+use cw_utils::{Duration, Expiration};
 use neutron_sdk::bindings::msg::{
     AdminProposal, ClientUpdateProposal, NeutronMsg, ParamChange, ParamChangeProposal,
     ProposalExecuteMessage,
@@ -52,9 +75,12 @@ fn test_add_strategy() {
     let info = mock_info("neutron_dao_address", &[]);
     execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr1".to_string()),
         StrategyMsg::AllowAll,
+        None,
+        None,
     )
     .unwrap();
 
@@ -62,12 +88,15 @@ fn test_add_strategy() {
     let info = mock_info("neutron_dao_address", &[]);
     execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr2".to_string()),
         StrategyMsg::AllowOnly(vec![CronPermission(CronPermissionType {
             add_schedule: true,
             remove_schedule: true,
         })]),
+        None,
+        None,
     )
     .unwrap();
 }
@@ -93,28 +122,37 @@ fn test_add_strategy_promotion() {
     let info = mock_info("neutron_dao_address", &[]);
     execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr2".to_string()),
         StrategyMsg::AllowOnly(vec![CronPermission(CronPermissionType {
             add_schedule: true,
             remove_schedule: true,
         })]),
+        None,
+        None,
     )
     .unwrap();
 
     execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr2".to_string()),
         StrategyMsg::AllowAll,
+        None,
+        None,
     )
     .unwrap();
     let info = mock_info("addr2", &[]);
     execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr3".to_string()),
         StrategyMsg::AllowAll,
+        None,
+        None,
     )
     .unwrap();
 }
@@ -140,27 +178,36 @@ fn test_add_strategy_demotion() {
     let info = mock_info("neutron_dao_address", &[]);
     execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr1".to_string()),
         StrategyMsg::AllowAll,
+        None,
+        None,
     )
     .unwrap();
     execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr1".to_string()),
         StrategyMsg::AllowOnly(vec![CronPermission(CronPermissionType {
             add_schedule: true,
             remove_schedule: true,
         })]),
+        None,
+        None,
     )
     .unwrap();
     let info = mock_info("addr1", &[]);
     let err = execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info,
         Addr::unchecked("addr2".to_string()),
         StrategyMsg::AllowAll,
+        None,
+        None,
     )
     .unwrap_err();
     assert_eq!(err, Unauthorized {})
@@ -187,12 +234,15 @@ fn test_add_strategy_invalid_demotion() {
     let info = mock_info("neutron_dao_address", &[]);
     let err = execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("neutron_dao_address".to_string()),
         StrategyMsg::AllowOnly(vec![CronPermission(CronPermissionType {
             add_schedule: true,
             remove_schedule: true,
         })]),
+        None,
+        None,
     )
     .unwrap_err();
     assert_eq!(err, InvalidDemotion {});
@@ -217,13 +267,17 @@ fn test_remove_strategy() {
     let info = mock_info("neutron_dao_address", &[]);
     execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr1".to_string()),
         StrategyMsg::AllowAll,
+        None,
+        None,
     )
     .unwrap();
     execute_remove_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr1".to_string()),
     )
@@ -232,9 +286,12 @@ fn test_remove_strategy() {
     let info = mock_info("addr1", &[]);
     let err = execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info,
         Addr::unchecked("addr1".to_string()),
         StrategyMsg::AllowAll,
+        None,
+        None,
     )
     .unwrap_err();
     assert_eq!(err, Unauthorized {})
@@ -258,6 +315,7 @@ fn test_remove_strategy_invalid_demotion() {
 
     let err = execute_remove_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("neutron_dao_address".to_string()),
     )
@@ -296,6 +354,7 @@ pub fn test_execute_execute_message_update_params_cron_authorized() {
     let info = mock_info("neutron_dao_address", &[]);
     execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr1".to_string()),
         StrategyMsg::AllowOnly(vec![UpdateParamsPermission(
@@ -304,11 +363,13 @@ pub fn test_execute_execute_message_update_params_cron_authorized() {
                 limit: true,
             }),
         )]),
+        None,
+        None,
     )
     .unwrap();
 
     let info = mock_info("addr1", &[]);
-    execute_execute_messages(deps.as_mut(), info.clone(), vec![msg]).unwrap();
+    execute_execute_messages(deps.as_mut(), env.clone(), info.clone(), vec![msg]).unwrap();
 }
 
 /// Checks that unsupported message types inside a ProposalExecuteMessage are not
@@ -341,6 +402,7 @@ pub fn test_execute_execute_message_unsupported_message_type_unauthorized() {
     let info = mock_info("neutron_dao_address", &[]);
     execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr1".to_string()),
         StrategyMsg::AllowOnly(vec![UpdateParamsPermission(
@@ -349,11 +411,14 @@ pub fn test_execute_execute_message_unsupported_message_type_unauthorized() {
                 limit: true,
             }),
         )]),
+        None,
+        None,
     )
     .unwrap();
 
     let info = mock_info("addr1", &[]);
-    let err = execute_execute_messages(deps.as_mut(), info.clone(), vec![msg]).unwrap_err();
+    let err =
+        execute_execute_messages(deps.as_mut(), env.clone(), info.clone(), vec![msg]).unwrap_err();
     assert_eq!(err, Unauthorized {})
 }
 
@@ -387,6 +452,7 @@ pub fn test_execute_execute_message_update_params_cron_unauthorized_limit() {
     let info = mock_info("neutron_dao_address", &[]);
     execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr1".to_string()),
         StrategyMsg::AllowOnly(vec![UpdateParamsPermission(
@@ -395,11 +461,14 @@ pub fn test_execute_execute_message_update_params_cron_unauthorized_limit() {
                 limit: false,
             }),
         )]),
+        None,
+        None,
     )
     .unwrap();
 
     let info = mock_info("addr1", &[]);
-    let err = execute_execute_messages(deps.as_mut(), info.clone(), vec![msg]).unwrap_err();
+    let err =
+        execute_execute_messages(deps.as_mut(), env.clone(), info.clone(), vec![msg]).unwrap_err();
     assert_eq!(err, Unauthorized {})
 }
 
@@ -433,6 +502,7 @@ pub fn test_execute_execute_message_update_params_cron_unauthorized_security_add
     let info = mock_info("neutron_dao_address", &[]);
     execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr1".to_string()),
         StrategyMsg::AllowOnly(vec![UpdateParamsPermission(
@@ -441,11 +511,14 @@ pub fn test_execute_execute_message_update_params_cron_unauthorized_security_add
                 limit: true,
             }),
         )]),
+        None,
+        None,
     )
     .unwrap();
 
     let info = mock_info("addr1", &[]);
-    let err = execute_execute_messages(deps.as_mut(), info.clone(), vec![msg]).unwrap_err();
+    let err =
+        execute_execute_messages(deps.as_mut(), env.clone(), info.clone(), vec![msg]).unwrap_err();
     assert_eq!(err, Unauthorized {});
 }
 
@@ -481,6 +554,7 @@ pub fn test_execute_execute_message_param_change_success() {
     let info = mock_info("neutron_dao_address", &[]);
     execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr1".to_string()),
         StrategyMsg::AllowOnly(vec![ParamChangePermission(ParamChangePermissionType {
@@ -489,11 +563,13 @@ pub fn test_execute_execute_message_param_change_success() {
                 key: "MinimumGasPricesParam".to_string(),
             }],
         })]),
+        None,
+        None,
     )
     .unwrap();
 
     let info = mock_info("addr1", &[]);
-    execute_execute_messages(deps.as_mut(), info.clone(), vec![msg]).unwrap();
+    execute_execute_messages(deps.as_mut(), env.clone(), info.clone(), vec![msg]).unwrap();
 }
 
 /// Checks that you can not update a legacy param without the necessary ALLOW_ONLY permission.
@@ -529,6 +605,7 @@ pub fn test_execute_execute_message_param_change_unauthorized_key() {
     let info = mock_info("neutron_dao_address", &[]);
     execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr1".to_string()),
         StrategyMsg::AllowOnly(vec![ParamChangePermission(ParamChangePermissionType {
@@ -537,11 +614,14 @@ pub fn test_execute_execute_message_param_change_unauthorized_key() {
                 key: "0xdeadbeef".to_string(),
             }],
         })]),
+        None,
+        None,
     )
     .unwrap();
 
     let info = mock_info("addr1", &[]);
-    let err = execute_execute_messages(deps.as_mut(), info.clone(), vec![msg]).unwrap_err();
+    let err =
+        execute_execute_messages(deps.as_mut(), env.clone(), info.clone(), vec![msg]).unwrap_err();
     assert_eq!(err, Unauthorized {});
 }
 
@@ -578,6 +658,7 @@ pub fn test_execute_execute_message_param_change_unauthorized_subspace() {
     let info = mock_info("neutron_dao_address", &[]);
     execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr1".to_string()),
         StrategyMsg::AllowOnly(vec![ParamChangePermission(ParamChangePermissionType {
@@ -586,11 +667,14 @@ pub fn test_execute_execute_message_param_change_unauthorized_subspace() {
                 key: "MinimumGasPricesParam".to_string(),
             }],
         })]),
+        None,
+        None,
     )
     .unwrap();
 
     let info = mock_info("addr1", &[]);
-    let err = execute_execute_messages(deps.as_mut(), info.clone(), vec![msg]).unwrap_err();
+    let err =
+        execute_execute_messages(deps.as_mut(), env.clone(), info.clone(), vec![msg]).unwrap_err();
     assert_eq!(err, Unauthorized {});
 }
 
@@ -615,6 +699,7 @@ pub fn test_execute_execute_unknown_message() {
     let info = mock_info("neutron_dao_address", &[]);
     execute_add_strategy(
         deps.as_mut(),
+        env.clone(),
         info.clone(),
         Addr::unchecked("addr1".to_string()),
         StrategyMsg::AllowOnly(vec![ParamChangePermission(ParamChangePermissionType {
@@ -623,6 +708,8 @@ pub fn test_execute_execute_unknown_message() {
                 key: "0xdeadbeef".to_string(),
             }],
         })]),
+        None,
+        None,
     )
     .unwrap();
 
@@ -631,7 +718,8 @@ pub fn test_execute_execute_unknown_message() {
     });
 
     let info = mock_info("addr1", &[]);
-    let err = execute_execute_messages(deps.as_mut(), info.clone(), vec![msg]).unwrap_err();
+    let err =
+        execute_execute_messages(deps.as_mut(), env.clone(), info.clone(), vec![msg]).unwrap_err();
     assert_eq!(err, Unauthorized {});
 
     let msg = CosmosMsg::Custom(NeutronMsg::BurnTokens {
@@ -641,7 +729,8 @@ pub fn test_execute_execute_unknown_message() {
     });
 
     let info = mock_info("addr1", &[]);
-    let err = execute_execute_messages(deps.as_mut(), info.clone(), vec![msg]).unwrap_err();
+    let err =
+        execute_execute_messages(deps.as_mut(), env.clone(), info.clone(), vec![msg]).unwrap_err();
     assert_eq!(err, Unauthorized {});
 
     let msg = CosmosMsg::Custom(NeutronMsg::SubmitAdminProposal {
@@ -654,6 +743,1091 @@ pub fn test_execute_execute_unknown_message() {
     });
 
     let info = mock_info("addr1", &[]);
-    let err = execute_execute_messages(deps.as_mut(), info.clone(), vec![msg]).unwrap_err();
+    let err =
+        execute_execute_messages(deps.as_mut(), env.clone(), info.clone(), vec![msg]).unwrap_err();
     assert_eq!(err, Unauthorized {});
 }
+
+/// Checks that an ALLOW_ONLY strategy with an expiration can be used until it
+/// lapses, and is rejected afterwards.
+#[test]
+pub fn test_execute_execute_message_expired_strategy() {
+    let msg = CosmosMsg::Custom(NeutronMsg::RemoveSchedule {
+        name: "schedule1".to_string(),
+    });
+
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let info = mock_info("neutron_dao_address", &[]);
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            initial_strategy_address: Addr::unchecked("neutron_dao_address".to_string()),
+        },
+    )
+    .unwrap();
+
+    let expiration = Expiration::AtHeight(env.block.height + 10);
+    execute_add_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        Addr::unchecked("addr1".to_string()),
+        StrategyMsg::AllowOnly(vec![CronPermission(CronPermissionType {
+            add_schedule: false,
+            remove_schedule: true,
+        })]),
+        Some(expiration),
+        None,
+    )
+    .unwrap();
+
+    let strategies = query_strategies(deps.as_ref()).unwrap();
+    assert_eq!(
+        strategies,
+        vec![
+            StrategyResponse {
+                address: Addr::unchecked("addr1"),
+                strategy: StrategyMsg::AllowOnly(vec![CronPermission(CronPermissionType {
+                    add_schedule: false,
+                    remove_schedule: true,
+                })]),
+                expiration,
+            },
+            StrategyResponse {
+                address: Addr::unchecked("neutron_dao_address"),
+                strategy: StrategyMsg::AllowAll,
+                expiration: Expiration::Never {},
+            },
+        ]
+    );
+
+    let info = mock_info("addr1", &[]);
+    execute_execute_messages(deps.as_mut(), env.clone(), info.clone(), vec![msg.clone()]).unwrap();
+
+    env.block.height += 10;
+    let err = execute_execute_messages(deps.as_mut(), env.clone(), info, vec![msg]).unwrap_err();
+    assert_eq!(err, StrategyExpired {});
+}
+
+/// Checks that a strategy can not be added with an expiration that has already
+/// passed.
+#[test]
+pub fn test_add_strategy_invalid_expiration() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("neutron_dao_address", &[]);
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            initial_strategy_address: Addr::unchecked("neutron_dao_address".to_string()),
+        },
+    )
+    .unwrap();
+
+    let err = execute_add_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        Addr::unchecked("addr1".to_string()),
+        StrategyMsg::AllowAll,
+        Some(Expiration::AtHeight(env.block.height)),
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(err, InvalidExpiration {});
+}
+
+/// Checks that an expiring ALLOW_ALL strategy does not count as an admin, so the
+/// only permanent ALLOW_ALL strategy can not be removed.
+#[test]
+pub fn test_remove_strategy_expiring_admin_invalid_demotion() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("neutron_dao_address", &[]);
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            initial_strategy_address: Addr::unchecked("neutron_dao_address".to_string()),
+        },
+    )
+    .unwrap();
+
+    execute_add_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        Addr::unchecked("addr1".to_string()),
+        StrategyMsg::AllowAll,
+        Some(Expiration::AtHeight(env.block.height + 100)),
+        None,
+    )
+    .unwrap();
+
+    let err = execute_remove_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        Addr::unchecked("neutron_dao_address".to_string()),
+    )
+    .unwrap_err();
+    assert_eq!(err, InvalidDemotion {});
+}
+
+/// Checks that a quota caps the number of messages using a permission within
+/// a window, and that the counter is reset once the window is over.
+#[test]
+pub fn test_execute_execute_message_rate_limit_quota() {
+    let msg = CosmosMsg::Custom(NeutronMsg::RemoveSchedule {
+        name: "schedule1".to_string(),
+    });
+
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let info = mock_info("neutron_dao_address", &[]);
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            initial_strategy_address: Addr::unchecked("neutron_dao_address".to_string()),
+        },
+    )
+    .unwrap();
+
+    execute_add_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        Addr::unchecked("addr1".to_string()),
+        StrategyMsg::AllowOnly(vec![CronPermission(CronPermissionType {
+            add_schedule: false,
+            remove_schedule: true,
+        })]),
+        None,
+        Some(vec![RateLimit {
+            permission_type: PermissionType::CronPermission,
+            quota: Some(Quota {
+                max_messages: 2,
+                window: Duration::Height(10),
+            }),
+            cooldown: None,
+        }]),
+    )
+    .unwrap();
+
+    let info = mock_info("addr1", &[]);
+    execute_execute_messages(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        vec![msg.clone(), msg.clone()],
+    )
+    .unwrap();
+
+    let usage = query_rate_limits(deps.as_ref(), Addr::unchecked("addr1"))
+        .unwrap()
+        .usage;
+    assert_eq!(usage.len(), 1);
+    assert_eq!(usage[0].messages_in_window, 2);
+    assert_eq!(
+        usage[0].window_expiration,
+        Some(Expiration::AtHeight(env.block.height + 10))
+    );
+
+    let err = execute_execute_messages(deps.as_mut(), env.clone(), info.clone(), vec![msg.clone()])
+        .unwrap_err();
+    assert_eq!(
+        err,
+        RateLimitExceeded {
+            permission_type: PermissionType::CronPermission
+        }
+    );
+
+    env.block.height += 10;
+    execute_execute_messages(deps.as_mut(), env.clone(), info, vec![msg]).unwrap();
+}
+
+/// Checks that a permission can not be used again before its cooldown is over.
+#[test]
+pub fn test_execute_execute_message_rate_limit_cooldown() {
+    let msg = CosmosMsg::Custom(NeutronMsg::RemoveSchedule {
+        name: "schedule1".to_string(),
+    });
+
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let info = mock_info("neutron_dao_address", &[]);
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            initial_strategy_address: Addr::unchecked("neutron_dao_address".to_string()),
+        },
+    )
+    .unwrap();
+
+    execute_add_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        Addr::unchecked("addr1".to_string()),
+        StrategyMsg::AllowOnly(vec![CronPermission(CronPermissionType {
+            add_schedule: false,
+            remove_schedule: true,
+        })]),
+        None,
+        Some(vec![RateLimit {
+            permission_type: PermissionType::CronPermission,
+            quota: None,
+            cooldown: Some(Duration::Time(60)),
+        }]),
+    )
+    .unwrap();
+
+    let info = mock_info("addr1", &[]);
+    execute_execute_messages(deps.as_mut(), env.clone(), info.clone(), vec![msg.clone()]).unwrap();
+
+    env.block.time = env.block.time.plus_seconds(30);
+    let err = execute_execute_messages(deps.as_mut(), env.clone(), info.clone(), vec![msg.clone()])
+        .unwrap_err();
+    assert_eq!(
+        err,
+        CooldownActive {
+            permission_type: PermissionType::CronPermission
+        }
+    );
+
+    env.block.time = env.block.time.plus_seconds(30);
+    execute_execute_messages(deps.as_mut(), env.clone(), info, vec![msg]).unwrap();
+}
+
+/// Checks that rate limits can only be set on permissions granted by the
+/// strategy.
+#[test]
+pub fn test_add_strategy_invalid_rate_limit() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("neutron_dao_address", &[]);
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            initial_strategy_address: Addr::unchecked("neutron_dao_address".to_string()),
+        },
+    )
+    .unwrap();
+
+    let err = execute_add_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        Addr::unchecked("addr1".to_string()),
+        StrategyMsg::AllowOnly(vec![CronPermission(CronPermissionType {
+            add_schedule: true,
+            remove_schedule: true,
+        })]),
+        None,
+        Some(vec![RateLimit {
+            permission_type: PermissionType::ParamChangePermission,
+            quota: None,
+            cooldown: Some(Duration::Height(1)),
+        }]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        InvalidRateLimit {
+            reason: "strategy has no ParamChangePermission permission".to_string()
+        }
+    );
+}
+
+/// Checks that you can change the parameters of the dex module you have the
+/// permission for, and only them.
+#[test]
+pub fn test_execute_execute_message_update_params_dex() {
+    let msg_pause = CosmosMsg::Custom(NeutronMsg::SubmitAdminProposal {
+        admin_proposal: AdminProposal::ProposalExecuteMessage(ProposalExecuteMessage {
+            message: r#"{"@type":"/neutron.dex.MsgUpdateParams",
+            "authority":"neutron1hxskfdxpp5hqgtjj6am6nkjefhfzj359x0ar3z",
+            "params": {"fee_tiers": ["1", "2", "3", "4", "5"], "paused": true,
+            "max_jits_per_block": "25", "good_til_purge_allowance": "540000"}}"#
+                .to_string(),
+        }),
+    });
+    let msg_fee_tiers = CosmosMsg::Custom(NeutronMsg::SubmitAdminProposal {
+        admin_proposal: AdminProposal::ProposalExecuteMessage(ProposalExecuteMessage {
+            message: r#"{"@type":"/neutron.dex.MsgUpdateParams",
+            "authority":"neutron1hxskfdxpp5hqgtjj6am6nkjefhfzj359x0ar3z",
+            "params": {"fee_tiers": [1, 2, 3], "paused": false,
+            "max_jits_per_block": 25, "good_til_purge_allowance": 540000}}"#
+                .to_string(),
+        }),
+    });
+    // A field the permission doesn't know about can't be checked, so it is rejected.
+    let msg_unknown_field = CosmosMsg::Custom(NeutronMsg::SubmitAdminProposal {
+        admin_proposal: AdminProposal::ProposalExecuteMessage(ProposalExecuteMessage {
+            message: r#"{"@type":"/neutron.dex.MsgUpdateParams",
+            "authority":"neutron1hxskfdxpp5hqgtjj6am6nkjefhfzj359x0ar3z",
+            "params": {"fee_tiers": [1, 2, 3, 4, 5], "paused": true,
+            "max_jits_per_block": 25, "good_til_purge_allowance": 540000,
+            "max_pool_fee": "100"}}"#
+                .to_string(),
+        }),
+    });
+
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("neutron_dao_address", &[]);
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            initial_strategy_address: Addr::unchecked("neutron_dao_address".to_string()),
+        },
+    )
+    .unwrap();
+
+    execute_add_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        Addr::unchecked("addr1".to_string()),
+        StrategyMsg::AllowOnly(vec![UpdateParamsPermission(
+            DexUpdateParamsPermissionEnumField(DexUpdateParamsPermission {
+                fee_tiers: false,
+                paused: true,
+                max_jits_per_block: false,
+                good_til_purge_allowance: false,
+            }),
+        )]),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let info = mock_info("addr1", &[]);
+    execute_execute_messages(deps.as_mut(), env.clone(), info.clone(), vec![msg_pause]).unwrap();
+
+    let err = execute_execute_messages(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        vec![msg_fee_tiers],
+    )
+    .unwrap_err();
+    assert_eq!(err, Unauthorized {});
+
+    let err = execute_execute_messages(deps.as_mut(), env.clone(), info, vec![msg_unknown_field])
+        .unwrap_err();
+    assert!(matches!(err, DeserializationError(_)));
+}
+
+/// Checks that permissions to update the parameters of several modules can be
+/// granted to the same address.
+#[test]
+pub fn test_execute_execute_message_update_params_several_modules() {
+    let msg_cron = CosmosMsg::Custom(NeutronMsg::SubmitAdminProposal {
+        admin_proposal: AdminProposal::ProposalExecuteMessage(ProposalExecuteMessage {
+            message: r#"{"@type":"/neutron.cron.MsgUpdateParams",
+            "authority":"neutron1hxskfdxpp5hqgtjj6am6nkjefhfzj359x0ar3z",
+            "params": {"security_address": "neutron_dao_address", "limit": 16}}"#
+                .to_string(),
+        }),
+    });
+    let msg_tokenfactory = CosmosMsg::Custom(NeutronMsg::SubmitAdminProposal {
+        admin_proposal: AdminProposal::ProposalExecuteMessage(ProposalExecuteMessage {
+            message: r#"{"@type":"/osmosis.tokenfactory.v1beta1.MsgUpdateParams",
+            "authority":"neutron1hxskfdxpp5hqgtjj6am6nkjefhfzj359x0ar3z",
+            "params": {"denom_creation_fee": [{"denom": "untrn", "amount": "2000000"}],
+            "denom_creation_gas_consume": "0", "fee_collector_address": "neutron_dao_address",
+            "whitelisted_hooks": []}}"#
+                .to_string(),
+        }),
+    });
+
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("neutron_dao_address", &[]);
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            initial_strategy_address: Addr::unchecked("neutron_dao_address".to_string()),
+        },
+    )
+    .unwrap();
+
+    execute_add_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        Addr::unchecked("addr1".to_string()),
+        StrategyMsg::AllowOnly(vec![
+            UpdateParamsPermission(CronUpdateParamsPermissionEnumField(
+                CronUpdateParamsPermission {
+                    security_address: false,
+                    limit: true,
+                },
+            )),
+            UpdateParamsPermission(TokenfactoryUpdateParamsPermissionEnumField(
+                TokenfactoryUpdateParamsPermission {
+                    denom_creation_fee: true,
+                    denom_creation_gas_consume: false,
+                    fee_collector_address: false,
+                    whitelisted_hooks: false,
+                },
+            )),
+        ]),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let info = mock_info("addr1", &[]);
+    execute_execute_messages(deps.as_mut(), env, info, vec![msg_cron, msg_tokenfactory]).unwrap();
+}
+
+/// Checks that execute messages can only be sent to allow-listed contracts,
+/// with the allowed top-level keys and without funds unless those are allowed
+/// by the bank send permission.
+#[test]
+pub fn test_execute_execute_message_wasm_execute() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("neutron_dao_address", &[]);
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            initial_strategy_address: Addr::unchecked("neutron_dao_address".to_string()),
+        },
+    )
+    .unwrap();
+
+    execute_add_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        Addr::unchecked("addr1".to_string()),
+        StrategyMsg::AllowOnly(vec![WasmExecutePermission(WasmExecutePermissionType {
+            contracts: vec![WasmExecuteContractPermission {
+                contract_address: "contract1".to_string(),
+                allowed_messages: Some(vec!["update_config".to_string(), "pause".to_string()]),
+            }],
+        })]),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let execute_msg = |contract_addr: &str, msg: &str, funds: Vec<Coin>| {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: contract_addr.to_string(),
+            msg: Binary::from(msg.as_bytes()),
+            funds,
+        })
+    };
+
+    let info = mock_info("addr1", &[]);
+    execute_execute_messages(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        vec![
+            execute_msg(
+                "contract1",
+                r#"{"update_config": {"owner": "addr2"}}"#,
+                vec![],
+            ),
+            execute_msg("contract1", r#""pause""#, vec![]),
+        ],
+    )
+    .unwrap();
+
+    // Scenario 1: a message that is not allowed for the contract (fails).
+    let err = execute_execute_messages(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        vec![execute_msg("contract1", r#"{"withdraw": {}}"#, vec![])],
+    )
+    .unwrap_err();
+    assert_eq!(err, Unauthorized {});
+
+    // Scenario 2: a contract that is not allow-listed (fails).
+    let err = execute_execute_messages(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        vec![execute_msg("contract2", r#"{"update_config": {}}"#, vec![])],
+    )
+    .unwrap_err();
+    assert_eq!(err, Unauthorized {});
+
+    // Scenario 3: funds are attached without a bank send permission (fails).
+    let err = execute_execute_messages(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        vec![execute_msg(
+            "contract1",
+            r#"{"update_config": {}}"#,
+            coins(10, "untrn"),
+        )],
+    )
+    .unwrap_err();
+    assert_eq!(err, Unauthorized {});
+}
+
+/// Checks that bank sends are capped per denom and per recipient, over all
+/// the messages of an execution.
+#[test]
+pub fn test_execute_execute_message_bank_send() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("neutron_dao_address", &[]);
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            initial_strategy_address: Addr::unchecked("neutron_dao_address".to_string()),
+        },
+    )
+    .unwrap();
+
+    execute_add_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        Addr::unchecked("addr1".to_string()),
+        StrategyMsg::AllowOnly(vec![BankSendPermission(BankSendPermissionType {
+            max_amounts: coins(100, "untrn"),
+            recipients: vec![
+                BankSendRecipientPermission {
+                    address: "recipient1".to_string(),
+                    max_amounts: None,
+                },
+                BankSendRecipientPermission {
+                    address: "recipient2".to_string(),
+                    max_amounts: Some(coins(10, "untrn")),
+                },
+            ],
+        })]),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let send_msg = |to_address: &str, amount: Vec<Coin>| {
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: to_address.to_string(),
+            amount,
+        })
+    };
+
+    let info = mock_info("addr1", &[]);
+    execute_execute_messages(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        vec![
+            send_msg("recipient1", coins(90, "untrn")),
+            send_msg("recipient2", coins(10, "untrn")),
+        ],
+    )
+    .unwrap();
+
+    let cases = [
+        // Above the per-denom cap, including when split across several coins.
+        vec![send_msg("recipient1", coins(101, "untrn"))],
+        vec![send_msg(
+            "recipient1",
+            vec![Coin::new(60, "untrn"), Coin::new(60, "untrn")],
+        )],
+        // Above the per-denom cap when split across several messages.
+        vec![
+            send_msg("recipient1", coins(60, "untrn")),
+            send_msg("recipient2", coins(5, "untrn")),
+            send_msg("recipient1", coins(40, "untrn")),
+        ],
+        // A denom without a cap.
+        vec![send_msg("recipient1", coins(1, "uatom"))],
+        // Above the recipient cap, in one or several messages.
+        vec![send_msg("recipient2", coins(11, "untrn"))],
+        vec![
+            send_msg("recipient2", coins(6, "untrn")),
+            send_msg("recipient2", coins(6, "untrn")),
+        ],
+        // A recipient that is not allowed.
+        vec![send_msg("recipient3", coins(1, "untrn"))],
+    ];
+    for msgs in cases {
+        let err =
+            execute_execute_messages(deps.as_mut(), env.clone(), info.clone(), msgs).unwrap_err();
+        assert_eq!(err, Unauthorized {});
+    }
+}
+
+/// Checks that the dry-run query reports, for each message, whether it would be
+/// executed and which permission is missing otherwise.
+#[test]
+pub fn test_query_check_messages() {
+    let param_change_msg = CosmosMsg::Custom(NeutronMsg::SubmitAdminProposal {
+        admin_proposal: AdminProposal::ParamChangeProposal(ParamChangeProposal {
+            title: "test_proposal".to_string(),
+            description: "Test proposal".to_string(),
+            param_changes: vec![ParamChange {
+                subspace: "globalfee".to_string(),
+                key: "MinimumGasPricesParam".to_string(),
+                value: "1000".to_string(),
+            }],
+        }),
+    });
+    let remove_schedule_msg = CosmosMsg::Custom(NeutronMsg::RemoveSchedule {
+        name: "schedule1".to_string(),
+    });
+    let burn_msg = CosmosMsg::Bank(BankMsg::Burn {
+        amount: vec![Coin::new(42, "untrn".to_string())],
+    });
+
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("neutron_dao_address", &[]);
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            initial_strategy_address: Addr::unchecked("neutron_dao_address".to_string()),
+        },
+    )
+    .unwrap();
+
+    execute_add_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        Addr::unchecked("addr1".to_string()),
+        StrategyMsg::AllowOnly(vec![ParamChangePermission(ParamChangePermissionType {
+            params: vec![ParamPermission {
+                subspace: "globalfee".to_string(),
+                key: "MinimumGasPricesParam".to_string(),
+            }],
+        })]),
+        None,
+        None,
+    )
+    .unwrap();
+
+    let resp = query_check_messages(
+        deps.as_ref(),
+        env.clone(),
+        Addr::unchecked("addr1"),
+        vec![param_change_msg.clone(), remove_schedule_msg, burn_msg],
+    )
+    .unwrap();
+    assert!(!resp.allowed);
+    assert_eq!(
+        resp.messages,
+        vec![
+            MessageCheck::allowed(),
+            MessageCheck {
+                allowed: false,
+                missing_permission: Some(PermissionType::CronPermission),
+                error: Some("Unauthorized".to_string()),
+            },
+            MessageCheck {
+                allowed: false,
+                missing_permission: None,
+                error: Some("Unauthorized".to_string()),
+            },
+        ]
+    );
+
+    let resp = query_check_messages(
+        deps.as_ref(),
+        env.clone(),
+        Addr::unchecked("addr1"),
+        vec![param_change_msg.clone()],
+    )
+    .unwrap();
+    assert!(resp.allowed);
+
+    // An address without a strategy can not execute anything.
+    let resp = query_check_messages(
+        deps.as_ref(),
+        env,
+        Addr::unchecked("addr2"),
+        vec![param_change_msg],
+    )
+    .unwrap();
+    assert!(!resp.allowed);
+    assert_eq!(
+        resp.messages,
+        vec![MessageCheck {
+            allowed: false,
+            missing_permission: Some(PermissionType::ParamChangePermission),
+            error: Some("Unauthorized".to_string()),
+        }]
+    );
+}
+
+/// Checks that executed messages and strategy changes are recorded in the
+/// audit log and can be listed by sender.
+#[test]
+pub fn test_query_audit_log() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("neutron_dao_address", &[]);
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            initial_strategy_address: Addr::unchecked("neutron_dao_address".to_string()),
+        },
+    )
+    .unwrap();
+
+    let strategy = StrategyMsg::AllowOnly(vec![CronPermission(CronPermissionType {
+        add_schedule: false,
+        remove_schedule: true,
+    })]);
+    execute_add_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        Addr::unchecked("addr1".to_string()),
+        strategy.clone(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    execute_execute_messages(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr1", &[]),
+        vec![CosmosMsg::Custom(NeutronMsg::RemoveSchedule {
+            name: "schedule1".to_string(),
+        })],
+    )
+    .unwrap();
+
+    execute_remove_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        Addr::unchecked("addr1".to_string()),
+    )
+    .unwrap();
+
+    let snapshot = StrategySnapshot {
+        strategy,
+        expiration: Expiration::Never {},
+        rate_limits: vec![],
+    };
+
+    let log = query_audit_log(deps.as_ref(), None, None, None).unwrap();
+    assert_eq!(log.len(), 4);
+    assert_eq!(
+        log.iter().map(|entry| entry.id).collect::<Vec<u64>>(),
+        vec![3, 2, 1, 0]
+    );
+    assert_eq!(
+        log[0].action,
+        AuditLogAction::RemoveStrategy {
+            address: Addr::unchecked("addr1"),
+            before: Some(snapshot.clone()),
+        }
+    );
+    assert_eq!(
+        log[2].action,
+        AuditLogAction::AddStrategy {
+            address: Addr::unchecked("addr1"),
+            before: None,
+            after: snapshot,
+        }
+    );
+
+    let log = query_audit_log(deps.as_ref(), Some(Addr::unchecked("addr1")), None, None).unwrap();
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].id, 2);
+    assert_eq!(log[0].height, env.block.height);
+    assert_eq!(
+        log[0].action,
+        AuditLogAction::ExecuteMessages {
+            strategy: StrategyType::AllowOnly,
+            message_types: vec!["/neutron.cron.MsgRemoveSchedule".to_string()],
+        }
+    );
+
+    let log = query_audit_log(
+        deps.as_ref(),
+        Some(Addr::unchecked("neutron_dao_address")),
+        Some(3),
+        Some(1),
+    )
+    .unwrap();
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].id, 1);
+}
+
+#[test]
+pub fn test_multi_party_batch() {
+    let remove_schedule_msg = CosmosMsg::Custom(NeutronMsg::RemoveSchedule {
+        name: "schedule1".to_string(),
+    });
+
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("neutron_dao_address", &[]);
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            initial_strategy_address: Addr::unchecked("neutron_dao_address".to_string()),
+        },
+    )
+    .unwrap();
+
+    execute_add_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        Addr::unchecked("shared_address".to_string()),
+        StrategyMsg::MultiParty {
+            approvers: vec![
+                Addr::unchecked("security_subdao"),
+                Addr::unchecked("ops_team"),
+            ],
+            threshold: 2,
+            permissions: vec![CronPermission(CronPermissionType {
+                add_schedule: false,
+                remove_schedule: true,
+            })],
+        },
+        None,
+        None,
+    )
+    .unwrap();
+
+    // Messages can't be executed directly.
+    let err = execute_execute_messages(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("shared_address", &[]),
+        vec![remove_schedule_msg.clone()],
+    )
+    .unwrap_err();
+    assert_eq!(err, ApprovalRequired {});
+
+    // Only approvers can submit batches, and only with allowed messages.
+    let err = execute_submit_batch(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr1", &[]),
+        Addr::unchecked("shared_address"),
+        vec![remove_schedule_msg.clone()],
+    )
+    .unwrap_err();
+    assert_eq!(err, Unauthorized {});
+    let err = execute_submit_batch(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("ops_team", &[]),
+        Addr::unchecked("shared_address"),
+        vec![CosmosMsg::Bank(BankMsg::Burn {
+            amount: coins(1, "untrn"),
+        })],
+    )
+    .unwrap_err();
+    assert_eq!(err, Unauthorized {});
+
+    execute_submit_batch(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("ops_team", &[]),
+        Addr::unchecked("shared_address"),
+        vec![remove_schedule_msg.clone()],
+    )
+    .unwrap();
+
+    let err = execute_execute_batch(deps.as_mut(), env.clone(), mock_info("ops_team", &[]), 0)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        NotEnoughApprovals {
+            approvals: 1,
+            threshold: 2
+        }
+    );
+    let err = execute_approve_batch(deps.as_mut(), env.clone(), mock_info("ops_team", &[]), 0)
+        .unwrap_err();
+    assert_eq!(err, AlreadyApproved {});
+    let err =
+        execute_approve_batch(deps.as_mut(), env.clone(), mock_info("addr1", &[]), 0).unwrap_err();
+    assert_eq!(err, Unauthorized {});
+
+    execute_approve_batch(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("security_subdao", &[]),
+        0,
+    )
+    .unwrap();
+
+    let pending = query_pending_batches(
+        deps.as_ref(),
+        Some(Addr::unchecked("shared_address")),
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(
+        pending[0].approvals,
+        vec![
+            Addr::unchecked("ops_team"),
+            Addr::unchecked("security_subdao")
+        ]
+    );
+
+    let res =
+        execute_execute_batch(deps.as_mut(), env.clone(), mock_info("ops_team", &[]), 0).unwrap();
+    assert_eq!(res.messages.len(), 1);
+    assert!(query_pending_batches(deps.as_ref(), None, None, None)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+pub fn test_multi_party_batch_cancel() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("neutron_dao_address", &[]);
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            initial_strategy_address: Addr::unchecked("neutron_dao_address".to_string()),
+        },
+    )
+    .unwrap();
+
+    let strategy = StrategyMsg::MultiParty {
+        approvers: vec![
+            Addr::unchecked("security_subdao"),
+            Addr::unchecked("ops_team"),
+        ],
+        threshold: 3,
+        permissions: vec![CronPermission(CronPermissionType {
+            add_schedule: false,
+            remove_schedule: true,
+        })],
+    };
+    let err = execute_add_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        Addr::unchecked("shared_address".to_string()),
+        strategy,
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        InvalidMultiPartyStrategy {
+            reason: "threshold must be between 1 and the number of approvers".to_string()
+        }
+    );
+
+    execute_add_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        Addr::unchecked("shared_address".to_string()),
+        StrategyMsg::MultiParty {
+            approvers: vec![
+                Addr::unchecked("security_subdao"),
+                Addr::unchecked("ops_team"),
+            ],
+            threshold: 1,
+            permissions: vec![CronPermission(CronPermissionType {
+                add_schedule: false,
+                remove_schedule: true,
+            })],
+        },
+        None,
+        None,
+    )
+    .unwrap();
+
+    for _ in 0..2 {
+        execute_submit_batch(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("ops_team", &[]),
+            Addr::unchecked("shared_address"),
+            vec![CosmosMsg::Custom(NeutronMsg::RemoveSchedule {
+                name: "schedule1".to_string(),
+            })],
+        )
+        .unwrap();
+    }
+
+    // Any approver can cancel a batch.
+    execute_cancel_batch(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("security_subdao", &[]),
+        0,
+    )
+    .unwrap();
+    let pending = query_pending_batches(deps.as_ref(), None, None, None).unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].id, 1);
+
+    // Removing the strategy drops its pending batches.
+    execute_remove_strategy(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        Addr::unchecked("shared_address".to_string()),
+    )
+    .unwrap();
+    assert!(query_pending_batches(deps.as_ref(), None, None, None)
+        .unwrap()
+        .is_empty());
+}
@@ -14,14 +14,16 @@ use cwd_pre_propose_base::{
     state::PreProposeContract,
 };
 use neutron_dao_pre_propose_overrule::msg::{
-    ExecuteMsg, InstantiateMsg, MigrateMsg, ProposeMessage, QueryExt, QueryMsg,
+    ExecuteMsg, InstantiateMsg, MigrateMsg, OverruleParams, ProposeMessage, QueryExt, QueryMsg,
 };
 
-use crate::state::PROPOSALS;
+use crate::state::{OVERRULE_PARAMS, PROPOSALS};
 use cwd_core::msg::QueryMsg as MainDaoQueryMsg;
 use cwd_proposal_single::{
     msg::ExecuteMsg as ProposeMessageInternal, msg::QueryMsg as ProposalSingleQueryMsg,
+    query::ProposalResponse,
 };
+use cwd_voting::status::Status;
 use neutron_subdao_core::{msg::QueryMsg as SubdaoQueryMsg, types as SubdaoTypes};
 use neutron_subdao_timelock_single::{msg as TimelockMsg, types as TimelockTypes};
 
@@ -55,14 +57,15 @@ pub fn instantiate(
     Ok(resp)
 }
 
+type ExecuteInternal = ExecuteBase<ProposeMessageInternal>;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, PreProposeOverruleError> {
-    type ExecuteInternal = ExecuteBase<ProposeMessageInternal>;
     let internal_msg = match msg {
         ExecuteMsg::Propose {
             msg:
@@ -70,73 +73,20 @@ pub fn execute(
                     timelock_contract,
                     proposal_id,
                 },
-        } => {
-            let timelock_contract_addr = deps.api.addr_validate(&timelock_contract)?;
-
-            if let Ok(id) =
-                PROPOSALS.load(deps.storage, (proposal_id, timelock_contract_addr.clone()))
-            {
-                return Err(PreProposeOverruleError::AlreadyExists { id });
-            }
-
-            let subdao_address = get_subdao_from_timelock(&deps, &timelock_contract_addr)?;
-
-            // We need this check since the timelock contract might be an impostor
-            // E.g. the timelock contract might be a malicious contract that is not a part of
-            // the subdao but pretends to be.
-            if !verify_is_timelock_from_subdao(
-                &deps,
-                &subdao_address,
-                timelock_contract_addr.clone(),
-            )? {
-                return Err(PreProposeOverruleError::SubdaoMisconfigured {});
-            }
-
-            if !is_subdao_legit(&deps, &subdao_address)? {
-                return Err(PreProposeOverruleError::ForbiddenSubdao {});
-            }
-
-            if !is_proposal_timelocked(&deps, &timelock_contract_addr, proposal_id)? {
-                return Err(PreProposeOverruleError::ProposalWrongState {});
-            }
-
-            let overrule_msg = CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: timelock_contract_addr.to_string(),
-                msg: to_json_binary(&TimelockMsg::ExecuteMsg::OverruleProposal { proposal_id })?,
-                funds: vec![],
-            });
-
-            let subdao_name = get_subdao_name(&deps, &subdao_address)?;
-            let prop_name: String = format!(
-                "Reject the proposal #{} of the '{}' subdao",
-                proposal_id, subdao_name
-            );
-            let prop_desc: String = format!(
-                "If this proposal will be accepted, the DAO is going to \
-overrule the proposal #{} of '{}' subdao (address {})",
-                proposal_id, subdao_name, subdao_address
-            );
-
-            let internal_msg = ExecuteInternal::Propose {
-                msg: ProposeMessageInternal::Propose {
-                    // Fill in proposer based on message sender.
-                    proposer: Some(info.sender.to_string()),
-                    title: prop_name,
-                    description: prop_desc,
-                    msgs: vec![overrule_msg],
+        } => propose_overrule(
+            &mut deps,
+            &info,
+            timelock_contract,
+            vec![proposal_id],
+            false,
+        ),
+        ExecuteMsg::Propose {
+            msg:
+                ProposeMessage::ProposeBatchOverrule {
+                    timelock_contract,
+                    proposal_ids,
                 },
-            };
-
-            let next_proposal_id = &get_next_proposal_id(&deps)?;
-
-            PROPOSALS.save(
-                deps.storage,
-                (proposal_id, timelock_contract_addr),
-                next_proposal_id,
-            )?;
-
-            Ok(internal_msg)
-        }
+        } => propose_overrule(&mut deps, &info, timelock_contract, proposal_ids, true),
         // The following messages are forwarded to the base contract
         ExecuteMsg::ProposalCreatedHook {
             proposal_id,
@@ -152,6 +102,10 @@ overrule the proposal #{} of '{}' subdao (address {})",
             proposal_id,
             new_status,
         }),
+        ExecuteMsg::UpdateOverruleParams {
+            timelock_contract,
+            params,
+        } => return execute_update_overrule_params(deps, info, timelock_contract, params),
         // ExecuteMsg::Withdraw and ExecuteMsg::UpdateConfig are unsupported
         // ExecuteMsg::Withdraw is unsupported because overrule proposals should have no deposits
         // ExecuteMsg::UpdateConfig since the config has only the info about deposits,
@@ -163,6 +117,143 @@ overrule the proposal #{} of '{}' subdao (address {})",
         .map_err(PreProposeOverruleError::PreProposeBase)
 }
 
+fn propose_overrule(
+    deps: &mut DepsMut,
+    info: &MessageInfo,
+    timelock_contract: String,
+    proposal_ids: Vec<u64>,
+    batch: bool,
+) -> Result<ExecuteInternal, PreProposeOverruleError> {
+    if proposal_ids.is_empty() {
+        return Err(PreProposeOverruleError::EmptyBatch {});
+    }
+
+    let timelock_contract_addr = deps.api.addr_validate(&timelock_contract)?;
+
+    for (i, proposal_id) in proposal_ids.iter().enumerate() {
+        if proposal_ids[..i].contains(proposal_id) {
+            return Err(PreProposeOverruleError::DuplicateProposal { id: *proposal_id });
+        }
+        if let Some(id) =
+            PROPOSALS.may_load(deps.storage, (*proposal_id, timelock_contract_addr.clone()))?
+        {
+            // The timelock creates an overrule proposal for every proposal it timelocks, so a
+            // batch takes over the members whose overrule proposal is still open.
+            if !batch || !is_overrule_proposal_open(deps, id)? {
+                return Err(PreProposeOverruleError::AlreadyExists { id });
+            }
+        }
+    }
+
+    let subdao_address = get_subdao_from_timelock(deps, &timelock_contract_addr)?;
+
+    // We need this check since the timelock contract might be an impostor
+    // E.g. the timelock contract might be a malicious contract that is not a part of
+    // the subdao but pretends to be.
+    if !verify_is_timelock_from_subdao(deps, &subdao_address, timelock_contract_addr.clone())? {
+        return Err(PreProposeOverruleError::SubdaoMisconfigured {});
+    }
+
+    if !is_subdao_legit(deps, &subdao_address)? {
+        return Err(PreProposeOverruleError::ForbiddenSubdao {});
+    }
+
+    let mut overrule_msgs = Vec::with_capacity(proposal_ids.len());
+    for proposal_id in &proposal_ids {
+        if !is_proposal_timelocked(deps, &timelock_contract_addr, *proposal_id)? {
+            return Err(PreProposeOverruleError::ProposalWrongState {});
+        }
+
+        overrule_msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: timelock_contract_addr.to_string(),
+            msg: to_json_binary(&TimelockMsg::ExecuteMsg::OverruleProposal {
+                proposal_id: *proposal_id,
+            })?,
+            funds: vec![],
+        }));
+    }
+
+    let subdao_name = get_subdao_name(deps, &subdao_address)?;
+    let ids = proposal_ids
+        .iter()
+        .map(|id| format!("#{}", id))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let proposals = if proposal_ids.len() == 1 {
+        "proposal"
+    } else {
+        "proposals"
+    };
+    let prop_name: String = format!(
+        "Reject the {} {} of the '{}' subdao",
+        proposals, ids, subdao_name
+    );
+    let prop_desc: String = format!(
+        "If this proposal will be accepted, the DAO is going to \
+overrule the {} {} of '{}' subdao (address {})",
+        proposals, ids, subdao_name, subdao_address
+    );
+
+    // Fill in proposer based on message sender.
+    let proposer = Some(info.sender.to_string());
+    let propose_msg =
+        match OVERRULE_PARAMS.may_load(deps.storage, timelock_contract_addr.clone())? {
+            Some(params) => ProposeMessageInternal::ProposeWithParams {
+                title: prop_name,
+                description: prop_desc,
+                msgs: overrule_msgs,
+                proposer,
+                threshold: params.threshold,
+                max_voting_period: params.max_voting_period,
+            },
+            None => ProposeMessageInternal::Propose {
+                title: prop_name,
+                description: prop_desc,
+                msgs: overrule_msgs,
+                proposer,
+            },
+        };
+
+    // Every proposal of the batch is indexed to the same overrule proposal,
+    // so `OverruleProposalId` works for each of them, and the timelock
+    // only executes them once the batch is declined. An overrule proposal
+    // the batch took over still overrules its proposal if it passes.
+    let next_proposal_id = &get_next_proposal_id(deps)?;
+    for proposal_id in proposal_ids {
+        PROPOSALS.save(
+            deps.storage,
+            (proposal_id, timelock_contract_addr.clone()),
+            next_proposal_id,
+        )?;
+    }
+
+    Ok(ExecuteInternal::Propose { msg: propose_msg })
+}
+
+fn execute_update_overrule_params(
+    deps: DepsMut,
+    info: MessageInfo,
+    timelock_contract: String,
+    params: Option<OverruleParams>,
+) -> Result<Response, PreProposeOverruleError> {
+    if info.sender != PrePropose::default().dao.load(deps.storage)? {
+        return Err(PreProposeOverruleError::Unauthorized {});
+    }
+
+    let timelock_contract_addr = deps.api.addr_validate(&timelock_contract)?;
+    match params {
+        Some(params) => {
+            params.threshold.validate()?;
+            OVERRULE_PARAMS.save(deps.storage, timelock_contract_addr, &params)?;
+        }
+        None => OVERRULE_PARAMS.remove(deps.storage, timelock_contract_addr),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "update_overrule_params")
+        .add_attribute("timelock_contract", timelock_contract))
+}
+
 fn get_subdao_from_timelock(
     deps: &DepsMut,
     timelock_contract: &Addr,
@@ -234,6 +325,19 @@ fn is_proposal_timelocked(
     Ok(proposal.status == TimelockTypes::ProposalStatus::Timelocked)
 }
 
+fn is_overrule_proposal_open(
+    deps: &DepsMut,
+    overrule_proposal_id: u64,
+) -> Result<bool, PreProposeOverruleError> {
+    let overrule_proposal: ProposalResponse = deps.querier.query_wasm_smart(
+        PrePropose::default().proposal_module.load(deps.storage)?,
+        &ProposalSingleQueryMsg::Proposal {
+            proposal_id: overrule_proposal_id,
+        },
+    )?;
+    Ok(overrule_proposal.proposal.status == Status::Open)
+}
+
 fn get_subdao_name(deps: &DepsMut, subdao: &Addr) -> Result<String, PreProposeOverruleError> {
     let subdao_config: SubdaoTypes::Config = deps
         .querier
@@ -260,6 +364,11 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             )?;
             to_json_binary(&overrule_proposal_id)
         }
+        QueryMsg::QueryExtension {
+            msg: QueryExt::OverruleParams { timelock_address },
+        } => to_json_binary(
+            &OVERRULE_PARAMS.may_load(deps.storage, deps.api.addr_validate(&timelock_address)?)?,
+        ),
         _ => PrePropose::default().query(deps, env, msg),
     }
 }
@@ -1,5 +1,6 @@
 use cosmwasm_std::StdError;
 use cwd_pre_propose_base::error::PreProposeError;
+use cwd_voting::threshold::ThresholdError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -10,6 +11,12 @@ pub enum PreProposeOverruleError {
     #[error(transparent)]
     PreProposeBase(PreProposeError),
 
+    #[error(transparent)]
+    Threshold(#[from] ThresholdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
     #[error("Base pre propose messages aren't supported")]
     MessageUnsupported {},
 
@@ -24,4 +31,10 @@ pub enum PreProposeOverruleError {
 
     #[error("Overrule proposal for this subdao proposal already created with id ({id})")]
     AlreadyExists { id: u64 },
+
+    #[error("Batch overrule proposal must contain at least one subdao proposal")]
+    EmptyBatch {},
+
+    #[error("Subdao proposal {id} is listed in the batch more than once")]
+    DuplicateProposal { id: u64 },
 }
@@ -1,4 +1,7 @@
 use cosmwasm_std::Addr;
 use cw_storage_plus::Map;
+use neutron_dao_pre_propose_overrule::msg::OverruleParams;
 
 pub const PROPOSALS: Map<(u64, Addr), u64> = Map::new("overrule_proposals");
+/// Overrule proposal params set per timelock contract.
+pub const OVERRULE_PARAMS: Map<Addr, OverruleParams> = Map::new("overrule_params");
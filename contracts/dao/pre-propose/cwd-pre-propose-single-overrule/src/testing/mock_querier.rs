@@ -1,5 +1,7 @@
+use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
+use std::rc::Rc;
 
 use cosmwasm_std::{
     from_json,
@@ -9,6 +11,12 @@ use cosmwasm_std::{
 };
 use cwd_core::msg::QueryMsg as MainDaoQueryMsg;
 use cwd_proposal_single::msg::QueryMsg as ProposalSingleQueryMsg;
+use cwd_proposal_single::{
+    proposal::SingleChoiceProposal as MainDaoSingleChoiceProposal, query::ProposalResponse,
+};
+use cwd_voting::status::Status;
+use cwd_voting::threshold::Threshold;
+use cwd_voting::voting::Votes;
 
 use neutron_subdao_core::{msg::QueryMsg as SubdaoQueryMsg, types as SubdaoTypes};
 use neutron_subdao_timelock_single::types::{ProposalStatus, SingleChoiceProposal};
@@ -23,6 +31,7 @@ pub const MOCK_IMPOSTOR_TIMELOCK_CONTRACT: &str = "neutron1timelock_contract_imp
 
 pub const SUBDAO_NAME: &str = "Based DAO";
 pub const TIMELOCKED_PROPOSAL_ID: u64 = 42;
+pub const SECOND_TIMELOCKED_PROPOSAL_ID: u64 = 43;
 pub const NON_TIMELOCKED_PROPOSAL_ID: u64 = 24;
 pub const PROPOSALS_COUNT: u64 = 61;
 
@@ -122,6 +131,9 @@ impl ContractQuerier for MockTimelockQueries {
                     owner: Addr::unchecked(self.owner.clone()),
                     overrule_pre_propose: Addr::unchecked(""),
                     subdao: Addr::unchecked(self.subdao.clone()),
+                    min_timelock_duration: None,
+                    execution_bounty: None,
+                    execution_deadline: None,
                 }),
             )),
             TimelockMsg::QueryMsg::Proposal { proposal_id } => SystemResult::Ok(
@@ -129,9 +141,14 @@ impl ContractQuerier for MockTimelockQueries {
                     id: proposal_id,
                     msgs: vec![],
                     status: match proposal_id {
-                        TIMELOCKED_PROPOSAL_ID => ProposalStatus::Timelocked,
+                        TIMELOCKED_PROPOSAL_ID | SECOND_TIMELOCKED_PROPOSAL_ID => {
+                            ProposalStatus::Timelocked
+                        }
                         _ => ProposalStatus::Executed,
                     },
+                    timelocked_until: None,
+                    msg_statuses: vec![],
+                    execution_deadline: None,
                 })),
             ),
             _ => SystemResult::Err(SystemError::Unknown {}),
@@ -141,6 +158,9 @@ impl ContractQuerier for MockTimelockQueries {
 
 pub struct MockDaoProposalQueries {
     dao_core: String,
+    /// Statuses of the overrule proposals created after the first
+    /// `PROPOSALS_COUNT` ones, which are all closed.
+    overrule_statuses: Rc<RefCell<Vec<Status>>>,
 }
 
 impl ContractQuerier for MockDaoProposalQueries {
@@ -151,7 +171,38 @@ impl ContractQuerier for MockDaoProposalQueries {
                 SystemResult::Ok(ContractResult::from(to_json_binary(&self.dao_core)))
             }
             ProposalSingleQueryMsg::ProposalCount {} => {
-                SystemResult::Ok(ContractResult::from(to_json_binary(&PROPOSALS_COUNT)))
+                let count = PROPOSALS_COUNT + self.overrule_statuses.borrow().len() as u64;
+                SystemResult::Ok(ContractResult::from(to_json_binary(&count)))
+            }
+            ProposalSingleQueryMsg::Proposal { proposal_id } => {
+                let status = match proposal_id.checked_sub(PROPOSALS_COUNT + 1) {
+                    Some(index) => match self.overrule_statuses.borrow().get(index as usize) {
+                        Some(status) => *status,
+                        None => return SystemResult::Err(SystemError::Unknown {}),
+                    },
+                    None => Status::Closed,
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&ProposalResponse {
+                    id: proposal_id,
+                    proposal: MainDaoSingleChoiceProposal {
+                        title: "".to_string(),
+                        description: "".to_string(),
+                        proposer: Addr::unchecked(""),
+                        start_height: 0,
+                        min_voting_period: None,
+                        expiration: Default::default(),
+                        threshold: Threshold::AbsoluteCount {
+                            threshold: Default::default(),
+                        },
+                        total_power: Default::default(),
+                        msgs: vec![],
+                        status,
+                        votes: Votes::zero(),
+                        allow_revoting: false,
+                        veto_threshold: None,
+                        voting_start: None,
+                    },
+                })))
             }
             _ => SystemResult::Err(SystemError::Unknown {}),
         }
@@ -185,11 +236,20 @@ impl ContractQuerier for MockSubdaoCoreQueries {
 }
 
 pub fn get_properly_initialized_dao() -> HashMap<String, Box<dyn ContractQuerier>> {
+    get_dao_with_overrule_proposals(Rc::new(RefCell::new(vec![])))
+}
+
+/// Returns a DAO whose proposal module knows the overrule proposals with
+/// the given statuses, following the first `PROPOSALS_COUNT` proposals.
+pub fn get_dao_with_overrule_proposals(
+    overrule_statuses: Rc<RefCell<Vec<Status>>>,
+) -> HashMap<String, Box<dyn ContractQuerier>> {
     let mut contracts: HashMap<String, Box<dyn ContractQuerier>> = HashMap::new();
     contracts.insert(
         MOCK_DAO_PROPOSE_MODULE.to_string(),
         Box::new(MockDaoProposalQueries {
             dao_core: MOCK_DAO_CORE.parse().unwrap(),
+            overrule_statuses,
         }),
     );
     contracts.insert(
@@ -1,26 +1,32 @@
 use cosmwasm_std::{
     from_json,
     testing::{mock_env, mock_info},
-    to_json_binary, Addr, CosmosMsg, DepsMut, Empty, SubMsg, WasmMsg,
+    to_json_binary, Addr, CosmosMsg, Deps, DepsMut, Empty, SubMsg, Uint128, WasmMsg,
 };
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::rc::Rc;
 
 use crate::{
     contract::{execute, instantiate, query},
     testing::mock_querier::{mock_dependencies, MOCK_DAO_CORE, MOCK_TIMELOCK_CONTRACT},
 };
 use neutron_dao_pre_propose_overrule::msg::{
-    ExecuteMsg, InstantiateMsg, ProposeMessage, QueryExt, QueryMsg,
+    ExecuteMsg, InstantiateMsg, OverruleParams, ProposeMessage, QueryExt, QueryMsg,
 };
 
 use crate::error::PreProposeOverruleError;
 use crate::testing::mock_querier::{
-    get_dao_with_impostor_subdao, get_dao_with_impostor_timelock, get_properly_initialized_dao,
-    ContractQuerier, MOCK_DAO_PROPOSE_MODULE, MOCK_IMPOSTOR_TIMELOCK_CONTRACT, MOCK_SUBDAO_CORE,
-    NON_TIMELOCKED_PROPOSAL_ID, PROPOSALS_COUNT, SUBDAO_NAME, TIMELOCKED_PROPOSAL_ID,
+    get_dao_with_impostor_subdao, get_dao_with_impostor_timelock, get_dao_with_overrule_proposals,
+    get_properly_initialized_dao, ContractQuerier, MOCK_DAO_PROPOSE_MODULE,
+    MOCK_IMPOSTOR_TIMELOCK_CONTRACT, MOCK_SUBDAO_CORE, NON_TIMELOCKED_PROPOSAL_ID, PROPOSALS_COUNT,
+    SECOND_TIMELOCKED_PROPOSAL_ID, SUBDAO_NAME, TIMELOCKED_PROPOSAL_ID,
 };
+use cw_utils::Duration;
 use cwd_pre_propose_base::state::Config;
 use cwd_proposal_single::msg::ExecuteMsg as ProposeMessageInternal;
+use cwd_voting::status::Status;
+use cwd_voting::threshold::{PercentageThreshold, Threshold};
 use neutron_subdao_timelock_single::msg as TimelockMsg;
 
 pub fn init_base_contract(deps: DepsMut<Empty>) {
@@ -146,6 +152,133 @@ fn test_proposal_id_query() {
     assert_eq!(expected_id, queried_id);
 }
 
+#[test]
+fn test_overrule_params() {
+    let contracts: HashMap<String, Box<dyn ContractQuerier>> = get_properly_initialized_dao();
+    let mut deps = mock_dependencies(contracts);
+    init_base_contract(deps.as_mut());
+    const PROPOSAL_ID: u64 = TIMELOCKED_PROPOSAL_ID;
+    const PROPOSER_ADDR: &str = "whatever";
+    let params = OverruleParams {
+        threshold: Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Majority {},
+        },
+        max_voting_period: Duration::Time(3600),
+    };
+    let query_params = |deps: Deps| -> Option<OverruleParams> {
+        let res = query(
+            deps,
+            mock_env(),
+            QueryMsg::QueryExtension {
+                msg: QueryExt::OverruleParams {
+                    timelock_address: MOCK_TIMELOCK_CONTRACT.to_string(),
+                },
+            },
+        )
+        .unwrap();
+        from_json(res).unwrap()
+    };
+    assert_eq!(query_params(deps.as_ref()), None);
+
+    // only the DAO can set overrule params
+    let msg = ExecuteMsg::UpdateOverruleParams {
+        timelock_contract: MOCK_TIMELOCK_CONTRACT.to_string(),
+        params: Some(params.clone()),
+    };
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER_ADDR, &[]),
+        msg.clone(),
+    );
+    assert_eq!(res, Err(PreProposeOverruleError::Unauthorized {}));
+
+    // invalid threshold is rejected
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MOCK_DAO_CORE, &[]),
+        ExecuteMsg::UpdateOverruleParams {
+            timelock_contract: MOCK_TIMELOCK_CONTRACT.to_string(),
+            params: Some(OverruleParams {
+                threshold: Threshold::AbsoluteCount {
+                    threshold: Uint128::zero(),
+                },
+                max_voting_period: Duration::Time(3600),
+            }),
+        },
+    );
+    assert!(matches!(res, Err(PreProposeOverruleError::Threshold(_))));
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MOCK_DAO_CORE, &[]),
+        msg,
+    );
+    assert!(res.is_ok());
+    assert_eq!(query_params(deps.as_ref()), Some(params.clone()));
+
+    // overrule proposal is created with the params set for the timelock
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER_ADDR, &[]),
+        ExecuteMsg::Propose {
+            msg: ProposeMessage::ProposeOverrule {
+                timelock_contract: MOCK_TIMELOCK_CONTRACT.to_string(),
+                proposal_id: PROPOSAL_ID,
+            },
+        },
+    )
+    .unwrap();
+    let prop_name: String = format!(
+        "Reject the proposal #{} of the '{}' subdao",
+        PROPOSAL_ID, SUBDAO_NAME
+    );
+    let prop_desc: String = format!(
+        "If this proposal will be accepted, the DAO is going to \
+overrule the proposal #{} of '{}' subdao (address {})",
+        PROPOSAL_ID, SUBDAO_NAME, MOCK_SUBDAO_CORE
+    );
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: MOCK_DAO_PROPOSE_MODULE.to_string(),
+            msg: to_json_binary(&ProposeMessageInternal::ProposeWithParams {
+                title: prop_name,
+                description: prop_desc,
+                msgs: vec![CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: MOCK_TIMELOCK_CONTRACT.to_string(),
+                    msg: to_json_binary(&TimelockMsg::ExecuteMsg::OverruleProposal {
+                        proposal_id: PROPOSAL_ID
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                })],
+                proposer: Some(PROPOSER_ADDR.to_string()),
+                threshold: params.threshold,
+                max_voting_period: params.max_voting_period,
+            })
+            .unwrap(),
+            funds: vec![],
+        }))]
+    );
+
+    // params can be removed
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MOCK_DAO_CORE, &[]),
+        ExecuteMsg::UpdateOverruleParams {
+            timelock_contract: MOCK_TIMELOCK_CONTRACT.to_string(),
+            params: None,
+        },
+    );
+    assert!(res.is_ok());
+    assert_eq!(query_params(deps.as_ref()), None);
+}
+
 #[test]
 fn test_base_prepropose_methods() {
     let contracts: HashMap<String, Box<dyn ContractQuerier>> = get_properly_initialized_dao();
@@ -272,3 +405,245 @@ fn test_double_creation() {
         })
     );
 }
+
+#[test]
+fn test_batch_overrule() {
+    let contracts: HashMap<String, Box<dyn ContractQuerier>> = get_properly_initialized_dao();
+    let mut deps = mock_dependencies(contracts);
+    init_base_contract(deps.as_mut());
+    const PROPOSER_ADDR: &str = "whatever";
+    let propose_batch = |proposal_ids: Vec<u64>| ExecuteMsg::Propose {
+        msg: ProposeMessage::ProposeBatchOverrule {
+            timelock_contract: MOCK_TIMELOCK_CONTRACT.to_string(),
+            proposal_ids,
+        },
+    };
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER_ADDR, &[]),
+        propose_batch(vec![]),
+    );
+    assert_eq!(res, Err(PreProposeOverruleError::EmptyBatch {}));
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER_ADDR, &[]),
+        propose_batch(vec![TIMELOCKED_PROPOSAL_ID, TIMELOCKED_PROPOSAL_ID]),
+    );
+    assert_eq!(
+        res,
+        Err(PreProposeOverruleError::DuplicateProposal {
+            id: TIMELOCKED_PROPOSAL_ID
+        })
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER_ADDR, &[]),
+        propose_batch(vec![TIMELOCKED_PROPOSAL_ID, NON_TIMELOCKED_PROPOSAL_ID]),
+    );
+    assert_eq!(res, Err(PreProposeOverruleError::ProposalWrongState {}));
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER_ADDR, &[]),
+        propose_batch(vec![TIMELOCKED_PROPOSAL_ID, SECOND_TIMELOCKED_PROPOSAL_ID]),
+    )
+    .unwrap();
+    let prop_name: String = format!(
+        "Reject the proposals #{}, #{} of the '{}' subdao",
+        TIMELOCKED_PROPOSAL_ID, SECOND_TIMELOCKED_PROPOSAL_ID, SUBDAO_NAME
+    );
+    let prop_desc: String = format!(
+        "If this proposal will be accepted, the DAO is going to \
+overrule the proposals #{}, #{} of '{}' subdao (address {})",
+        TIMELOCKED_PROPOSAL_ID, SECOND_TIMELOCKED_PROPOSAL_ID, SUBDAO_NAME, MOCK_SUBDAO_CORE
+    );
+    let overrule_msg = |proposal_id: u64| {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: MOCK_TIMELOCK_CONTRACT.to_string(),
+            msg: to_json_binary(&TimelockMsg::ExecuteMsg::OverruleProposal { proposal_id })
+                .unwrap(),
+            funds: vec![],
+        })
+    };
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: MOCK_DAO_PROPOSE_MODULE.to_string(),
+            msg: to_json_binary(&ProposeMessageInternal::Propose {
+                title: prop_name,
+                description: prop_desc,
+                msgs: vec![
+                    overrule_msg(TIMELOCKED_PROPOSAL_ID),
+                    overrule_msg(SECOND_TIMELOCKED_PROPOSAL_ID),
+                ],
+                proposer: Some(PROPOSER_ADDR.to_string()),
+            })
+            .unwrap(),
+            funds: vec![],
+        }))]
+    );
+
+    // every member of the batch points to the same overrule proposal
+    for subdao_proposal_id in [TIMELOCKED_PROPOSAL_ID, SECOND_TIMELOCKED_PROPOSAL_ID] {
+        let res_id = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::QueryExtension {
+                msg: QueryExt::OverruleProposalId {
+                    subdao_proposal_id,
+                    timelock_address: MOCK_TIMELOCK_CONTRACT.to_string(),
+                },
+            },
+        )
+        .unwrap();
+        let queried_id: u64 = from_json(res_id).unwrap();
+        assert_eq!(PROPOSALS_COUNT + 1, queried_id);
+    }
+
+    // a batch member can't be overruled again
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER_ADDR, &[]),
+        ExecuteMsg::Propose {
+            msg: ProposeMessage::ProposeOverrule {
+                timelock_contract: MOCK_TIMELOCK_CONTRACT.to_string(),
+                proposal_id: SECOND_TIMELOCKED_PROPOSAL_ID,
+            },
+        },
+    );
+    assert_eq!(
+        res,
+        Err(PreProposeOverruleError::AlreadyExists {
+            id: PROPOSALS_COUNT + 1
+        })
+    );
+}
+
+#[test]
+fn test_batch_overrule_of_timelocked_proposals() {
+    let overrule_statuses = Rc::new(RefCell::new(vec![]));
+    let contracts: HashMap<String, Box<dyn ContractQuerier>> =
+        get_dao_with_overrule_proposals(Rc::clone(&overrule_statuses));
+    let mut deps = mock_dependencies(contracts);
+    init_base_contract(deps.as_mut());
+    const PROPOSER_ADDR: &str = "whatever";
+    let overrule_proposal_id = |deps: Deps, subdao_proposal_id: u64| -> u64 {
+        let res = query(
+            deps,
+            mock_env(),
+            QueryMsg::QueryExtension {
+                msg: QueryExt::OverruleProposalId {
+                    subdao_proposal_id,
+                    timelock_address: MOCK_TIMELOCK_CONTRACT.to_string(),
+                },
+            },
+        )
+        .unwrap();
+        from_json(res).unwrap()
+    };
+    let propose_batch = |proposal_ids: Vec<u64>| ExecuteMsg::Propose {
+        msg: ProposeMessage::ProposeBatchOverrule {
+            timelock_contract: MOCK_TIMELOCK_CONTRACT.to_string(),
+            proposal_ids,
+        },
+    };
+
+    // the timelock contract creates an overrule proposal for each proposal it timelocks
+    for proposal_id in [TIMELOCKED_PROPOSAL_ID, SECOND_TIMELOCKED_PROPOSAL_ID] {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MOCK_TIMELOCK_CONTRACT, &[]),
+            ExecuteMsg::Propose {
+                msg: ProposeMessage::ProposeOverrule {
+                    timelock_contract: MOCK_TIMELOCK_CONTRACT.to_string(),
+                    proposal_id,
+                },
+            },
+        )
+        .unwrap();
+        overrule_statuses.borrow_mut().push(Status::Open);
+    }
+    assert_eq!(
+        PROPOSALS_COUNT + 1,
+        overrule_proposal_id(deps.as_ref(), TIMELOCKED_PROPOSAL_ID)
+    );
+    assert_eq!(
+        PROPOSALS_COUNT + 2,
+        overrule_proposal_id(deps.as_ref(), SECOND_TIMELOCKED_PROPOSAL_ID)
+    );
+
+    // a proposal whose overrule proposal is over can't be included
+    overrule_statuses.borrow_mut()[1] = Status::Rejected;
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER_ADDR, &[]),
+        propose_batch(vec![TIMELOCKED_PROPOSAL_ID, SECOND_TIMELOCKED_PROPOSAL_ID]),
+    );
+    assert_eq!(
+        res,
+        Err(PreProposeOverruleError::AlreadyExists {
+            id: PROPOSALS_COUNT + 2
+        })
+    );
+
+    // the batch takes over the open overrule proposals
+    overrule_statuses.borrow_mut()[1] = Status::Open;
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER_ADDR, &[]),
+        propose_batch(vec![TIMELOCKED_PROPOSAL_ID, SECOND_TIMELOCKED_PROPOSAL_ID]),
+    )
+    .unwrap();
+    overrule_statuses.borrow_mut().push(Status::Open);
+    for subdao_proposal_id in [TIMELOCKED_PROPOSAL_ID, SECOND_TIMELOCKED_PROPOSAL_ID] {
+        assert_eq!(
+            PROPOSALS_COUNT + 3,
+            overrule_proposal_id(deps.as_ref(), subdao_proposal_id)
+        );
+    }
+
+    // single overrule proposals are never taken over
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MOCK_TIMELOCK_CONTRACT, &[]),
+        ExecuteMsg::Propose {
+            msg: ProposeMessage::ProposeOverrule {
+                timelock_contract: MOCK_TIMELOCK_CONTRACT.to_string(),
+                proposal_id: TIMELOCKED_PROPOSAL_ID,
+            },
+        },
+    );
+    assert_eq!(
+        res,
+        Err(PreProposeOverruleError::AlreadyExists {
+            id: PROPOSALS_COUNT + 3
+        })
+    );
+
+    // once the batch is declined, its members can't be batched again
+    overrule_statuses.borrow_mut()[2] = Status::Rejected;
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER_ADDR, &[]),
+        propose_batch(vec![TIMELOCKED_PROPOSAL_ID]),
+    );
+    assert_eq!(
+        res,
+        Err(PreProposeOverruleError::AlreadyExists {
+            id: PROPOSALS_COUNT + 3
+        })
+    );
+}
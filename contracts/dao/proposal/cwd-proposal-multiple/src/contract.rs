@@ -14,6 +14,10 @@ use cwd_pre_propose_multiple::contract::ExecuteMsg as PreProposeMsg;
 use cwd_proposal_hooks::{new_proposal_hooks, proposal_status_changed_hooks};
 use cwd_vote_hooks::new_vote_hooks;
 use cwd_voting::{
+    delegation::{
+        delegate_at_height, delegators_at_height, get_delegated_power, DelegationResponse,
+        DelegatorsResponse,
+    },
     multiple_choice::{
         CheckedMultipleChoiceOption, MultipleChoiceOptions, MultipleChoiceVote,
         MultipleChoiceVotes, VotingStrategy,
@@ -141,6 +145,8 @@ pub fn execute(
         ExecuteMsg::RemoveVoteHook { address } => {
             execute_remove_vote_hook(deps, env, info, address)
         }
+        ExecuteMsg::Delegate { delegate } => execute_delegate(deps, env, info, delegate),
+        ExecuteMsg::Undelegate {} => execute_undelegate(deps, env, info),
     }
 }
 
@@ -298,16 +304,51 @@ pub fn execute_vote(
         return Err(ContractError::NotOpen { id: proposal_id });
     }
 
-    let vote_power = get_voting_power(
+    let own_power = get_voting_power(
         deps.as_ref(),
         info.sender.clone(),
-        config.dao,
+        config.dao.clone(),
         Some(prop.start_height),
     )?;
+    // Delegators who voted directly are not represented by the
+    // sender.
+    let delegated_power = {
+        let deps = deps.as_ref();
+        get_delegated_power(
+            deps,
+            config.dao,
+            &info.sender,
+            prop.start_height,
+            |delegator| BALLOTS.has(deps.storage, (proposal_id, delegator.clone())),
+        )?
+    };
+    let vote_power = own_power + delegated_power;
     if vote_power.is_zero() {
         return Err(ContractError::NotRegistered {});
     }
 
+    // A delegator voting for the first time overrides its delegate,
+    // so its power is removed from the delegate's ballot.
+    let mut overridden_delegate = None;
+    if !BALLOTS.has(deps.storage, (proposal_id, info.sender.clone())) {
+        if let Some(delegate) =
+            delegate_at_height(deps.storage, &info.sender, Some(prop.start_height))?
+        {
+            if let Some(mut delegate_ballot) =
+                BALLOTS.may_load(deps.storage, (proposal_id, delegate.clone()))?
+            {
+                prop.votes.remove_vote(delegate_ballot.vote, own_power)?;
+                delegate_ballot.power -= own_power;
+                BALLOTS.save(
+                    deps.storage,
+                    (proposal_id, delegate.clone()),
+                    &delegate_ballot,
+                )?;
+                overridden_delegate = Some(delegate);
+            }
+        }
+    }
+
     BALLOTS.update(
         deps.storage,
         (proposal_id, info.sender.clone()),
@@ -359,14 +400,55 @@ pub fn execute_vote(
         info.sender.to_string(),
         vote.to_string(),
     )?;
-    Ok(Response::default()
+    let mut response = Response::default()
         .add_submessages(change_hooks)
         .add_submessages(vote_hooks)
         .add_attribute("action", "vote")
         .add_attribute("sender", info.sender)
         .add_attribute("proposal_id", proposal_id.to_string())
         .add_attribute("position", vote.to_string())
-        .add_attribute("status", prop.status.to_string()))
+        .add_attribute("power", vote_power.to_string())
+        .add_attribute("status", prop.status.to_string());
+    if let Some(delegate) = overridden_delegate {
+        response = response.add_attribute("overridden_delegate", delegate);
+    }
+    Ok(response)
+}
+
+pub fn execute_delegate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    let delegate = deps.api.addr_validate(&delegate)?;
+    let config = CONFIG.load(deps.storage)?;
+    cwd_voting::delegation::check_delegator_power(
+        deps.as_ref(),
+        &info.sender,
+        config.dao,
+        env.block.height,
+    )?;
+    cwd_voting::delegation::delegate(deps.storage, env.block.height, &info.sender, &delegate)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "delegate")
+        .add_attribute("delegator", info.sender)
+        .add_attribute("delegate", delegate))
+}
+
+pub fn execute_undelegate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let delegate =
+        cwd_voting::delegation::undelegate(deps.storage, env.block.height, &info.sender)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "undelegate")
+        .add_attribute("delegator", info.sender)
+        .add_attribute("delegate", delegate))
 }
 
 pub fn execute_execute(
@@ -735,6 +817,8 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ProposalExecutionError { proposal_id } => {
             query_proposal_execution_error(deps, proposal_id)
         }
+        QueryMsg::Delegation { delegator, height } => query_delegation(deps, delegator, height),
+        QueryMsg::Delegators { delegate, height } => query_delegators(deps, delegate, height),
     }
 }
 
@@ -841,6 +925,18 @@ pub fn query_list_votes(
     to_json_binary(&VoteListResponse { votes })
 }
 
+pub fn query_delegation(deps: Deps, delegator: String, height: Option<u64>) -> StdResult<Binary> {
+    let delegator = deps.api.addr_validate(&delegator)?;
+    let delegate = delegate_at_height(deps.storage, &delegator, height)?;
+    to_json_binary(&DelegationResponse { delegate })
+}
+
+pub fn query_delegators(deps: Deps, delegate: String, height: Option<u64>) -> StdResult<Binary> {
+    let delegate = deps.api.addr_validate(&delegate)?;
+    let delegators = delegators_at_height(deps.storage, &delegate, height)?;
+    to_json_binary(&DelegatorsResponse { delegators })
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_json_binary(&cwd_interface::voting::InfoResponse { info })
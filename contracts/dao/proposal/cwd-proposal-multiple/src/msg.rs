@@ -133,6 +133,14 @@ pub enum ExecuteMsg {
     RemoveVoteHook {
         address: String,
     },
+    /// Delegates the sender's vote to DELEGATE starting from the
+    /// next block. The delegate votes with the summed voting power
+    /// of its delegators unless they vote directly.
+    Delegate {
+        delegate: String,
+    },
+    /// Removes the sender's delegation starting from the next block.
+    Undelegate {},
 }
 
 #[proposal_module_query]
@@ -191,6 +199,20 @@ pub enum QueryMsg {
     /// Returns `Option<String>`
     #[returns(Option<String>)]
     ProposalExecutionError { proposal_id: u64 },
+    /// Gets the delegate of DELEGATOR at HEIGHT or at the current
+    /// height if none is specified.
+    #[returns(cwd_voting::delegation::DelegationResponse)]
+    Delegation {
+        delegator: String,
+        height: Option<u64>,
+    },
+    /// Lists the delegators of DELEGATE at HEIGHT or at the current
+    /// height if none is specified.
+    #[returns(cwd_voting::delegation::DelegatorsResponse)]
+    Delegators {
+        delegate: String,
+        height: Option<u64>,
+    },
 }
 
 #[cw_serde]
@@ -14,6 +14,7 @@ use cwd_core::state::ProposalModule;
 use cwd_hooks::HooksResponse;
 use cwd_interface::{Admin, ModuleInstantiateInfo};
 use cwd_voting::{
+    delegation::DelegationResponse,
     deposit::{CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
     multiple_choice::{
         CheckedMultipleChoiceOption, MultipleChoiceOption, MultipleChoiceOptionType,
@@ -2336,3 +2337,123 @@ fn test_migrate_mock() {
 
     assert_eq!(migrated_proposal, expected);
 }
+
+#[test]
+fn test_vote_delegation() {
+    let mut app = custom_app::<NeutronMsg, Empty, _>(no_init);
+    let core_addr = instantiate_with_native_bonded_balances_governance(
+        &mut app,
+        InstantiateMsg {
+            min_voting_period: None,
+            max_voting_period: Duration::Height(6),
+            only_members_execute: false,
+            allow_revoting: false,
+            voting_strategy: VotingStrategy::SingleChoice {
+                quorum: PercentageThreshold::Percent(Decimal::percent(90)),
+            },
+            close_proposal_on_execution_failure: false,
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        },
+        Some(vec![
+            Cw20Coin {
+                address: "delegate".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "one".to_string(),
+                amount: Uint128::new(3),
+            },
+            Cw20Coin {
+                address: "two".to_string(),
+                amount: Uint128::new(3),
+            },
+            Cw20Coin {
+                address: "three".to_string(),
+                amount: Uint128::new(3),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    for delegator in ["one", "two"] {
+        app.execute_contract(
+            Addr::unchecked(delegator),
+            govmod.clone(),
+            &ExecuteMsg::Delegate {
+                delegate: "delegate".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    let delegation: DelegationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            govmod.clone(),
+            &QueryMsg::Delegation {
+                delegator: "one".to_string(),
+                height: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(delegation.delegate, Some(Addr::unchecked("delegate")));
+
+    // Delegations take effect from the next block.
+    app.update_block(next_block);
+    let options = vec![
+        MultipleChoiceOption {
+            title: "title".to_string(),
+            description: "multiple choice option 1".to_string(),
+            msgs: None,
+        },
+        MultipleChoiceOption {
+            title: "title".to_string(),
+            description: "multiple choice option 2".to_string(),
+            msgs: None,
+        },
+    ];
+    let proposal_id = make_proposal(
+        &mut app,
+        &govmod,
+        "three",
+        MultipleChoiceOptions { options },
+    );
+
+    app.execute_contract(
+        Addr::unchecked("delegate"),
+        govmod.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id,
+            vote: MultipleChoiceVote { option_id: 0 },
+        },
+        &[],
+    )
+    .unwrap();
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![Uint128::new(7), Uint128::zero(), Uint128::zero()]
+    );
+
+    // A delegator overrides its delegate by voting directly.
+    let res = app
+        .execute_contract(
+            Addr::unchecked("two"),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: MultipleChoiceVote { option_id: 1 },
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(res.events.iter().any(|e| e
+        .attributes
+        .contains(&Attribute::new("overridden_delegate", "delegate"))));
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Open);
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![Uint128::new(4), Uint128::new(3), Uint128::zero()]
+    );
+}
@@ -1,8 +1,8 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply, Response,
-    StdError, StdResult, Storage, SubMsg, WasmMsg,
+    to_json_binary, Addr, Binary, BlockInfo, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env,
+    MessageInfo, Reply, Response, StdError, StdResult, Storage, SubMsg, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw_storage_plus::Bound;
@@ -11,26 +11,39 @@ use cwd_hooks::Hooks;
 use cwd_pre_propose_single::contract::ExecuteMsg as PreProposeMsg;
 use cwd_proposal_hooks::{new_proposal_hooks, proposal_status_changed_hooks};
 use cwd_vote_hooks::new_vote_hooks;
+use cwd_voting::delegation::{
+    delegate_at_height, delegators_at_height, get_delegated_power, DelegationResponse,
+    DelegatorsResponse,
+};
 use cwd_voting::pre_propose::{PreProposeInfo, ProposalCreationPolicy};
 use cwd_voting::proposal::{DEFAULT_LIMIT, MAX_PROPOSAL_SIZE};
 use cwd_voting::reply::{
     failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
 };
 use cwd_voting::status::Status;
-use cwd_voting::threshold::Threshold;
-use cwd_voting::voting::{get_total_power, get_voting_power, validate_voting_period, Vote, Votes};
+use cwd_voting::threshold::{validate_percentage, PercentageThreshold, Threshold};
+use cwd_voting::voting::{
+    get_total_power, get_voting_power, validate_voting_period, validate_weighted_vote, Vote, Votes,
+    WeightedVoteOption,
+};
 use neutron_sdk::bindings::msg::NeutronMsg;
 
 use crate::msg::MigrateMsg;
 use crate::proposal::SingleChoiceProposal;
-use crate::state::{Config, CREATION_POLICY, PROPOSAL_EXECUTION_ERRORS};
+use crate::state::{
+    Config, ProposalRevision, Turnout, CREATION_POLICY, PROPOSAL_EXECUTION_ERRORS,
+    PROPOSAL_REVISIONS, TURNOUT, UNRECORDED_TURNOUTS,
+};
 
 use crate::{
     error::ContractError,
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::advance_proposal_id,
     query::ProposalListResponse,
-    query::{ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
+    query::{
+        ProposalResponse, RevisionInfo, RevisionListResponse, VoteInfo, VoteListResponse,
+        VoteResponse,
+    },
     state::{Ballot, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS},
 };
 
@@ -47,11 +60,15 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     msg.threshold.validate()?;
+    if let Some(veto_threshold) = msg.veto_threshold {
+        validate_percentage(&veto_threshold)?;
+    }
 
     let dao = info.sender;
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(msg.min_voting_period, msg.max_voting_period)?;
+    let amendment_period = validate_amendment_period(msg.amendment_period, max_voting_period)?;
 
     let (initial_policy, pre_propose_messages) = msg
         .pre_propose_info
@@ -64,6 +81,8 @@ pub fn instantiate(
         dao: dao.clone(),
         allow_revoting: msg.allow_revoting,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
+        veto_threshold: msg.veto_threshold,
+        amendment_period,
     };
 
     // Initialize proposal count to zero so that queries return zero
@@ -91,8 +110,44 @@ pub fn execute(
             description,
             msgs,
             proposer,
-        } => execute_propose(deps, env, info.sender, title, description, msgs, proposer),
+        } => execute_propose(
+            deps,
+            env,
+            info.sender,
+            title,
+            description,
+            msgs,
+            proposer,
+            None,
+        ),
+        ExecuteMsg::ProposeWithParams {
+            title,
+            description,
+            msgs,
+            proposer,
+            threshold,
+            max_voting_period,
+        } => execute_propose(
+            deps,
+            env,
+            info.sender,
+            title,
+            description,
+            msgs,
+            proposer,
+            Some((threshold, max_voting_period)),
+        ),
         ExecuteMsg::Vote { proposal_id, vote } => execute_vote(deps, env, info, proposal_id, vote),
+        ExecuteMsg::VoteWeighted {
+            proposal_id,
+            options,
+        } => execute_vote_weighted(deps, env, info, proposal_id, options),
+        ExecuteMsg::Amend {
+            proposal_id,
+            title,
+            description,
+            msgs,
+        } => execute_amend(deps, env, info, proposal_id, title, description, msgs),
         ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
         ExecuteMsg::UpdateConfig {
@@ -102,6 +157,8 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            veto_threshold,
+            amendment_period,
         } => execute_update_config(
             deps,
             info,
@@ -111,6 +168,8 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            veto_threshold,
+            amendment_period,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
@@ -125,9 +184,12 @@ pub fn execute(
         ExecuteMsg::RemoveVoteHook { address } => {
             execute_remove_vote_hook(deps, env, info, address)
         }
+        ExecuteMsg::Delegate { delegate } => execute_delegate(deps, env, info, delegate),
+        ExecuteMsg::Undelegate {} => execute_undelegate(deps, env, info),
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn execute_propose(
     deps: DepsMut,
     env: Env,
@@ -136,6 +198,7 @@ pub fn execute_propose(
     description: String,
     msgs: Vec<CosmosMsg<NeutronMsg>>,
     proposer: Option<String>,
+    params: Option<(Threshold, Duration)>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
@@ -158,7 +221,41 @@ pub fn execute_propose(
         _ => return Err(ContractError::InvalidProposer {}),
     };
 
-    let expiration = config.max_voting_period.after(&env.block);
+    // Only the pre-propose module may override the config params.
+    let (threshold, max_voting_period) = match params {
+        None => (config.threshold, config.max_voting_period),
+        Some((threshold, max_voting_period)) => {
+            if !matches!(
+                proposal_creation_policy,
+                ProposalCreationPolicy::Module { .. }
+            ) {
+                return Err(ContractError::Unauthorized {});
+            }
+            threshold.validate()?;
+            let (_, max_voting_period) =
+                validate_voting_period(config.min_voting_period, max_voting_period)?;
+            validate_amendment_period(config.amendment_period, max_voting_period)?;
+            (threshold, max_voting_period)
+        }
+    };
+    // Dynamic quorums follow the turnout of past proposals, including
+    // the ones that expired without being closed.
+    record_turnouts(deps.storage, &env.block)?;
+    let threshold = match TURNOUT.may_load(deps.storage)? {
+        Some(turnout) => threshold.with_turnout(turnout.average),
+        None => threshold,
+    };
+
+    // Proposals created through a pre-propose module may not be
+    // amended as the module would not see the amended contents, so
+    // they get no amendment period.
+    let amendment_period = match proposal_creation_policy {
+        ProposalCreationPolicy::Anyone {} => config.amendment_period,
+        ProposalCreationPolicy::Module { .. } => None,
+    };
+    // Voting periods count from the end of the amendment period.
+    let voting_start = voting_start_block(&env.block, amendment_period);
+    let expiration = max_voting_period.after(&voting_start);
 
     let total_power = get_total_power(deps.as_ref(), config.dao, Some(env.block.height))?;
 
@@ -169,14 +266,16 @@ pub fn execute_propose(
             description,
             proposer: proposer.clone(),
             start_height: env.block.height,
-            min_voting_period: config.min_voting_period.map(|min| min.after(&env.block)),
+            min_voting_period: config.min_voting_period.map(|min| min.after(&voting_start)),
             expiration,
-            threshold: config.threshold,
+            threshold,
             total_power,
             msgs,
             status: Status::Open,
             votes: Votes::zero(),
             allow_revoting: config.allow_revoting,
+            veto_threshold: config.veto_threshold,
+            voting_start: amendment_period.map(|period| period.after(&env.block)),
         };
         // Update the proposal's status. Addresses case where proposal
         // expires on the same block as it is created.
@@ -185,31 +284,22 @@ pub fn execute_propose(
     };
     let id = advance_proposal_id(deps.storage)?;
 
-    // TODO: discuss and probably adapt to Neutron reality.
-    //
-    // Limit the size of proposals.
-    //
-    // The Juno mainnet has a larger limit for data that can be
-    // uploaded as part of an execute message than it does for data
-    // that can be queried as part of a query. This means that without
-    // this check it is possible to create a proposal that can not be
-    // queried.
-    //
-    // The size selected was determined by uploading versions of this
-    // contract to the Juno mainnet until queries worked within a
-    // reasonable margin of error.
-    //
-    // `to_json_vec` is the method used by cosmwasm to convert a struct
-    // into it's byte representation in storage.
-    let proposal_size = cosmwasm_std::to_json_vec(&proposal)?.len() as u64;
-    if proposal_size > MAX_PROPOSAL_SIZE {
-        return Err(ContractError::ProposalTooLarge {
-            size: proposal_size,
-            max: MAX_PROPOSAL_SIZE,
-        });
-    }
+    check_proposal_size(&proposal)?;
 
     PROPOSALS.save(deps.storage, id, &proposal)?;
+    UNRECORDED_TURNOUTS.save(deps.storage, id, &Empty {})?;
+    if proposal.voting_start.is_some() {
+        PROPOSAL_REVISIONS.save(
+            deps.storage,
+            (id, 0),
+            &ProposalRevision {
+                title: proposal.title.clone(),
+                description: proposal.description.clone(),
+                msgs: proposal.msgs.clone(),
+                height: env.block.height,
+            },
+        )?;
+    }
 
     let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
 
@@ -244,6 +334,147 @@ pub fn execute_propose(
         .add_attribute("status", proposal.status.to_string()))
 }
 
+/// Validates that the amendment period has the same units as the max
+/// voting period so that voting periods can count from its end.
+fn validate_amendment_period(
+    amendment_period: Option<Duration>,
+    max_voting_period: Duration,
+) -> Result<Option<Duration>, ContractError> {
+    match (amendment_period, max_voting_period) {
+        (None, _)
+        | (Some(Duration::Height(_)), Duration::Height(_))
+        | (Some(Duration::Time(_)), Duration::Time(_)) => Ok(amendment_period),
+        _ => Err(ContractError::AmendmentPeriodUnitsConflict {}),
+    }
+}
+
+/// Returns BLOCK advanced by the amendment period, if any.
+fn voting_start_block(block: &BlockInfo, amendment_period: Option<Duration>) -> BlockInfo {
+    let mut start = block.clone();
+    match amendment_period {
+        Some(Duration::Height(blocks)) => start.height += blocks,
+        Some(Duration::Time(seconds)) => start.time = start.time.plus_seconds(seconds),
+        None => (),
+    }
+    start
+}
+
+/// Adds the turnout of the proposals that stopped accepting votes
+/// since they were created to the moving average of turnout. Returns
+/// the updated turnout along with the IDs of these proposals.
+fn final_turnout(storage: &dyn Storage, block: &BlockInfo) -> StdResult<(Turnout, Vec<u64>)> {
+    let mut turnout = TURNOUT.may_load(storage)?.unwrap_or_default();
+    let mut recorded = vec![];
+    for id in UNRECORDED_TURNOUTS.keys(storage, None, None, cosmwasm_std::Order::Ascending) {
+        let id = id?;
+        let prop = PROPOSALS.load(storage, id)?;
+        // Proposals that expired are only marked as such once they
+        // are touched again.
+        if prop.current_status(block) == Status::Open {
+            continue;
+        }
+        if !prop.total_power.is_zero() {
+            turnout.record(Decimal::from_ratio(prop.votes.total(), prop.total_power));
+        }
+        recorded.push(id);
+    }
+    Ok((turnout, recorded))
+}
+
+/// Saves the turnout returned by `final_turnout`.
+fn record_turnouts(storage: &mut dyn Storage, block: &BlockInfo) -> StdResult<()> {
+    let (turnout, recorded) = final_turnout(storage, block)?;
+    if recorded.is_empty() {
+        return Ok(());
+    }
+    for id in recorded {
+        UNRECORDED_TURNOUTS.remove(storage, id);
+    }
+    TURNOUT.save(storage, &turnout)
+}
+
+fn check_proposal_size(proposal: &SingleChoiceProposal) -> Result<(), ContractError> {
+    // TODO: discuss and probably adapt to Neutron reality.
+    //
+    // Limit the size of proposals.
+    //
+    // The Juno mainnet has a larger limit for data that can be
+    // uploaded as part of an execute message than it does for data
+    // that can be queried as part of a query. This means that without
+    // this check it is possible to create a proposal that can not be
+    // queried.
+    //
+    // The size selected was determined by uploading versions of this
+    // contract to the Juno mainnet until queries worked within a
+    // reasonable margin of error.
+    //
+    // `to_json_vec` is the method used by cosmwasm to convert a struct
+    // into it's byte representation in storage.
+    let proposal_size = cosmwasm_std::to_json_vec(proposal)?.len() as u64;
+    if proposal_size > MAX_PROPOSAL_SIZE {
+        return Err(ContractError::ProposalTooLarge {
+            size: proposal_size,
+            max: MAX_PROPOSAL_SIZE,
+        });
+    }
+    Ok(())
+}
+
+pub fn execute_amend(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    title: String,
+    description: String,
+    msgs: Vec<CosmosMsg<NeutronMsg>>,
+) -> Result<Response, ContractError> {
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+    if info.sender != prop.proposer {
+        return Err(ContractError::Unauthorized {});
+    }
+    // The pre-propose module checks proposal contents (and the
+    // overrule one sets them entirely), so amendments must not go
+    // around it.
+    if let ProposalCreationPolicy::Module { .. } = CREATION_POLICY.load(deps.storage)? {
+        return Err(ContractError::PreProposeAmendment {});
+    }
+    if prop.status != Status::Open || !prop.is_amendable(&env.block) {
+        return Err(ContractError::NotAmendable { id: proposal_id });
+    }
+
+    prop.title = title;
+    prop.description = description;
+    prop.msgs = msgs;
+    check_proposal_size(&prop)?;
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    let revision = PROPOSAL_REVISIONS
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, cosmwasm_std::Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(0, |last| last + 1);
+    PROPOSAL_REVISIONS.save(
+        deps.storage,
+        (proposal_id, revision),
+        &ProposalRevision {
+            title: prop.title,
+            description: prop.description,
+            msgs: prop.msgs,
+            height: env.block.height,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "amend")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("revision", revision.to_string()))
+}
+
 pub fn execute_execute(
     deps: DepsMut,
     env: Env,
@@ -268,6 +499,9 @@ pub fn execute_execute(
     prop.status = Status::Executed;
 
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    if old_status == Status::Open {
+        record_turnouts(deps.storage, &env.block)?;
+    }
 
     let response = {
         if !prop.msgs.is_empty() {
@@ -335,65 +569,146 @@ pub fn execute_vote(
     info: MessageInfo,
     proposal_id: u64,
     vote: Vote,
+) -> Result<Response, ContractError> {
+    cast_vote(deps, env, info, proposal_id, vote, None)
+}
+
+pub fn execute_vote_weighted(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    options: Vec<WeightedVoteOption>,
+) -> Result<Response, ContractError> {
+    validate_weighted_vote(&options)?;
+    // The ballot's position is the one with the largest weight, the
+    // first one wins on ties.
+    let vote = options
+        .iter()
+        .fold(
+            &options[0],
+            |max, o| if o.weight > max.weight { o } else { max },
+        )
+        .vote;
+    cast_vote(deps, env, info, proposal_id, vote, Some(options))
+}
+
+fn cast_vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    vote: Vote,
+    weighted_options: Option<Vec<WeightedVoteOption>>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let mut prop = PROPOSALS
         .may_load(deps.storage, proposal_id)?
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+    if prop.is_amendable(&env.block) {
+        return Err(ContractError::VotingNotStarted { id: proposal_id });
+    }
     if prop.current_status(&env.block) != Status::Open {
         return Err(ContractError::NotOpen { id: proposal_id });
     }
+    // Without a veto threshold such a vote would silently count as a no.
+    let vetoes = vote == Vote::NoWithVeto
+        || weighted_options.as_ref().map_or(false, |options| {
+            options.iter().any(|option| option.vote == Vote::NoWithVeto)
+        });
+    if vetoes && prop.veto_threshold.is_none() {
+        return Err(ContractError::VetoNotSupported {});
+    }
 
-    let vote_power = get_voting_power(
+    let own_power = get_voting_power(
         deps.as_ref(),
         info.sender.clone(),
-        config.dao,
+        config.dao.clone(),
         Some(prop.start_height),
     )?;
+    // Delegators who voted directly are not represented by the
+    // sender.
+    let delegated_power = {
+        let deps = deps.as_ref();
+        get_delegated_power(
+            deps,
+            config.dao,
+            &info.sender,
+            prop.start_height,
+            |delegator| BALLOTS.has(deps.storage, (proposal_id, delegator.clone())),
+        )?
+    };
+    let vote_power = own_power + delegated_power;
     if vote_power.is_zero() {
         return Err(ContractError::NotRegistered {});
     }
 
+    // A delegator voting for the first time overrides its delegate,
+    // so its power is removed from the delegate's ballot.
+    let mut overridden_delegate = None;
+    if !BALLOTS.has(deps.storage, (proposal_id, info.sender.clone())) {
+        if let Some(delegate) =
+            delegate_at_height(deps.storage, &info.sender, Some(prop.start_height))?
+        {
+            if let Some(mut delegate_ballot) =
+                BALLOTS.may_load(deps.storage, (proposal_id, delegate.clone()))?
+            {
+                delegate_ballot.remove_from(&mut prop.votes);
+                delegate_ballot.power -= own_power;
+                delegate_ballot.add_to(&mut prop.votes);
+                BALLOTS.save(
+                    deps.storage,
+                    (proposal_id, delegate.clone()),
+                    &delegate_ballot,
+                )?;
+                overridden_delegate = Some(delegate);
+            }
+        }
+    }
+
+    let ballot = Ballot {
+        power: vote_power,
+        vote,
+        weighted_options,
+    };
     BALLOTS.update(
         deps.storage,
         (proposal_id, info.sender.clone()),
         |bal| match bal {
             Some(current_ballot) => {
                 if prop.allow_revoting {
-                    if current_ballot.vote == vote {
+                    if current_ballot.vote == ballot.vote
+                        && current_ballot.weighted_options == ballot.weighted_options
+                    {
                         // Don't allow casting the same vote more than
                         // once. This seems liable to be confusing
                         // behavior.
                         Err(ContractError::AlreadyCast {})
                     } else {
                         // Remove the old vote if this is a re-vote.
-                        prop.votes
-                            .remove_vote(current_ballot.vote, current_ballot.power);
-                        Ok(Ballot {
-                            power: vote_power,
-                            vote,
-                        })
+                        current_ballot.remove_from(&mut prop.votes);
+                        Ok(ballot.clone())
                     }
                 } else {
                     Err(ContractError::AlreadyVoted {})
                 }
             }
-            None => Ok(Ballot {
-                power: vote_power,
-                vote,
-            }),
+            None => Ok(ballot.clone()),
         },
     )?;
 
     let old_status = prop.status;
 
-    prop.votes.add_vote(vote, vote_power);
+    ballot.add_to(&mut prop.votes);
     prop.update_status(&env.block);
 
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
 
     let new_status = prop.status;
-    let change_hooks = proposal_status_changed_hooks(
+    if old_status == Status::Open && new_status != Status::Open {
+        record_turnouts(deps.storage, &env.block)?;
+    }
+    let mut change_hooks = proposal_status_changed_hooks(
         PROPOSAL_HOOKS,
         deps.storage,
         proposal_id,
@@ -401,22 +716,82 @@ pub fn execute_vote(
         new_status.to_string(),
     )?;
 
+    // Vetoed proposals are final, so the pre-propose module slashes
+    // their deposit right away.
+    if new_status == Status::Vetoed {
+        if let ProposalCreationPolicy::Module { addr } = CREATION_POLICY.load(deps.storage)? {
+            let msg = to_json_binary(&PreProposeMsg::ProposalCompletedHook {
+                proposal_id,
+                new_status,
+            })?;
+            change_hooks.push(SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: addr.into_string(),
+                    msg,
+                    funds: vec![],
+                },
+                failed_pre_propose_module_hook_id(),
+            ));
+        }
+    }
+
     let vote_hooks = new_vote_hooks(
         VOTE_HOOKS,
         deps.storage,
         proposal_id,
         info.sender.to_string(),
-        vote.to_string(),
+        ballot.position(),
     )?;
 
-    Ok(Response::default()
+    let mut response = Response::default()
         .add_submessages(change_hooks)
         .add_submessages(vote_hooks)
         .add_attribute("action", "vote")
         .add_attribute("sender", info.sender)
         .add_attribute("proposal_id", proposal_id.to_string())
-        .add_attribute("position", vote.to_string())
-        .add_attribute("status", prop.status.to_string()))
+        .add_attribute("position", ballot.position())
+        .add_attribute("power", ballot.power.to_string())
+        .add_attribute("status", prop.status.to_string());
+    if let Some(delegate) = overridden_delegate {
+        response = response.add_attribute("overridden_delegate", delegate);
+    }
+    Ok(response)
+}
+
+pub fn execute_delegate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    let delegate = deps.api.addr_validate(&delegate)?;
+    let config = CONFIG.load(deps.storage)?;
+    cwd_voting::delegation::check_delegator_power(
+        deps.as_ref(),
+        &info.sender,
+        config.dao,
+        env.block.height,
+    )?;
+    cwd_voting::delegation::delegate(deps.storage, env.block.height, &info.sender, &delegate)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "delegate")
+        .add_attribute("delegator", info.sender)
+        .add_attribute("delegate", delegate))
+}
+
+pub fn execute_undelegate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let delegate =
+        cwd_voting::delegation::undelegate(deps.storage, env.block.height, &info.sender)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "undelegate")
+        .add_attribute("delegator", info.sender)
+        .add_attribute("delegate", delegate))
 }
 
 pub fn execute_close(
@@ -426,18 +801,26 @@ pub fn execute_close(
     proposal_id: u64,
 ) -> Result<Response, ContractError> {
     let mut prop = PROPOSALS.load(deps.storage, proposal_id)?;
+    let stored_status = prop.status;
 
     // Update status to ensure that proposals which were open and have
-    // expired are moved to "rejected."
+    // expired are moved to "rejected" or "vetoed".
     prop.update_status(&env.block);
-    if prop.status != Status::Rejected {
-        return Err(ContractError::WrongCloseStatus {});
-    }
-
-    let old_status = prop.status;
+    let old_status = match prop.status {
+        Status::Rejected => {
+            prop.status = Status::Closed;
+            Status::Rejected
+        }
+        // Proposals vetoed by a vote have had their deposit slashed
+        // already, the ones vetoed on expiration are handled here.
+        Status::Vetoed if stored_status != Status::Vetoed => stored_status,
+        _ => return Err(ContractError::WrongCloseStatus {}),
+    };
 
-    prop.status = Status::Closed;
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    if stored_status == Status::Open {
+        record_turnouts(deps.storage, &env.block)?;
+    }
 
     let hooks = proposal_status_changed_hooks(
         PROPOSAL_HOOKS,
@@ -486,6 +869,8 @@ pub fn execute_update_config(
     allow_revoting: bool,
     dao: String,
     close_proposal_on_execution_failure: bool,
+    veto_threshold: Option<PercentageThreshold>,
+    amendment_period: Option<Duration>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -495,10 +880,14 @@ pub fn execute_update_config(
     }
 
     threshold.validate()?;
+    if let Some(veto_threshold) = veto_threshold {
+        validate_percentage(&veto_threshold)?;
+    }
     let dao = deps.api.addr_validate(&dao)?;
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(min_voting_period, max_voting_period)?;
+    let amendment_period = validate_amendment_period(amendment_period, max_voting_period)?;
 
     CONFIG.save(
         deps.storage,
@@ -509,6 +898,8 @@ pub fn execute_update_config(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            veto_threshold,
+            amendment_period,
         },
     )?;
 
@@ -670,6 +1061,14 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ProposalExecutionError { proposal_id } => {
             query_proposal_execution_error(deps, proposal_id)
         }
+        QueryMsg::Delegation { delegator, height } => query_delegation(deps, delegator, height),
+        QueryMsg::Delegators { delegate, height } => query_delegators(deps, delegate, height),
+        QueryMsg::ListRevisions {
+            proposal_id,
+            start_after,
+            limit,
+        } => query_list_revisions(deps, proposal_id, start_after, limit),
+        QueryMsg::Turnout {} => query_turnout(deps, env),
     }
 }
 
@@ -743,6 +1142,7 @@ pub fn query_vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Bina
         voter,
         vote: ballot.vote,
         power: ballot.power,
+        weighted_options: ballot.weighted_options,
     });
     to_json_binary(&VoteResponse { vote })
 }
@@ -769,6 +1169,7 @@ pub fn query_list_votes(
                 voter,
                 vote: ballot.vote,
                 power: ballot.power,
+                weighted_options: ballot.weighted_options,
             })
         })
         .collect::<StdResult<Vec<_>>>()?;
@@ -776,6 +1177,48 @@ pub fn query_list_votes(
     to_json_binary(&VoteListResponse { votes })
 }
 
+pub fn query_list_revisions(
+    deps: Deps,
+    proposal_id: u64,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let min = start_after.map(Bound::<u64>::exclusive);
+
+    let revisions = PROPOSAL_REVISIONS
+        .prefix(proposal_id)
+        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+        .take(limit as usize)
+        .map(|item| {
+            let (revision, proposal_revision) = item?;
+            Ok(RevisionInfo {
+                revision,
+                proposal_revision,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&RevisionListResponse { revisions })
+}
+
+pub fn query_turnout(deps: Deps, env: Env) -> StdResult<Binary> {
+    let (turnout, _) = final_turnout(deps.storage, &env.block)?;
+    to_json_binary(&turnout)
+}
+
+pub fn query_delegation(deps: Deps, delegator: String, height: Option<u64>) -> StdResult<Binary> {
+    let delegator = deps.api.addr_validate(&delegator)?;
+    let delegate = delegate_at_height(deps.storage, &delegator, height)?;
+    to_json_binary(&DelegationResponse { delegate })
+}
+
+pub fn query_delegators(deps: Deps, delegate: String, height: Option<u64>) -> StdResult<Binary> {
+    let delegate = deps.api.addr_validate(&delegate)?;
+    let delegators = delegators_at_height(deps.storage, &delegate, height)?;
+    to_json_binary(&DelegatorsResponse { delegators })
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_json_binary(&cwd_interface::voting::InfoResponse { info })
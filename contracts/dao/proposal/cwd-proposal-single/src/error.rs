@@ -47,6 +47,21 @@ pub enum ContractError {
     #[error("proposal is not in 'passed' state")]
     NotPassed {},
 
+    #[error("proposal can no longer be amended ({id})")]
+    NotAmendable { id: u64 },
+
+    #[error("proposals may not be amended while a pre-propose module creates them")]
+    PreProposeAmendment {},
+
+    #[error("voting on proposal has not started ({id})")]
+    VotingNotStarted { id: u64 },
+
+    #[error("amendment_period and max_voting_period must have the same units (height or time)")]
+    AmendmentPeriodUnitsConflict {},
+
+    #[error("this proposal has no veto threshold, no_with_veto votes are not supported")]
+    VetoNotSupported {},
+
     #[error("only rejected proposals may be closed")]
     WrongCloseStatus {},
 
@@ -7,7 +7,11 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use cwd_macros::{info_query, proposal_module_query};
-use cwd_voting::{pre_propose::PreProposeInfo, threshold::Threshold, voting::Vote};
+use cwd_voting::{
+    pre_propose::PreProposeInfo,
+    threshold::{PercentageThreshold, Threshold},
+    voting::{Vote, WeightedVoteOption},
+};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
@@ -37,6 +41,16 @@ pub struct InstantiateMsg {
     /// remain open until the DAO's reserve was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// The share of no with veto votes at which proposals are vetoed
+    /// and their deposits slashed regardless of the refund policy.
+    /// None disables vetoes.
+    pub veto_threshold: Option<PercentageThreshold>,
+    /// The amount of time after a proposal's creation during which
+    /// its proposer may amend it. Voting opens once this period has
+    /// passed. Must have the same units as `max_voting_period`. None
+    /// opens voting on creation. Does not apply to proposals created
+    /// through a pre-propose module.
+    pub amendment_period: Option<Duration>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -58,6 +72,25 @@ pub enum ExecuteMsg {
         /// set the proposer of the proposal it creates.
         proposer: Option<String>,
     },
+    /// Creates a proposal with its own threshold and voting period
+    /// instead of the ones set in the module config. Only the
+    /// pre-propose module may create such proposals.
+    ProposeWithParams {
+        /// The title of the proposal.
+        title: String,
+        /// A description of the proposal.
+        description: String,
+        /// The messages that should be executed in response to this
+        /// proposal passing.
+        msgs: Vec<CosmosMsg<NeutronMsg>>,
+        /// The address creating the proposal.
+        proposer: Option<String>,
+        /// The threshold the proposal must reach to complete.
+        threshold: Threshold,
+        /// The maximum amount of time the proposal may be voted on
+        /// before expiring.
+        max_voting_period: Duration,
+    },
     /// Votes on a proposal. Voting power is determined by the DAO's
     /// voting power module.
     Vote {
@@ -66,6 +99,30 @@ pub enum ExecuteMsg {
         /// The senders position on the proposal.
         vote: Vote,
     },
+    /// Votes on a proposal splitting the sender's voting power
+    /// between several positions. Weights must be non-zero, for
+    /// distinct positions and sum up to one.
+    VoteWeighted {
+        /// The ID of the proposal to vote on.
+        proposal_id: u64,
+        /// The shares of the sender's voting power per position.
+        options: Vec<WeightedVoteOption>,
+    },
+    /// Replaces the contents of a proposal before voting on it
+    /// opens. Only the proposer may amend a proposal and only during
+    /// the module's amendment period. Proposals may not be amended
+    /// while a pre-propose module creates them.
+    Amend {
+        /// The ID of the proposal to amend.
+        proposal_id: u64,
+        /// The new title of the proposal.
+        title: String,
+        /// The new description of the proposal.
+        description: String,
+        /// The new messages that should be executed in response to
+        /// this proposal passing.
+        msgs: Vec<CosmosMsg<NeutronMsg>>,
+    },
     /// Causes the messages associated with a passed proposal to be
     /// executed by the DAO.
     Execute {
@@ -110,6 +167,14 @@ pub enum ExecuteMsg {
         /// remain open until the DAO's reserve was large enough for it to be
         /// executed.
         close_proposal_on_execution_failure: bool,
+        /// The share of no with veto votes at which proposals are
+        /// vetoed. This will only apply to proposals created after
+        /// the config update.
+        veto_threshold: Option<PercentageThreshold>,
+        /// The amount of time after a proposal's creation during
+        /// which its proposer may amend it. This will only apply to
+        /// proposals created after the config update.
+        amendment_period: Option<Duration>,
     },
     /// Update's the proposal creation policy used for this
     /// module. Only the DAO may call this method.
@@ -129,6 +194,12 @@ pub enum ExecuteMsg {
     AddVoteHook { address: String },
     /// Removed a consumer of vote hooks.
     RemoveVoteHook { address: String },
+    /// Delegates the sender's vote to DELEGATE starting from the
+    /// next block. The delegate votes with the summed voting power
+    /// of its delegators unless they vote directly.
+    Delegate { delegate: String },
+    /// Removes the sender's delegation starting from the next block.
+    Undelegate {},
 }
 
 #[proposal_module_query]
@@ -207,6 +278,36 @@ pub enum QueryMsg {
     /// Returns `Option<String>`
     #[returns(Option<String>)]
     ProposalExecutionError { proposal_id: u64 },
+    /// Gets the delegate of DELEGATOR at HEIGHT or at the current
+    /// height if none is specified.
+    #[returns(cwd_voting::delegation::DelegationResponse)]
+    Delegation {
+        delegator: String,
+        height: Option<u64>,
+    },
+    /// Lists the delegators of DELEGATE at HEIGHT or at the current
+    /// height if none is specified.
+    #[returns(cwd_voting::delegation::DelegatorsResponse)]
+    Delegators {
+        delegate: String,
+        height: Option<u64>,
+    },
+    /// Lists the revisions of a proposal with an amendment period in
+    /// ascending order. Returns `query::RevisionListResponse`.
+    #[returns(crate::query::RevisionListResponse)]
+    ListRevisions {
+        /// The proposal to list the revisions of.
+        proposal_id: u64,
+        /// The revision number to start listing revisions after.
+        start_after: Option<u64>,
+        /// The maximum number of revisions to return. If no limit is
+        /// specified a max of 30 are returned.
+        limit: Option<u64>,
+    },
+    /// Gets the moving average of the turnout of past proposals
+    /// which dynamic quorums follow. Returns `state::Turnout`.
+    #[returns(crate::state::Turnout)]
+    Turnout {},
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -36,6 +36,15 @@ pub struct SingleChoiceProposal {
     pub status: Status,
     pub votes: Votes,
     pub allow_revoting: bool,
+    /// The share of no with veto votes at which this proposal is
+    /// vetoed. None disables vetoes.
+    #[serde(default)]
+    pub veto_threshold: Option<PercentageThreshold>,
+    /// The time at which voting on this proposal opens. Until then
+    /// the proposer may amend the proposal. None if voting opened on
+    /// creation.
+    #[serde(default)]
+    pub voting_start: Option<Expiration>,
 }
 
 pub fn advance_proposal_id(store: &mut dyn Storage) -> StdResult<u64> {
@@ -59,7 +68,11 @@ impl SingleChoiceProposal {
 
     /// Gets the current status of the proposal.
     pub fn current_status(&self, block: &BlockInfo) -> Status {
-        if self.status == Status::Open && self.is_passed(block) {
+        if self.status == Status::Open && self.is_amendable(block) {
+            Status::Open
+        } else if self.status == Status::Open && self.is_vetoed(block) {
+            Status::Vetoed
+        } else if self.status == Status::Open && self.is_passed(block) {
             Status::Passed
         } else if self.status == Status::Open
             && (self.expiration.is_expired(block) || self.is_rejected(block))
@@ -76,6 +89,13 @@ impl SingleChoiceProposal {
         self.status = new_status
     }
 
+    /// Returns true iff this proposal may still be amended by its
+    /// proposer, that is voting on it has not opened yet.
+    pub fn is_amendable(&self, block: &BlockInfo) -> bool {
+        self.voting_start
+            .map_or(false, |start| !start.is_expired(block))
+    }
+
     /// Returns true iff this proposal is sure to pass (even before
     /// expiration if no future sequence of possible votes can cause
     /// it to fail).
@@ -95,13 +115,21 @@ impl SingleChoiceProposal {
                 return false;
             }
         }
+        // The proposal can not pass early while the outstanding votes
+        // may still veto it.
+        if !self.expiration.is_expired(block) && self.may_be_vetoed() {
+            return false;
+        }
 
         match self.threshold {
             Threshold::AbsolutePercentage { percentage } => {
                 let options = self.total_power - self.votes.abstain;
                 does_vote_count_pass(self.votes.yes, options, percentage)
             }
-            Threshold::ThresholdQuorum { threshold, quorum } => {
+            Threshold::ThresholdQuorum { threshold, quorum }
+            | Threshold::DynamicQuorum {
+                threshold, quorum, ..
+            } => {
                 if !does_vote_count_pass(self.votes.total(), self.total_power, quorum) {
                     return false;
                 }
@@ -159,7 +187,10 @@ impl SingleChoiceProposal {
 
                 does_vote_count_fail(self.votes.no, options, percentage_needed)
             }
-            Threshold::ThresholdQuorum { threshold, quorum } => {
+            Threshold::ThresholdQuorum { threshold, quorum }
+            | Threshold::DynamicQuorum {
+                threshold, quorum, ..
+            } => {
                 match (
                     does_vote_count_pass(self.votes.total(), self.total_power, quorum),
                     self.expiration.is_expired(block),
@@ -234,12 +265,47 @@ impl SingleChoiceProposal {
             }
         }
     }
+
+    /// Returns true iff the no with veto votes crossed the veto
+    /// threshold. Before expiration they are compared to the total
+    /// voting power, after it to the votes cast.
+    pub fn is_vetoed(&self, block: &BlockInfo) -> bool {
+        let veto_threshold = match self.veto_threshold {
+            Some(veto_threshold) => veto_threshold,
+            None => return false,
+        };
+        if self.expiration.is_expired(block) {
+            does_vote_count_pass(self.votes.no_with_veto, self.votes.total(), veto_threshold)
+        } else if self.allow_revoting {
+            // Veto votes may still be changed.
+            false
+        } else {
+            does_vote_count_pass(self.votes.no_with_veto, self.total_power, veto_threshold)
+        }
+    }
+
+    /// Returns true if the proposal would be vetoed should all of the
+    /// outstanding votes be cast as no with veto.
+    fn may_be_vetoed(&self) -> bool {
+        match self.veto_threshold {
+            Some(veto_threshold) => {
+                let outstanding_votes = self.total_power - self.votes.total();
+                does_vote_count_pass(
+                    self.votes.no_with_veto + outstanding_votes,
+                    self.total_power,
+                    veto_threshold,
+                )
+            }
+            None => false,
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use cosmwasm_std::{testing::mock_env, Decimal};
+    use cwd_voting::voting::Vote;
 
     fn setup_prop(
         threshold: Threshold,
@@ -272,6 +338,8 @@ mod test {
             threshold,
             total_power,
             votes,
+            veto_threshold: None,
+            voting_start: None,
         };
         (prop, block)
     }
@@ -323,6 +391,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(4),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // 15 total votes. 7 yes and 2 abstain. Majority threshold. This
@@ -365,6 +434,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(4),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // Does not pass if min voting period is not expired.
@@ -406,6 +476,7 @@ mod test {
             yes: Uint128::new(4),
             no: Uint128::new(7),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // Proposal has not passed.
@@ -440,6 +511,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(4),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // 15 total votes. 7 yes and 2 abstain. Majority threshold. This
@@ -475,6 +547,7 @@ mod test {
             yes: Uint128::new(4),
             no: Uint128::new(7),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // Not expired, revoting allowed => no rejection.
@@ -512,6 +585,7 @@ mod test {
                 yes: Uint128::new(10),
                 no: Uint128::zero(),
                 abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(100),
             false,
@@ -524,7 +598,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(10),
             false,
@@ -537,7 +612,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(11),
             false,
@@ -550,7 +626,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(11),
             false,
@@ -573,6 +650,7 @@ mod test {
                 yes: Uint128::new(10),
                 no: Uint128::zero(),
                 abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(100),
             false,
@@ -585,6 +663,7 @@ mod test {
                 yes: Uint128::new(10),
                 no: Uint128::zero(),
                 abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(100),
             true,
@@ -597,7 +676,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(10),
             false,
@@ -609,7 +689,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(10),
             true,
@@ -627,6 +708,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(6),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         };
         assert!(check_is_passed(
             threshold,
@@ -647,6 +729,7 @@ mod test {
             yes: Uint128::new(6),
             no: Uint128::new(7),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         };
         assert!(check_is_passed(
             threshold.clone(),
@@ -675,6 +758,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(6),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         };
         assert!(check_is_passed(
             threshold.clone(),
@@ -705,6 +789,7 @@ mod test {
             yes: Uint128::new(4),
             no: Uint128::new(7),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // 15 total voting power
@@ -776,18 +861,21 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(3),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
         // abstain votes are not counted for threshold => yes / (yes + no + veto)
         let passes_ignoring_abstain = Votes {
             yes: Uint128::new(6),
             no: Uint128::new(6),
             abstain: Uint128::new(5),
+            no_with_veto: Uint128::zero(),
         };
         // fails any way you look at it
         let failing = Votes {
             yes: Uint128::new(6),
             no: Uint128::new(7),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // first, expired (voting period over)
@@ -888,18 +976,21 @@ mod test {
             yes: Uint128::new(3),
             no: Uint128::new(8),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
         // abstain votes are not counted for threshold => yes / (yes + no)
         let rejected_ignoring_abstain = Votes {
             yes: Uint128::new(4),
             no: Uint128::new(8),
             abstain: Uint128::new(5),
+            no_with_veto: Uint128::zero(),
         };
         // fails any way you look at it
         let failing = Votes {
             yes: Uint128::new(5),
             no: Uint128::new(8),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // first, expired (voting period over)
@@ -1021,6 +1112,7 @@ mod test {
             yes: Uint128::new(9),
             no: Uint128::new(1),
             abstain: Uint128::new(0),
+            no_with_veto: Uint128::zero(),
         };
         assert!(!check_is_passed(
             quorum.clone(),
@@ -1044,6 +1136,7 @@ mod test {
             yes: Uint128::new(8),
             no: Uint128::new(4),
             abstain: Uint128::new(0),
+            no_with_veto: Uint128::zero(),
         };
         assert!(!check_is_passed(
             quorum.clone(),
@@ -1067,6 +1160,7 @@ mod test {
             yes: Uint128::new(9),
             no: Uint128::new(3),
             abstain: Uint128::new(0),
+            no_with_veto: Uint128::zero(),
         };
         assert!(check_is_passed(
             quorum.clone(),
@@ -1085,4 +1179,49 @@ mod test {
             false
         ));
     }
+
+    #[test]
+    fn test_veto() {
+        let threshold = Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Majority {},
+        };
+        let votes = Votes {
+            yes: Uint128::new(6),
+            no: Uint128::new(2),
+            abstain: Uint128::zero(),
+            no_with_veto: Uint128::new(2),
+        };
+        let (mut prop, block) = setup_prop(threshold, votes, Uint128::new(10), false, true, false);
+        assert!(prop.is_passed(&block));
+        assert!(!prop.is_vetoed(&block));
+
+        // The outstanding votes may still veto the proposal.
+        prop.veto_threshold = Some(PercentageThreshold::Percent(Decimal::percent(33)));
+        assert!(!prop.is_passed(&block));
+        assert!(!prop.is_vetoed(&block));
+        assert_eq!(prop.current_status(&block), Status::Open);
+
+        prop.votes.add_vote(Vote::NoWithVeto, Uint128::new(2));
+        assert!(prop.is_vetoed(&block));
+        assert_eq!(prop.current_status(&block), Status::Vetoed);
+
+        // After expiration the veto votes are compared to the votes
+        // cast.
+        let (mut prop, block) = setup_prop(
+            threshold,
+            Votes {
+                yes: Uint128::new(4),
+                no: Uint128::new(2),
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::new(2),
+            },
+            Uint128::new(10),
+            true,
+            true,
+            false,
+        );
+        prop.veto_threshold = Some(PercentageThreshold::Percent(Decimal::percent(33)));
+        assert!(prop.is_vetoed(&block));
+        assert_eq!(prop.current_status(&block), Status::Vetoed);
+    }
 }
@@ -2,9 +2,10 @@ use cosmwasm_std::{Addr, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cwd_voting::voting::Vote;
+use cwd_voting::voting::{Vote, WeightedVoteOption};
 
 use crate::proposal::SingleChoiceProposal;
+use crate::state::ProposalRevision;
 
 /// Information about a proposal returned by proposal queries.
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
@@ -23,6 +24,10 @@ pub struct VoteInfo {
     pub vote: Vote,
     /// The voting power behind the vote.
     pub power: Uint128,
+    /// The split of the voting power between positions if the vote
+    /// is weighted.
+    #[serde(default)]
+    pub weighted_options: Option<Vec<WeightedVoteOption>>,
 }
 
 /// Information about a vote.
@@ -44,3 +49,18 @@ pub struct VoteListResponse {
 pub struct ProposalListResponse {
     pub proposals: Vec<ProposalResponse>,
 }
+
+/// A revision of a proposal.
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+pub struct RevisionInfo {
+    /// The revision number. The proposal as created is revision
+    /// zero.
+    pub revision: u64,
+    pub proposal_revision: ProposalRevision,
+}
+
+/// Information about the revisions of a proposal.
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+pub struct RevisionListResponse {
+    pub revisions: Vec<RevisionInfo>,
+}
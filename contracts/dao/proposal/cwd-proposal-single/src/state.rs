@@ -1,12 +1,17 @@
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, CosmosMsg, Decimal, Empty, Uint128};
 use cw_storage_plus::{Item, Map};
 use cw_utils::Duration;
 
 use cwd_hooks::Hooks;
+use neutron_sdk::bindings::msg::NeutronMsg;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cwd_voting::{pre_propose::ProposalCreationPolicy, threshold::Threshold, voting::Vote};
+use cwd_voting::{
+    pre_propose::ProposalCreationPolicy,
+    threshold::{PercentageThreshold, Threshold},
+    voting::{weighted_vote_to_string, Vote, Votes, WeightedVoteOption},
+};
 
 use crate::proposal::SingleChoiceProposal;
 
@@ -15,9 +20,81 @@ use crate::proposal::SingleChoiceProposal;
 pub struct Ballot {
     /// The amount of voting power behind the vote.
     pub power: Uint128,
-    /// The position.
+    /// The position. For weighted votes this is the position with
+    /// the largest weight.
     pub vote: Vote,
+    /// The split of the voting power between positions if the vote
+    /// is weighted.
+    #[serde(default)]
+    pub weighted_options: Option<Vec<WeightedVoteOption>>,
 }
+
+impl Ballot {
+    /// Adds the ballot's voting power to the votes.
+    pub fn add_to(&self, votes: &mut Votes) {
+        match &self.weighted_options {
+            Some(options) => votes.add_weighted_vote(options, self.power),
+            None => votes.add_vote(self.vote, self.power),
+        }
+    }
+
+    /// Removes the ballot's voting power from the votes.
+    pub fn remove_from(&self, votes: &mut Votes) {
+        match &self.weighted_options {
+            Some(options) => votes.remove_weighted_vote(options, self.power),
+            None => votes.remove_vote(self.vote, self.power),
+        }
+    }
+
+    /// The position as reported in vote hooks and attributes.
+    pub fn position(&self) -> String {
+        match &self.weighted_options {
+            Some(options) => weighted_vote_to_string(options),
+            None => self.vote.to_string(),
+        }
+    }
+}
+
+/// A revision of a proposal's contents. The first revision is the
+/// proposal as created, later ones are amendments by the proposer.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ProposalRevision {
+    pub title: String,
+    pub description: String,
+    pub msgs: Vec<CosmosMsg<NeutronMsg>>,
+    /// The block height at which the revision was made.
+    pub height: u64,
+}
+
+/// The weight in percent of the latest proposal's turnout in the
+/// moving average of turnout.
+pub const TURNOUT_WEIGHT_PERCENT: u64 = 20;
+
+/// The turnout of past proposals. Dynamic quorums follow its moving
+/// average.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema, Default)]
+pub struct Turnout {
+    /// The exponential moving average of the share of the total
+    /// voting power that voted on proposals.
+    pub average: Decimal,
+    /// The number of proposals counted in the average.
+    pub proposals: u64,
+}
+
+impl Turnout {
+    /// Adds the turnout of a proposal that stopped accepting votes to
+    /// the moving average.
+    pub fn record(&mut self, turnout: Decimal) {
+        self.average = if self.proposals == 0 {
+            turnout
+        } else {
+            let weight = Decimal::percent(TURNOUT_WEIGHT_PERCENT);
+            self.average * (Decimal::one() - weight) + turnout * weight
+        };
+        self.proposals += 1;
+    }
+}
+
 /// The governance module's configuration.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
@@ -48,6 +125,16 @@ pub struct Config {
     /// remain open until the DAO's reserve was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// The share of no with veto votes at which proposals are vetoed
+    /// and their deposits slashed. None disables vetoes.
+    #[serde(default)]
+    pub veto_threshold: Option<PercentageThreshold>,
+    /// The amount of time after a proposal's creation during which
+    /// its proposer may amend it. Voting opens once this period has
+    /// passed. None opens voting on creation. Does not apply to
+    /// proposals created through a pre-propose module.
+    #[serde(default)]
+    pub amendment_period: Option<Duration>,
 }
 
 /// The current top level config for the module.  The "config" key was
@@ -57,6 +144,15 @@ pub const CONFIG: Item<Config> = Item::new("config_v2");
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
 pub const PROPOSALS: Map<u64, SingleChoiceProposal> = Map::new("proposals_v2");
 pub const BALLOTS: Map<(u64, Addr), Ballot> = Map::new("ballots");
+/// Revisions of proposals with an amendment period keyed by proposal
+/// ID and revision number.
+pub const PROPOSAL_REVISIONS: Map<(u64, u64), ProposalRevision> = Map::new("proposal_revisions");
+/// The turnout of the proposals of this module.
+pub const TURNOUT: Item<Turnout> = Item::new("turnout");
+/// The proposals whose turnout has not been added to `TURNOUT` yet.
+/// A proposal is added once it stops accepting votes, whether or not
+/// its stored status reflects that yet.
+pub const UNRECORDED_TURNOUTS: Map<u64, Empty> = Map::new("unrecorded_turnouts");
 /// Consumers of proposal state change hooks.
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
@@ -82,6 +82,8 @@ where
         min_voting_period: None,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        veto_threshold: None,
+        amendment_period: None,
         pre_propose_info,
     };
 
@@ -206,6 +208,7 @@ where
                             // expected voting power.
                             _ => weight,
                         },
+                        weighted_options: None,
                     }),
                 };
                 assert_eq!(vote, expected)
@@ -66,6 +66,8 @@ pub(crate) fn get_proposal_module_instantiate(app: &mut BasicApp<NeutronMsg>) ->
             false,
         ),
         close_proposal_on_execution_failure: true,
+        veto_threshold: None,
+        amendment_period: None,
     }
 }
 
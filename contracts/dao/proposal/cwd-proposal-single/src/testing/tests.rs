@@ -8,12 +8,16 @@ use cosmwasm_std::{
 use cosmwasm_std::{Api, Storage};
 use cw2::ContractVersion;
 use cw20::Cw20Coin;
+use cw_denom::UncheckedDenom;
 use cw_multi_test::{custom_app, BasicApp, Executor, Router};
-use cw_utils::Duration;
+use cw_utils::{Duration, Expiration};
 use cwd_core::msg::{ExecuteMsg as DaoExecuteMsg, QueryMsg as DaoQueryMsg};
 use cwd_hooks::{HookError, HooksResponse};
 use cwd_interface::voting::InfoResponse;
 use cwd_voting::{
+    delegation::{DelegationResponse, DelegatorsResponse},
+    deposit::{DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    error::VotingError,
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     proposal::MAX_PROPOSAL_SIZE,
     reply::{
@@ -22,7 +26,7 @@ use cwd_voting::{
     },
     status::Status,
     threshold::{PercentageThreshold, Threshold},
-    voting::{Vote, Votes},
+    voting::{Vote, Votes, WeightedVoteOption},
 };
 use neutron_sdk::bindings::msg::NeutronMsg;
 use neutron_subdao_core::types::SubDao;
@@ -32,8 +36,8 @@ use crate::{
     contract::{CONTRACT_NAME, CONTRACT_VERSION},
     msg::{ExecuteMsg, QueryMsg},
     proposal::SingleChoiceProposal,
-    query::{ProposalResponse, VoteInfo},
-    state::Config,
+    query::{ProposalResponse, RevisionListResponse, VoteInfo},
+    state::{Config, Turnout},
     testing::{
         execute::{
             add_proposal_hook, add_proposal_hook_should_fail, add_vote_hook,
@@ -42,7 +46,8 @@ use crate::{
             remove_vote_hook_should_fail, vote_on_proposal, vote_on_proposal_should_fail,
         },
         instantiate::{
-            get_proposal_module_instantiate, instantiate_with_native_bonded_balances_governance,
+            get_pre_propose_info, get_proposal_module_instantiate,
+            instantiate_with_native_bonded_balances_governance,
         },
         queries::{
             query_balance_native, query_creation_policy, query_list_proposals,
@@ -295,6 +300,8 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                veto_threshold: None,
+                amendment_period: None,
             })
             .unwrap(),
             funds: vec![],
@@ -322,6 +329,8 @@ fn test_update_config() {
             allow_revoting: false,
             dao: core_addr.clone(),
             close_proposal_on_execution_failure: false,
+            veto_threshold: None,
+            amendment_period: None,
         }
     );
 
@@ -339,6 +348,8 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                veto_threshold: None,
+                amendment_period: None,
             },
             &[],
         )
@@ -419,8 +430,11 @@ fn test_anyone_may_propose_and_proposal_listing() {
                 votes: Votes {
                     yes: Uint128::new(100_000_000),
                     no: Uint128::zero(),
-                    abstain: Uint128::zero()
+                    abstain: Uint128::zero(),
+                    no_with_veto: Uint128::zero(),
                 },
+                veto_threshold: None,
+                voting_start: None,
             }
         }
     )
@@ -730,6 +744,8 @@ fn test_allow_revoting_config_changes() {
             allow_revoting: false,
             dao: core_addr.to_string(),
             close_proposal_on_execution_failure: false,
+            veto_threshold: None,
+            amendment_period: None,
         },
         &[],
     )
@@ -885,6 +901,8 @@ fn test_execution_failed() {
             dao: config.dao.into_string(),
             // Disable.
             close_proposal_on_execution_failure: false,
+            veto_threshold: None,
+            amendment_period: None,
         },
         &[],
     )
@@ -950,6 +968,8 @@ fn test_reply_proposal_mock() {
                 msgs: vec![],
                 status: Status::Open,
                 votes: Votes::zero(),
+                veto_threshold: None,
+                voting_start: None,
             },
         )
         .unwrap();
@@ -1280,27 +1300,32 @@ fn test_query_list_votes() {
             VoteInfo {
                 voter: Addr::unchecked("five"),
                 vote: Vote::Yes,
-                power: Uint128::new(1)
+                power: Uint128::new(1),
+                weighted_options: None,
             },
             VoteInfo {
                 voter: Addr::unchecked("four"),
                 vote: Vote::Yes,
-                power: Uint128::new(1)
+                power: Uint128::new(1),
+                weighted_options: None,
             },
             VoteInfo {
                 voter: Addr::unchecked("one"),
                 vote: Vote::Yes,
-                power: Uint128::new(1)
+                power: Uint128::new(1),
+                weighted_options: None,
             },
             VoteInfo {
                 voter: Addr::unchecked("three"),
                 vote: Vote::No,
-                power: Uint128::new(1)
+                power: Uint128::new(1),
+                weighted_options: None,
             },
             VoteInfo {
                 voter: Addr::unchecked("two"),
                 vote: Vote::No,
-                power: Uint128::new(1)
+                power: Uint128::new(1),
+                weighted_options: None,
             }
         ]
     );
@@ -1318,12 +1343,14 @@ fn test_query_list_votes() {
             VoteInfo {
                 voter: Addr::unchecked("one"),
                 vote: Vote::Yes,
-                power: Uint128::new(1)
+                power: Uint128::new(1),
+                weighted_options: None,
             },
             VoteInfo {
                 voter: Addr::unchecked("three"),
                 vote: Vote::No,
-                power: Uint128::new(1)
+                power: Uint128::new(1),
+                weighted_options: None,
             },
         ]
     );
@@ -1420,3 +1447,674 @@ fn test_subdao_queries() {
 
 // - What happens if you have proposals that can not be executed but
 //   took deposits and want to migrate?
+
+#[test]
+fn test_weighted_vote() {
+    let mut app = custom_app::<NeutronMsg, Empty, _>(no_init);
+    let mut instantiate = get_proposal_module_instantiate(&mut app);
+    instantiate.allow_revoting = true;
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_native_bonded_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "custodian".to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "one".to_string(),
+                amount: Uint128::new(5),
+            },
+            Cw20Coin {
+                address: "two".to_string(),
+                amount: Uint128::new(5),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, "one", vec![]);
+
+    let option = |vote, percent| WeightedVoteOption {
+        vote,
+        weight: Decimal::percent(percent),
+    };
+    let vote_weighted = |app: &mut BasicApp<NeutronMsg>, options: Vec<WeightedVoteOption>| {
+        app.execute_contract(
+            Addr::unchecked("custodian"),
+            proposal_module.clone(),
+            &ExecuteMsg::VoteWeighted {
+                proposal_id,
+                options,
+            },
+            &[],
+        )
+    };
+
+    // Weights must sum up to one.
+    let err: ContractError =
+        vote_weighted(&mut app, vec![option(Vote::Yes, 60), option(Vote::No, 30)])
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VotingError(VotingError::InvalidWeightedVote {})
+    );
+
+    // The module has no veto threshold, so vetoing isn't supported.
+    let err: ContractError = vote_weighted(
+        &mut app,
+        vec![option(Vote::Yes, 90), option(Vote::NoWithVeto, 10)],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, ContractError::VetoNotSupported {});
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("one"),
+            proposal_module.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: Vote::NoWithVeto,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::VetoNotSupported {});
+
+    let split = vec![
+        option(Vote::Yes, 60),
+        option(Vote::No, 30),
+        option(Vote::Abstain, 10),
+    ];
+    let res = vote_weighted(&mut app, split.clone()).unwrap();
+    assert!(res.events.iter().any(|e| e
+        .attributes
+        .contains(&Attribute::new("position", "yes=0.6,no=0.3,abstain=0.1"))));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(
+        proposal.votes,
+        Votes {
+            yes: Uint128::new(6),
+            no: Uint128::new(3),
+            abstain: Uint128::new(1),
+            no_with_veto: Uint128::zero(),
+        }
+    );
+    assert_eq!(proposal.status, Status::Open);
+
+    // Casting the same split twice is not allowed.
+    let err: ContractError = vote_weighted(&mut app, split)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AlreadyCast {});
+
+    // Revoting removes the previous split.
+    let split = vec![option(Vote::Yes, 30), option(Vote::No, 70)];
+    vote_weighted(&mut app, split.clone()).unwrap();
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(
+        proposal.votes,
+        Votes {
+            yes: Uint128::new(3),
+            no: Uint128::new(7),
+            abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
+        }
+    );
+
+    vote_on_proposal(&mut app, &proposal_module, "one", proposal_id, Vote::No);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::Rejected);
+
+    let votes = query_list_votes(&app, &proposal_module, proposal_id, None, None);
+    assert_eq!(
+        votes.votes,
+        vec![
+            VoteInfo {
+                voter: Addr::unchecked("custodian"),
+                vote: Vote::No,
+                power: Uint128::new(10),
+                weighted_options: Some(split),
+            },
+            VoteInfo {
+                voter: Addr::unchecked("one"),
+                vote: Vote::No,
+                power: Uint128::new(5),
+                weighted_options: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_vote_delegation() {
+    let mut app = custom_app::<NeutronMsg, Empty, _>(no_init);
+    let mut instantiate = get_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::AbsoluteCount {
+        threshold: Uint128::new(8),
+    };
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_native_bonded_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "delegate".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "one".to_string(),
+                amount: Uint128::new(3),
+            },
+            Cw20Coin {
+                address: "two".to_string(),
+                amount: Uint128::new(3),
+            },
+            Cw20Coin {
+                address: "three".to_string(),
+                amount: Uint128::new(3),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    for delegator in ["one", "two"] {
+        app.execute_contract(
+            Addr::unchecked(delegator),
+            proposal_module.clone(),
+            &ExecuteMsg::Delegate {
+                delegate: "delegate".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("three"),
+            proposal_module.clone(),
+            &ExecuteMsg::Delegate {
+                delegate: "three".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VotingError(VotingError::SelfDelegation {})
+    );
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("nobody"),
+            proposal_module.clone(),
+            &ExecuteMsg::Delegate {
+                delegate: "delegate".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VotingError(VotingError::NoDelegatorPower {})
+    );
+
+    let delegators: DelegatorsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_module.clone(),
+            &QueryMsg::Delegators {
+                delegate: "delegate".to_string(),
+                height: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        delegators.delegators,
+        vec![Addr::unchecked("one"), Addr::unchecked("two")]
+    );
+
+    // Delegations take effect from the next block.
+    app.update_block(|b| b.height += 1);
+    let proposal_id = make_proposal(&mut app, &proposal_module, "three", vec![]);
+
+    // Delegations made after the proposal start do not count.
+    app.execute_contract(
+        Addr::unchecked("three"),
+        proposal_module.clone(),
+        &ExecuteMsg::Delegate {
+            delegate: "delegate".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "delegate",
+        proposal_id,
+        Vote::Yes,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(
+        proposal.votes,
+        Votes {
+            yes: Uint128::new(7),
+            no: Uint128::zero(),
+            abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
+        }
+    );
+    assert_eq!(proposal.status, Status::Open);
+
+    // A delegator overrides its delegate by voting directly.
+    let res = app
+        .execute_contract(
+            Addr::unchecked("two"),
+            proposal_module.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: Vote::No,
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(res.events.iter().any(|e| e
+        .attributes
+        .contains(&Attribute::new("overridden_delegate", "delegate"))));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(
+        proposal.votes,
+        Votes {
+            yes: Uint128::new(4),
+            no: Uint128::new(3),
+            abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
+        }
+    );
+    // The outstanding power can no longer reach the threshold.
+    assert_eq!(proposal.status, Status::Rejected);
+
+    app.execute_contract(
+        Addr::unchecked("one"),
+        proposal_module.clone(),
+        &ExecuteMsg::Undelegate {},
+        &[],
+    )
+    .unwrap();
+    let delegation: DelegationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_module.clone(),
+            &QueryMsg::Delegation {
+                delegator: "one".to_string(),
+                height: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(delegation.delegate, None);
+}
+
+#[test]
+fn test_veto_slashes_deposit() {
+    let mut app = custom_app::<NeutronMsg, Empty, _>(no_init);
+    let mut instantiate = get_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = get_pre_propose_info(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10_000_000),
+            // Vetoed proposals are slashed regardless of the refund
+            // policy.
+            refund_policy: DepositRefundPolicy::Always,
+        }),
+        false,
+    );
+    instantiate.veto_threshold = Some(PercentageThreshold::Percent(Decimal::percent(33)));
+    let core_addr = instantiate_with_native_bonded_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(60),
+            },
+            Cw20Coin {
+                address: "vetoer".to_string(),
+                amount: Uint128::new(40),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    mint_natives(&mut app, CREATOR_ADDR, coins(10_000_000, "ujuno"));
+    let dao_balance = query_balance_native(&app, core_addr.as_str(), "ujuno");
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "vetoer",
+        proposal_id,
+        Vote::NoWithVeto,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::Vetoed);
+    assert_eq!(
+        proposal.votes,
+        Votes {
+            yes: Uint128::zero(),
+            no: Uint128::new(40),
+            abstain: Uint128::zero(),
+            no_with_veto: Uint128::new(40),
+        }
+    );
+
+    // The deposit went to the DAO instead of the proposer.
+    assert_eq!(
+        query_balance_native(&app, CREATOR_ADDR, "ujuno"),
+        Uint128::zero()
+    );
+    assert_eq!(
+        query_balance_native(&app, core_addr.as_str(), "ujuno"),
+        dao_balance + Uint128::new(10_000_000)
+    );
+
+    // Vetoed proposals are final.
+    let err = close_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::WrongCloseStatus {}));
+    let err = vote_on_proposal_should_fail(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    assert!(matches!(err, ContractError::NotOpen { .. }));
+}
+
+#[test]
+fn test_amend_proposal() {
+    let mut app = custom_app::<NeutronMsg, Empty, _>(no_init);
+    let mut instantiate = get_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.amendment_period = Some(Duration::Time(86400));
+    let core_addr = instantiate_with_native_bonded_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let created = app.block_info().time;
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::Open);
+    assert_eq!(
+        proposal.voting_start,
+        Some(Expiration::AtTime(created.plus_seconds(86400)))
+    );
+    // The voting period counts from the end of the amendment period.
+    assert_eq!(
+        proposal.expiration,
+        Expiration::AtTime(created.plus_seconds(86400 + 604800))
+    );
+
+    let err = vote_on_proposal_should_fail(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    assert!(matches!(err, ContractError::VotingNotStarted { id: 1 }));
+
+    let amend = ExecuteMsg::Amend {
+        proposal_id,
+        title: "amended".to_string(),
+        description: "fixed a typo".to_string(),
+        msgs: vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: CREATOR_ADDR.to_string(),
+            amount: coins(10, "ujuno"),
+        })],
+    };
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            proposal_module.clone(),
+            &amend,
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    app.update_block(|b| b.height += 1);
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        proposal_module.clone(),
+        &amend,
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.title, "amended");
+    assert_eq!(proposal.msgs.len(), 1);
+
+    let revisions: RevisionListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_module.clone(),
+            &QueryMsg::ListRevisions {
+                proposal_id,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(revisions.revisions.len(), 2);
+    assert_eq!(revisions.revisions[0].revision, 0);
+    assert_eq!(revisions.revisions[0].proposal_revision.title, "title");
+    assert_eq!(revisions.revisions[1].revision, 1);
+    assert_eq!(revisions.revisions[1].proposal_revision.title, "amended");
+    assert_eq!(
+        revisions.revisions[1].proposal_revision.height,
+        app.block_info().height
+    );
+
+    // Once voting opens the proposal is frozen.
+    app.update_block(|b| b.time = b.time.plus_seconds(86400));
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &amend,
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::NotAmendable { id: 1 }));
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::Passed);
+}
+
+#[test]
+fn test_dynamic_quorum() {
+    let mut app = custom_app::<NeutronMsg, Empty, _>(no_init);
+    let mut instantiate = get_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::DynamicQuorum {
+        threshold: PercentageThreshold::Majority {},
+        quorum: PercentageThreshold::Percent(Decimal::percent(30)),
+        min_quorum: Decimal::percent(10),
+        max_quorum: Decimal::percent(40),
+    };
+    let core_addr = instantiate_with_native_bonded_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(20),
+            },
+            Cw20Coin {
+                address: "whale".to_string(),
+                amount: Uint128::new(80),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let query_turnout = |app: &BasicApp<NeutronMsg>| -> Turnout {
+        app.wrap()
+            .query_wasm_smart(proposal_module.clone(), &QueryMsg::Turnout {})
+            .unwrap()
+    };
+    assert_eq!(query_turnout(&app), Turnout::default());
+
+    // Without turnout history the configured quorum applies.
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    app.update_block(|b| b.time = b.time.plus_seconds(604800));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::Rejected);
+    close_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert_eq!(
+        query_turnout(&app),
+        Turnout {
+            average: Decimal::percent(20),
+            proposals: 1,
+        }
+    );
+
+    // The quorum of the next proposal follows the turnout.
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(
+        proposal.threshold,
+        Threshold::DynamicQuorum {
+            threshold: PercentageThreshold::Majority {},
+            quorum: PercentageThreshold::Percent(Decimal::percent(20)),
+            min_quorum: Decimal::percent(10),
+            max_quorum: Decimal::percent(40),
+        }
+    );
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    app.update_block(|b| b.time = b.time.plus_seconds(604800));
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert_eq!(
+        query_turnout(&app),
+        Turnout {
+            average: Decimal::percent(20),
+            proposals: 2,
+        }
+    );
+
+    // Proposals that expire without being closed count as well.
+    make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    app.update_block(|b| b.time = b.time.plus_seconds(604800));
+    let expected = Turnout {
+        average: Decimal::percent(16),
+        proposals: 3,
+    };
+    assert_eq!(query_turnout(&app), expected);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(
+        proposal.threshold,
+        Threshold::DynamicQuorum {
+            threshold: PercentageThreshold::Majority {},
+            quorum: PercentageThreshold::Percent(Decimal::percent(16)),
+            min_quorum: Decimal::percent(10),
+            max_quorum: Decimal::percent(40),
+        }
+    );
+    assert_eq!(query_turnout(&app), expected);
+}
+
+#[test]
+fn test_amend_overrule_proposal() {
+    let mut app = custom_app::<NeutronMsg, Empty, _>(no_init);
+    let mut instantiate = get_proposal_module_instantiate(&mut app);
+    instantiate.amendment_period = Some(Duration::Time(86400));
+    let core_addr = instantiate_with_native_bonded_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let pre_propose = match query_creation_policy(&app, &proposal_module) {
+        ProposalCreationPolicy::Module { addr } => addr,
+        ProposalCreationPolicy::Anyone {} => panic!("expected a pre-propose module"),
+    };
+
+    // Create the proposal the way the overrule pre-propose module
+    // does, recording the requester of the overrule as its proposer.
+    let overrule_msgs = vec![CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: "timelock".to_string(),
+        msg: to_json_binary(&"overrule_proposal").unwrap(),
+        funds: vec![],
+    })];
+    app.execute_contract(
+        pre_propose,
+        proposal_module.clone(),
+        &ExecuteMsg::Propose {
+            title: "Reject the proposal #1".to_string(),
+            description: "Reject the proposal".to_string(),
+            msgs: overrule_msgs.clone(),
+            proposer: Some("requester".to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &proposal_module, 1).proposal;
+    assert_eq!(proposal.proposer, Addr::unchecked("requester"));
+    // Pre-propose module proposals get no amendment period.
+    assert_eq!(proposal.voting_start, None);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("requester"),
+            proposal_module.clone(),
+            &ExecuteMsg::Amend {
+                proposal_id: 1,
+                title: "Reject the proposal #1".to_string(),
+                description: "Reject the proposal".to_string(),
+                msgs: vec![CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "requester".to_string(),
+                    amount: coins(10, "ujuno"),
+                })],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::PreProposeAmendment {}));
+    let proposal = query_proposal(&app, &proposal_module, 1).proposal;
+    assert_eq!(proposal.msgs, overrule_msgs);
+}
@@ -1,8 +1,8 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Reply,
-    Response, StdError, StdResult, SubMsg,
+    to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, IbcMsg,
+    MessageInfo, Order, Reply, Response, StdError, StdResult, SubMsg, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version};
 use cw_paginate::{paginate_map, paginate_map_values};
@@ -15,16 +15,18 @@ use exec_control::pause::{
 use neutron_sdk::bindings::msg::NeutronMsg;
 use neutron_subdao_core::msg::{ExecuteMsg, InitialItem, InstantiateMsg, MigrateMsg, QueryMsg};
 use neutron_subdao_core::types::{
-    Config, DumpStateResponse, GetItemResponse, ProposalModule, ProposalModuleStatus, SubDao,
+    Config, DumpStateResponse, GetItemResponse, ProposalModule, ProposalModuleStatus,
+    SpendingBudgetResponse, SpendingLimit, SpentAmount, SubDao,
 };
 use neutron_subdao_pre_propose_single::msg::QueryExt as PreProposeQueryExt;
 use neutron_subdao_pre_propose_single::msg::QueryMsg as PreProposeQueryMsg;
 use neutron_subdao_proposal_single::msg::QueryMsg as ProposeQueryMsg;
+use neutron_subdao_timelock_single::msg::ExecuteMsg as TimelockExecuteMsg;
 
 use crate::error::ContractError;
 use crate::state::{
-    ACTIVE_PROPOSAL_MODULE_COUNT, CONFIG, ITEMS, PAUSED_UNTIL, PROPOSAL_MODULES, SUBDAO_LIST,
-    TOTAL_PROPOSAL_MODULE_COUNT, VOTE_MODULE,
+    ACTIVE_PROPOSAL_MODULE_COUNT, CONFIG, ITEMS, PAUSED_UNTIL, PROPOSAL_MODULES, SPENDING_LIMITS,
+    SPENT_AMOUNTS, SUBDAO_LIST, TOTAL_PROPOSAL_MODULE_COUNT, VOTE_MODULE,
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:cwd-subdao-core";
@@ -96,6 +98,18 @@ pub fn execute(
             return match msg {
                 ExecuteMsg::Pause { duration } => execute_pause(deps, env, info.sender, duration),
                 ExecuteMsg::Unpause {} => execute_unpause(deps, env, info.sender),
+                ExecuteMsg::EmergencyRecovery {
+                    pause_duration,
+                    voting_module,
+                    proposal_modules,
+                } => execute_emergency_recovery(
+                    deps,
+                    env,
+                    info.sender,
+                    pause_duration,
+                    voting_module,
+                    proposal_modules,
+                ),
                 _ => Err(ContractError::PauseError(PauseError::Paused {})),
             };
         }
@@ -104,10 +118,10 @@ pub fn execute(
 
     match msg {
         ExecuteMsg::ExecuteProposalHook { msgs } => {
-            execute_proposal_hook(deps.as_ref(), info.sender, msgs)
+            execute_proposal_hook(deps, env, info.sender, msgs)
         }
         ExecuteMsg::ExecuteTimelockedMsgs { msgs } => {
-            execute_timelocked_msgs(deps.as_ref(), env, info.sender, msgs)
+            execute_timelocked_msgs(deps, env, info.sender, msgs)
         }
         ExecuteMsg::Pause { duration } => execute_pause(deps, env, info.sender, duration),
         ExecuteMsg::Unpause {} => execute_unpause(deps, env, info.sender),
@@ -127,6 +141,21 @@ pub fn execute(
         ExecuteMsg::UpdateSubDaos { to_add, to_remove } => {
             execute_update_sub_daos_list(deps, env, info.sender, to_add, to_remove)
         }
+        ExecuteMsg::EmergencyRecovery {
+            pause_duration,
+            voting_module,
+            proposal_modules,
+        } => execute_emergency_recovery(
+            deps,
+            env,
+            info.sender,
+            pause_duration,
+            voting_module,
+            proposal_modules,
+        ),
+        ExecuteMsg::UpdateSpendingLimits { to_set, to_remove } => {
+            execute_update_spending_limits(deps, info.sender, to_set, to_remove)
+        }
     }
 }
 
@@ -174,8 +203,111 @@ pub fn execute_unpause(
         .add_attribute("sender", sender))
 }
 
+pub fn execute_emergency_recovery(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    pause_duration: u64,
+    voting_module: ModuleInstantiateInfo,
+    proposal_modules: Option<Vec<ModuleInstantiateInfo>>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+
+    if sender != config.main_dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    validate_duration(pause_duration)?;
+
+    // Keep the longer pause if the subDAO is already paused for a greater duration.
+    let paused_until_height = PAUSED_UNTIL
+        .load(deps.storage)?
+        .unwrap_or(0u64)
+        .max(env.block.height + pause_duration);
+    PAUSED_UNTIL.save(deps.storage, &Some(paused_until_height))?;
+
+    let modules = PROPOSAL_MODULES
+        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .map(|kv| Ok(kv?.1))
+        .collect::<StdResult<Vec<ProposalModule>>>()?;
+
+    // Disabled proposal modules might still have timelocked proposals,
+    // so we cancel proposals of all timelock contracts we know about.
+    let mut timelocks = vec![];
+    for module in &modules {
+        let timelock = match timelock_from_proposal_module(deps.as_ref(), module) {
+            Ok(timelock) => timelock,
+            // A disabled module might be broken or migrated away, which must not block
+            // the recovery.
+            Err(_) if module.status == ProposalModuleStatus::Disabled => None,
+            Err(err) => return Err(err.into()),
+        };
+        if let Some(timelock) = timelock {
+            timelocks.push(timelock);
+        }
+    }
+    let cancel_msgs = timelocks
+        .iter()
+        .map(|timelock| {
+            Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: timelock.to_string(),
+                msg: to_json_binary(&TimelockExecuteMsg::CancelTimelockedProposals {})?,
+                funds: vec![],
+            }))
+        })
+        .collect::<StdResult<Vec<CosmosMsg<NeutronMsg>>>>()?;
+
+    let old_voting_module = VOTE_MODULE.load(deps.storage)?;
+    let voting_module_msg = SubMsg::reply_on_success(
+        voting_module.into_wasm_msg(env.contract.address.clone()),
+        VOTE_MODULE_UPDATE_REPLY_ID,
+    );
+
+    let mut disabled_modules = vec![];
+    let mut proposal_module_msgs: Vec<SubMsg<NeutronMsg>> = vec![];
+    if let Some(proposal_modules) = proposal_modules {
+        if proposal_modules.is_empty() {
+            return Err(ContractError::NoActiveProposalModules {});
+        }
+
+        for mut module in modules {
+            if module.status == ProposalModuleStatus::Enabled {
+                module.status = ProposalModuleStatus::Disabled;
+                PROPOSAL_MODULES.save(deps.storage, module.address.clone(), &module)?;
+                disabled_modules.push(module.address.to_string());
+            }
+        }
+        // New modules increase the counter once they are instantiated.
+        ACTIVE_PROPOSAL_MODULE_COUNT.save(deps.storage, &0)?;
+
+        proposal_module_msgs = proposal_modules
+            .into_iter()
+            .map(|info| info.into_wasm_msg(env.contract.address.clone()))
+            .map(|wasm| SubMsg::reply_on_success(wasm, PROPOSAL_MODULE_REPLY_ID))
+            .collect();
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_emergency_recovery")
+        .add_attribute("sender", sender)
+        .add_attribute("paused_until_height", paused_until_height.to_string())
+        .add_attribute(
+            "cancelled_timelocks",
+            timelocks
+                .iter()
+                .map(|t| t.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+        .add_attribute("old_voting_module", old_voting_module)
+        .add_attribute("disabled_proposal_modules", disabled_modules.join(","))
+        .add_messages(cancel_msgs)
+        .add_submessage(voting_module_msg)
+        .add_submessages(proposal_module_msgs))
+}
+
 pub fn execute_proposal_hook(
-    deps: Deps,
+    deps: DepsMut,
+    env: Env,
     sender: Addr,
     msgs: Vec<CosmosMsg<NeutronMsg>>,
 ) -> Result<Response<NeutronMsg>, ContractError> {
@@ -188,22 +320,151 @@ pub fn execute_proposal_hook(
         return Err(ContractError::ModuleDisabledCannotExecute { address: sender });
     }
 
+    spend(deps, &env, &msgs)?;
+
     Ok(Response::default()
         .add_attribute("action", "execute_proposal_hook")
         .add_messages(msgs))
 }
 
 pub fn execute_timelocked_msgs(
-    deps: Deps,
-    _env: Env,
+    deps: DepsMut,
+    env: Env,
     sender: Addr,
     msgs: Vec<CosmosMsg<NeutronMsg>>,
 ) -> Result<Response<NeutronMsg>, ContractError> {
-    execution_access_check(deps, sender)?;
+    execution_access_check(deps.as_ref(), sender)?;
+    spend(deps, &env, &msgs)?;
 
     Ok(Response::default().add_messages(msgs))
 }
 
+pub fn execute_update_spending_limits(
+    deps: DepsMut,
+    sender: Addr,
+    to_set: Vec<SpendingLimit>,
+    to_remove: Vec<String>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+    if sender != config.main_dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    for denom in to_remove {
+        SPENDING_LIMITS.remove(deps.storage, denom.clone());
+        SPENT_AMOUNTS.remove(deps.storage, denom);
+    }
+
+    for limit in to_set {
+        limit.validate()?;
+        // Epoch numbers depend on the epoch length, so the spent amount can't be carried over.
+        if let Some(current) = SPENDING_LIMITS.may_load(deps.storage, limit.denom.clone())? {
+            if current.epoch_length != limit.epoch_length {
+                SPENT_AMOUNTS.remove(deps.storage, limit.denom.clone());
+            }
+        }
+        SPENDING_LIMITS.save(deps.storage, limit.denom.clone(), &limit)?;
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_update_spending_limits")
+        .add_attribute("sender", sender))
+}
+
+/// Records the funds sent by the messages against the spending limits of their denoms.
+/// Fails if any of the limits would be exceeded.
+fn spend(deps: DepsMut, env: &Env, msgs: &[CosmosMsg<NeutronMsg>]) -> Result<(), ContractError> {
+    let limited = SPENDING_LIMITS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+
+    let mut amounts: Vec<Coin> = vec![];
+    for msg in msgs {
+        // Messages that might move funds in ways we can't tell are only allowed without limits.
+        let funds = match sent_funds(msg) {
+            Some(funds) => funds,
+            None if limited => {
+                return Err(ContractError::SpendingLimitUnchecked {
+                    kind: msg_kind(msg),
+                })
+            }
+            None => continue,
+        };
+        for coin in funds {
+            match amounts.iter_mut().find(|c| c.denom == coin.denom) {
+                Some(c) => c.amount = c.amount.checked_add(coin.amount)?,
+                None => amounts.push(coin.clone()),
+            }
+        }
+    }
+
+    for coin in amounts {
+        let limit = match SPENDING_LIMITS.may_load(deps.storage, coin.denom.clone())? {
+            Some(limit) => limit,
+            None => continue,
+        };
+
+        let spent_amount = SPENT_AMOUNTS.may_load(deps.storage, coin.denom.clone())?;
+        let spent = limit.spent_at(spent_amount.as_ref(), env.block.height);
+        let new_spent = spent.checked_add(coin.amount)?;
+        if new_spent > limit.amount {
+            return Err(ContractError::SpendingLimitExceeded {
+                denom: coin.denom,
+                remaining: limit.amount.saturating_sub(spent),
+            });
+        }
+
+        SPENT_AMOUNTS.save(
+            deps.storage,
+            coin.denom,
+            &SpentAmount {
+                epoch: limit.epoch(env.block.height),
+                amount: new_spent,
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Returns the funds sent by the message, including the fees of IBC transfers. Returns `None`
+/// for the messages whose funds can't be told, e.g. stargate messages, which would have to be
+/// decoded, and may wrap other messages, like `MsgExec` of authz does.
+fn sent_funds(msg: &CosmosMsg<NeutronMsg>) -> Option<Vec<&Coin>> {
+    match msg {
+        CosmosMsg::Bank(BankMsg::Send { amount, .. })
+        | CosmosMsg::Bank(BankMsg::Burn { amount }) => Some(amount.iter().collect()),
+        CosmosMsg::Wasm(WasmMsg::Execute { funds, .. })
+        | CosmosMsg::Wasm(WasmMsg::Instantiate { funds, .. })
+        | CosmosMsg::Wasm(WasmMsg::Instantiate2 { funds, .. }) => Some(funds.iter().collect()),
+        CosmosMsg::Wasm(WasmMsg::Migrate { .. })
+        | CosmosMsg::Wasm(WasmMsg::UpdateAdmin { .. })
+        | CosmosMsg::Wasm(WasmMsg::ClearAdmin { .. }) => Some(vec![]),
+        CosmosMsg::Ibc(IbcMsg::Transfer { amount, .. }) => Some(vec![amount]),
+        CosmosMsg::Custom(NeutronMsg::IbcTransfer { token, fee, .. }) => Some(
+            std::iter::once(token)
+                .chain(&fee.recv_fee)
+                .chain(&fee.ack_fee)
+                .chain(&fee.timeout_fee)
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Names the kind of a message in errors.
+fn msg_kind(msg: &CosmosMsg<NeutronMsg>) -> String {
+    match msg {
+        CosmosMsg::Stargate { type_url, .. } => type_url.clone(),
+        CosmosMsg::Custom(_) => "Neutron".to_string(),
+        CosmosMsg::Bank(_) => "Bank".to_string(),
+        CosmosMsg::Wasm(_) => "Wasm".to_string(),
+        CosmosMsg::Ibc(_) => "IBC".to_string(),
+        _ => "Staking, distribution and governance".to_string(),
+    }
+}
+
 pub fn execute_update_config(
     deps: DepsMut,
     env: Env,
@@ -404,6 +665,10 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::TimelockProposalModuleAddress { timelock } => {
             query_timelock_proposal_module_address(deps, timelock)
         }
+        QueryMsg::SpendingBudget { denom } => query_spending_budget(deps, env, denom),
+        QueryMsg::SpendingBudgets { start_after, limit } => {
+            query_spending_budgets(deps, env, start_after, limit)
+        }
     }
 }
 
@@ -610,6 +875,42 @@ pub fn query_timelock_proposal_module_address(deps: Deps, timelock: String) -> S
     to_json_binary(&proposal.address)
 }
 
+pub fn query_spending_budget(deps: Deps, env: Env, denom: String) -> StdResult<Binary> {
+    let budget = SPENDING_LIMITS
+        .may_load(deps.storage, denom.clone())?
+        .map(|limit| -> StdResult<_> {
+            let spent = SPENT_AMOUNTS.may_load(deps.storage, denom)?;
+            Ok(SpendingBudgetResponse::new(
+                limit,
+                spent.as_ref(),
+                env.block.height,
+            ))
+        })
+        .transpose()?;
+    to_json_binary(&budget)
+}
+
+pub fn query_spending_budgets(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limits = paginate_map_values(deps, &SPENDING_LIMITS, start_after, limit, Order::Ascending)?;
+    let budgets = limits
+        .into_iter()
+        .map(|limit| {
+            let spent = SPENT_AMOUNTS.may_load(deps.storage, limit.denom.clone())?;
+            Ok(SpendingBudgetResponse::new(
+                limit,
+                spent.as_ref(),
+                env.block.height,
+            ))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    to_json_binary(&budgets)
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
@@ -689,26 +990,37 @@ fn proposal_from_timelock(
         .map(|kv| Ok(kv?.1))
         .collect::<StdResult<Vec<ProposalModule>>>()?;
     for proposal_module in proposal_modules.into_iter() {
-        let policy: ProposalCreationPolicy = deps.querier.query_wasm_smart(
-            &proposal_module.address,
-            &ProposeQueryMsg::ProposalCreationPolicy {},
-        )?;
-        if let ProposalCreationPolicy::Module { addr } = policy {
-            if let Ok(proposal_timelock_contract) = deps.querier.query_wasm_smart::<Addr>(
-                &addr,
-                &PreProposeQueryMsg::QueryExtension {
-                    msg: PreProposeQueryExt::TimelockAddress {},
-                },
-            ) {
-                if timelock_contract == proposal_timelock_contract {
-                    return Ok(Some(proposal_module));
-                }
-            }
-        };
+        if timelock_from_proposal_module(deps, &proposal_module)? == Some(timelock_contract.clone())
+        {
+            return Ok(Some(proposal_module));
+        }
     }
     Ok(None)
 }
 
+/// Returns the timelock contract behind the pre-propose module of a given proposal module.
+/// Returns Ok(None) if the proposal module has no pre-propose module with a timelock.
+fn timelock_from_proposal_module(
+    deps: Deps,
+    proposal_module: &ProposalModule,
+) -> Result<Option<Addr>, StdError> {
+    let policy: ProposalCreationPolicy = deps.querier.query_wasm_smart(
+        &proposal_module.address,
+        &ProposeQueryMsg::ProposalCreationPolicy {},
+    )?;
+    if let ProposalCreationPolicy::Module { addr } = policy {
+        if let Ok(proposal_timelock_contract) = deps.querier.query_wasm_smart::<Addr>(
+            &addr,
+            &PreProposeQueryMsg::QueryExtension {
+                msg: PreProposeQueryExt::TimelockAddress {},
+            },
+        ) {
+            return Ok(Some(proposal_timelock_contract));
+        }
+    };
+    Ok(None)
+}
+
 pub(crate) fn derive_proposal_module_prefix(mut dividend: usize) -> StdResult<String> {
     dividend += 1;
     // Pre-allocate string
@@ -727,9 +1039,366 @@ pub(crate) fn derive_proposal_module_prefix(mut dividend: usize) -> StdResult<St
 
 #[cfg(test)]
 mod test {
-    use crate::contract::derive_proposal_module_prefix;
+    use crate::contract::{
+        derive_proposal_module_prefix, execute, query, PROPOSAL_MODULE_REPLY_ID,
+        VOTE_MODULE_UPDATE_REPLY_ID,
+    };
+    use crate::error::ContractError;
+    use crate::state::{
+        ACTIVE_PROPOSAL_MODULE_COUNT, CONFIG, PAUSED_UNTIL, PROPOSAL_MODULES,
+        TOTAL_PROPOSAL_MODULE_COUNT, VOTE_MODULE,
+    };
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::{
+        coin, coins, from_json, to_json_binary, Addr, Attribute, BankMsg, ContractResult,
+        CosmosMsg, Env, IbcMsg, IbcTimeout, OwnedDeps, SubMsg, SystemError, SystemResult,
+        Timestamp, Uint128, WasmMsg, WasmQuery,
+    };
+    use cwd_interface::ModuleInstantiateInfo;
+    use cwd_voting::pre_propose::ProposalCreationPolicy;
+    use neutron_sdk::bindings::msg::{IbcFee, NeutronMsg};
+    use neutron_sdk::sudo::msg::RequestPacketTimeoutHeight;
+    use neutron_subdao_core::msg::{ExecuteMsg, QueryMsg};
+    use neutron_subdao_core::types::{
+        Config, ProposalModule, ProposalModuleStatus, SpendingBudgetResponse, SpendingLimit,
+    };
+    use neutron_subdao_timelock_single::msg::ExecuteMsg as TimelockExecuteMsg;
     use std::collections::HashSet;
 
+    const MAIN_DAO: &str = "main_dao";
+    const PROPOSAL_MODULE: &str = "proposal_module";
+    const PRE_PROPOSE: &str = "pre_propose";
+    const TIMELOCK: &str = "timelock";
+    const OLD_PROPOSAL_MODULE: &str = "old_proposal_module";
+    const OLD_PRE_PROPOSE: &str = "old_pre_propose";
+    const OLD_TIMELOCK: &str = "old_timelock";
+
+    /// Sets up a subDAO with a single proposal module, whose pre-propose module sends
+    /// proposals to a timelock contract. The querier also knows a module with its own
+    /// timelock, which tests can add to the subDAO.
+    fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+        CONFIG
+            .save(
+                deps.as_mut().storage,
+                &Config {
+                    name: "subdao".to_string(),
+                    description: "subdao".to_string(),
+                    dao_uri: None,
+                    main_dao: Addr::unchecked(MAIN_DAO),
+                    security_dao: Addr::unchecked("security_dao"),
+                },
+            )
+            .unwrap();
+        PAUSED_UNTIL.save(deps.as_mut().storage, &None).unwrap();
+        VOTE_MODULE
+            .save(deps.as_mut().storage, &Addr::unchecked("voting_module"))
+            .unwrap();
+        PROPOSAL_MODULES
+            .save(
+                deps.as_mut().storage,
+                Addr::unchecked(PROPOSAL_MODULE),
+                &ProposalModule {
+                    address: Addr::unchecked(PROPOSAL_MODULE),
+                    prefix: "A".to_string(),
+                    status: ProposalModuleStatus::Enabled,
+                },
+            )
+            .unwrap();
+        TOTAL_PROPOSAL_MODULE_COUNT
+            .save(deps.as_mut().storage, &1)
+            .unwrap();
+        ACTIVE_PROPOSAL_MODULE_COUNT
+            .save(deps.as_mut().storage, &1)
+            .unwrap();
+
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::Smart { contract_addr, .. } => match contract_addr.as_str() {
+                PROPOSAL_MODULE => SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&ProposalCreationPolicy::Module {
+                        addr: Addr::unchecked(PRE_PROPOSE),
+                    })
+                    .unwrap(),
+                )),
+                PRE_PROPOSE => SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&Addr::unchecked(TIMELOCK)).unwrap(),
+                )),
+                OLD_PROPOSAL_MODULE => SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&ProposalCreationPolicy::Module {
+                        addr: Addr::unchecked(OLD_PRE_PROPOSE),
+                    })
+                    .unwrap(),
+                )),
+                OLD_PRE_PROPOSE => SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&Addr::unchecked(OLD_TIMELOCK)).unwrap(),
+                )),
+                _ => SystemResult::Err(SystemError::NoSuchContract {
+                    addr: contract_addr.clone(),
+                }),
+            },
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "wasm".to_string(),
+            }),
+        });
+
+        deps
+    }
+
+    fn bank_send(amount: u128, denom: &str) -> CosmosMsg<NeutronMsg> {
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "recipient".to_string(),
+            amount: coins(amount, denom),
+        })
+    }
+
+    fn set_spending_limit(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        env: &Env,
+        amount: u128,
+        epoch_length: u64,
+    ) {
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MAIN_DAO, &[]),
+            ExecuteMsg::UpdateSpendingLimits {
+                to_set: vec![SpendingLimit {
+                    denom: "untrn".to_string(),
+                    amount: Uint128::new(amount),
+                    epoch_length,
+                }],
+                to_remove: vec![],
+            },
+        )
+        .unwrap();
+    }
+
+    fn spent(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, env: &Env) -> Uint128 {
+        let budget: Option<SpendingBudgetResponse> = from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::SpendingBudget {
+                    denom: "untrn".to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        budget.unwrap().spent
+    }
+
+    #[test]
+    fn test_update_spending_limits() {
+        let mut deps = setup();
+        let mut env = mock_env();
+        env.block.height = 100;
+
+        // Only the main DAO can update the limits, not even the subDAO itself.
+        for sender in ["anyone", env.contract.address.as_str(), PROPOSAL_MODULE] {
+            let err = execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(sender, &[]),
+                ExecuteMsg::UpdateSpendingLimits {
+                    to_set: vec![],
+                    to_remove: vec!["untrn".to_string()],
+                },
+            )
+            .unwrap_err();
+            assert_eq!(err, ContractError::Unauthorized {});
+        }
+
+        set_spending_limit(&mut deps, &env, 100, 10);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(PROPOSAL_MODULE, &[]),
+            ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![bank_send(60, "untrn")],
+            },
+        )
+        .unwrap();
+        assert_eq!(spent(&deps, &env), Uint128::new(60));
+
+        // Changing the amount keeps the spent amount, changing the epoch length resets it.
+        set_spending_limit(&mut deps, &env, 200, 10);
+        assert_eq!(spent(&deps, &env), Uint128::new(60));
+        set_spending_limit(&mut deps, &env, 200, 20);
+        assert_eq!(spent(&deps, &env), Uint128::zero());
+    }
+
+    #[test]
+    fn test_spending_limits() {
+        let mut deps = setup();
+        let mut env = mock_env();
+        env.block.height = 100;
+        set_spending_limit(&mut deps, &env, 100, 10);
+
+        // Proposal modules and timelocks share the budget of the epoch.
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(PROPOSAL_MODULE, &[]),
+            ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![bank_send(30, "untrn"), bank_send(1000, "uatom")],
+            },
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(TIMELOCK, &[]),
+            ExecuteMsg::ExecuteTimelockedMsgs {
+                msgs: vec![CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: "contract".to_string(),
+                    msg: to_json_binary(&"msg").unwrap(),
+                    funds: coins(30, "untrn"),
+                })],
+            },
+        )
+        .unwrap();
+        assert_eq!(spent(&deps, &env), Uint128::new(60));
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(PROPOSAL_MODULE, &[]),
+            ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![bank_send(30, "untrn"), bank_send(11, "untrn")],
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::SpendingLimitExceeded {
+                denom: "untrn".to_string(),
+                remaining: Uint128::new(40),
+            }
+        );
+
+        // Every kind of message sending funds counts against the limit.
+        let over_limit_msgs: Vec<CosmosMsg<NeutronMsg>> = vec![
+            CosmosMsg::Wasm(WasmMsg::Instantiate {
+                admin: None,
+                code_id: 1,
+                msg: to_json_binary(&"msg").unwrap(),
+                funds: coins(41, "untrn"),
+                label: "label".to_string(),
+            }),
+            CosmosMsg::Wasm(WasmMsg::Instantiate2 {
+                admin: None,
+                code_id: 1,
+                label: "label".to_string(),
+                msg: to_json_binary(&"msg").unwrap(),
+                funds: coins(41, "untrn"),
+                salt: to_json_binary(&"salt").unwrap(),
+            }),
+            CosmosMsg::Ibc(IbcMsg::Transfer {
+                channel_id: "channel-0".to_string(),
+                to_address: "recipient".to_string(),
+                amount: coin(41, "untrn"),
+                timeout: IbcTimeout::with_timestamp(Timestamp::from_seconds(1)),
+            }),
+            CosmosMsg::Custom(NeutronMsg::IbcTransfer {
+                source_port: "transfer".to_string(),
+                source_channel: "channel-0".to_string(),
+                sender: "subdao".to_string(),
+                receiver: "recipient".to_string(),
+                token: coin(40, "untrn"),
+                timeout_height: RequestPacketTimeoutHeight {
+                    revision_number: None,
+                    revision_height: None,
+                },
+                timeout_timestamp: 1,
+                memo: String::new(),
+                fee: IbcFee {
+                    recv_fee: vec![],
+                    ack_fee: coins(1, "untrn"),
+                    timeout_fee: vec![],
+                },
+            }),
+        ];
+        for msg in over_limit_msgs {
+            let err = execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(TIMELOCK, &[]),
+                ExecuteMsg::ExecuteTimelockedMsgs { msgs: vec![msg] },
+            )
+            .unwrap_err();
+            assert_eq!(
+                err,
+                ContractError::SpendingLimitExceeded {
+                    denom: "untrn".to_string(),
+                    remaining: Uint128::new(40),
+                }
+            );
+        }
+
+        // Stargate and other Neutron messages can't be checked, so they are rejected while a
+        // limit is set. That includes contract calls with funds and authz executions, which
+        // need no grant when the subDAO executes on its own behalf.
+        let unchecked_msgs: Vec<(CosmosMsg<NeutronMsg>, &str)> = vec![
+            (
+                CosmosMsg::Stargate {
+                    type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                    value: Default::default(),
+                },
+                "/cosmos.bank.v1beta1.MsgSend",
+            ),
+            (
+                CosmosMsg::Stargate {
+                    type_url: "/cosmwasm.wasm.v1.MsgExecuteContract".to_string(),
+                    value: Default::default(),
+                },
+                "/cosmwasm.wasm.v1.MsgExecuteContract",
+            ),
+            (
+                CosmosMsg::Stargate {
+                    type_url: "/cosmos.authz.v1beta1.MsgExec".to_string(),
+                    value: Default::default(),
+                },
+                "/cosmos.authz.v1beta1.MsgExec",
+            ),
+            (
+                CosmosMsg::Custom(NeutronMsg::remove_interchain_query(1)),
+                "Neutron",
+            ),
+        ];
+        for (msg, kind) in unchecked_msgs {
+            let err = execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(PROPOSAL_MODULE, &[]),
+                ExecuteMsg::ExecuteProposalHook { msgs: vec![msg] },
+            )
+            .unwrap_err();
+            assert_eq!(
+                err,
+                ContractError::SpendingLimitUnchecked {
+                    kind: kind.to_string(),
+                }
+            );
+        }
+
+        // The budget is reset once the epoch is over.
+        env.block.height = 110;
+        assert_eq!(spent(&deps, &env), Uint128::zero());
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(PROPOSAL_MODULE, &[]),
+            ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![bank_send(100, "untrn")],
+            },
+        )
+        .unwrap();
+        assert_eq!(spent(&deps, &env), Uint128::new(100));
+    }
+
     #[test]
     fn test_prefix_generation() {
         assert_eq!("A", derive_proposal_module_prefix(0).unwrap());
@@ -760,4 +1429,207 @@ mod test {
             seen.insert(prefix);
         }
     }
+
+    fn module_info(label: &str) -> ModuleInstantiateInfo {
+        ModuleInstantiateInfo {
+            code_id: 1,
+            msg: to_json_binary(&"msg").unwrap(),
+            admin: None,
+            label: label.to_string(),
+        }
+    }
+
+    fn emergency_recovery(proposal_modules: Option<Vec<ModuleInstantiateInfo>>) -> ExecuteMsg {
+        ExecuteMsg::EmergencyRecovery {
+            pause_duration: 10,
+            voting_module: module_info("voting_module"),
+            proposal_modules,
+        }
+    }
+
+    fn add_disabled_module(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        address: &str,
+        prefix: &str,
+    ) {
+        PROPOSAL_MODULES
+            .save(
+                deps.as_mut().storage,
+                Addr::unchecked(address),
+                &ProposalModule {
+                    address: Addr::unchecked(address),
+                    prefix: prefix.to_string(),
+                    status: ProposalModuleStatus::Disabled,
+                },
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_emergency_recovery_access() {
+        let mut deps = setup();
+        let env = mock_env();
+
+        // Only the main DAO can recover the subDAO, not even the security DAO.
+        for sender in [
+            "security_dao",
+            "anyone",
+            PROPOSAL_MODULE,
+            env.contract.address.as_str(),
+        ] {
+            let err = execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(sender, &[]),
+                emergency_recovery(None),
+            )
+            .unwrap_err();
+            assert_eq!(err, ContractError::Unauthorized {});
+        }
+        assert_eq!(PAUSED_UNTIL.load(deps.as_ref().storage).unwrap(), None);
+    }
+
+    #[test]
+    fn test_emergency_recovery_while_paused() {
+        let mut deps = setup();
+        let env = mock_env();
+        let height = env.block.height;
+
+        // A longer pause is kept.
+        PAUSED_UNTIL
+            .save(deps.as_mut().storage, &Some(height + 100))
+            .unwrap();
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MAIN_DAO, &[]),
+            emergency_recovery(None),
+        )
+        .unwrap();
+        assert!(res.attributes.contains(&Attribute::new(
+            "paused_until_height",
+            (height + 100).to_string()
+        )));
+        assert_eq!(
+            PAUSED_UNTIL.load(deps.as_ref().storage).unwrap(),
+            Some(height + 100)
+        );
+
+        // A shorter pause is extended.
+        PAUSED_UNTIL
+            .save(deps.as_mut().storage, &Some(height + 5))
+            .unwrap();
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info(MAIN_DAO, &[]),
+            emergency_recovery(None),
+        )
+        .unwrap();
+        assert_eq!(
+            PAUSED_UNTIL.load(deps.as_ref().storage).unwrap(),
+            Some(height + 10)
+        );
+    }
+
+    #[test]
+    fn test_emergency_recovery_cancels_all_timelocks() {
+        let mut deps = setup();
+        let env = mock_env();
+        // A disabled module keeps its timelock, and a disabled module that can't be
+        // queried anymore is skipped.
+        add_disabled_module(&mut deps, OLD_PROPOSAL_MODULE, "B");
+        add_disabled_module(&mut deps, "broken_proposal_module", "C");
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MAIN_DAO, &[]),
+            emergency_recovery(None),
+        )
+        .unwrap();
+
+        let cancel_msg = |timelock: &str| {
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: timelock.to_string(),
+                msg: to_json_binary(&TimelockExecuteMsg::CancelTimelockedProposals {}).unwrap(),
+                funds: vec![],
+            })
+        };
+        let expected: Vec<SubMsg<NeutronMsg>> = vec![
+            cancel_msg(OLD_TIMELOCK),
+            cancel_msg(TIMELOCK),
+            SubMsg::reply_on_success(
+                module_info("voting_module").into_wasm_msg(env.contract.address),
+                VOTE_MODULE_UPDATE_REPLY_ID,
+            ),
+        ];
+        assert_eq!(res.messages, expected);
+        assert!(res.attributes.contains(&Attribute::new(
+            "cancelled_timelocks",
+            format!("{},{}", OLD_TIMELOCK, TIMELOCK)
+        )));
+
+        // Proposal modules are kept unless new ones are given.
+        let module = PROPOSAL_MODULES
+            .load(deps.as_ref().storage, Addr::unchecked(PROPOSAL_MODULE))
+            .unwrap();
+        assert_eq!(module.status, ProposalModuleStatus::Enabled);
+        assert_eq!(
+            ACTIVE_PROPOSAL_MODULE_COUNT
+                .load(deps.as_ref().storage)
+                .unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_emergency_recovery_replaces_proposal_modules() {
+        let mut deps = setup();
+        let env = mock_env();
+        add_disabled_module(&mut deps, OLD_PROPOSAL_MODULE, "B");
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MAIN_DAO, &[]),
+            emergency_recovery(Some(vec![])),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NoActiveProposalModules {});
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(MAIN_DAO, &[]),
+            emergency_recovery(Some(vec![module_info("proposal_module")])),
+        )
+        .unwrap();
+
+        // Only the enabled modules are disabled, and the new modules increase the
+        // counter once they are instantiated.
+        assert!(res.attributes.contains(&Attribute::new(
+            "disabled_proposal_modules",
+            PROPOSAL_MODULE
+        )));
+        for address in [PROPOSAL_MODULE, OLD_PROPOSAL_MODULE] {
+            let module = PROPOSAL_MODULES
+                .load(deps.as_ref().storage, Addr::unchecked(address))
+                .unwrap();
+            assert_eq!(module.status, ProposalModuleStatus::Disabled);
+        }
+        assert_eq!(
+            ACTIVE_PROPOSAL_MODULE_COUNT
+                .load(deps.as_ref().storage)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            res.messages.last().unwrap(),
+            &SubMsg::reply_on_success(
+                module_info("proposal_module").into_wasm_msg(env.contract.address),
+                PROPOSAL_MODULE_REPLY_ID,
+            )
+        );
+    }
 }
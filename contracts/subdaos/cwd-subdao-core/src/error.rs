@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, StdError};
+use cosmwasm_std::{Addr, OverflowError, StdError, Uint128};
 use cw_utils::ParseReplyError;
 use exec_control::pause::PauseError;
 use neutron_subdao_core::error::ContractError as BaseContractError;
@@ -18,6 +18,9 @@ pub enum ContractError {
     #[error(transparent)]
     BaseContractError(#[from] BaseContractError),
 
+    #[error(transparent)]
+    OverflowError(#[from] OverflowError),
+
     #[error("Unauthorized.")]
     Unauthorized {},
 
@@ -41,4 +44,10 @@ pub enum ContractError {
 
     #[error("Proposal module with address is disabled and cannot execute messages.")]
     ModuleDisabledCannotExecute { address: Addr },
+
+    #[error("Spending limit of {denom} is exceeded, remaining budget is {remaining}.")]
+    SpendingLimitExceeded { denom: String, remaining: Uint128 },
+
+    #[error("{kind} messages cannot be checked against the spending limits.")]
+    SpendingLimitUnchecked { kind: String },
 }
@@ -1,6 +1,6 @@
 use cosmwasm_std::Addr;
 use cw_storage_plus::{Item, Map};
-use neutron_subdao_core::types::{Config, ProposalModule};
+use neutron_subdao_core::types::{Config, ProposalModule, SpendingLimit, SpentAmount};
 
 /// The current configuration of the module.
 pub const CONFIG: Item<Config> = Item::new("config_v2");
@@ -27,3 +27,9 @@ pub const ITEMS: Map<String, String> = Map::new("items");
 
 /// List of SubDAOs associated to this DAO. Each SubDAO has an optional charter.
 pub const SUBDAO_LIST: Map<&Addr, Option<String>> = Map::new("sub_daos");
+
+/// Per-denom caps on the funds the subDAO can spend per epoch.
+pub const SPENDING_LIMITS: Map<String, SpendingLimit> = Map::new("spending_limits");
+
+/// Per-denom amounts spent by the subDAO during the last epoch it spent them in.
+pub const SPENT_AMOUNTS: Map<String, SpentAmount> = Map::new("spent_amounts");
@@ -1,11 +1,12 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply,
-    Response, StdError, StdResult, SubMsg, WasmMsg,
+    from_json, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env,
+    MessageInfo, Order, Reply, Response, StdError, StdResult, Storage, SubMsg, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw_storage_plus::Bound;
+use cw_utils::Duration;
 use cwd_proposal_single::{
     msg::QueryMsg as MainDaoProposalModuleQueryMsg,
     query::ProposalResponse as MainDaoProposalResponse,
@@ -19,19 +20,34 @@ use neutron_sdk::bindings::msg::NeutronMsg;
 use neutron_subdao_core::msg::ExecuteMsg as CoreExecuteMsg;
 use neutron_subdao_core::msg::QueryMsg as SubdaoQuery;
 use neutron_subdao_pre_propose_single::msg::QueryMsg as PreProposeQuery;
-use neutron_subdao_proposal_single::msg::QueryMsg as ProposalQueryMsg;
+use neutron_subdao_proposal_single::msg::{
+    ExecuteMsg as ProposalExecuteMsg, QueryMsg as ProposalQueryMsg,
+};
 use neutron_subdao_proposal_single::types::Config as ProposalConfig;
 use neutron_subdao_timelock_single::{
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
-    types::{Config, ProposalListResponse, ProposalStatus, SingleChoiceProposal},
+    types::{
+        BountyBalanceResponse, Config, MessageStatus, ProposalListResponse, ProposalMessage,
+        ProposalMessagesResponse, ProposalStatus, SingleChoiceProposal,
+    },
 };
 
 use crate::error::ContractError;
-use crate::state::{CONFIG, DEFAULT_LIMIT, PROPOSALS, PROPOSAL_EXECUTION_ERRORS};
+use crate::state::{
+    CONFIG, DEFAULT_LIMIT, PROPOSALS, PROPOSALS_BY_STATUS, PROPOSAL_COUNTS,
+    PROPOSAL_EXECUTION_ERRORS, PROPOSAL_MSG_EXECUTION_ERRORS,
+};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:cwd-subdao-timelock-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Every message of a proposal is executed as a separate submessage, so the reply id
+/// carries both the proposal id and the message index. The lowest bits hold the index.
+const BITS_RESERVED_FOR_MSG_INDEX: u8 = 16;
+const MSG_INDEX_MASK: u64 = (1 << BITS_RESERVED_FOR_MSG_INDEX) - 1;
+/// Maximum number of messages inside `ExecuteTimelockedMsgs` of a single proposal.
+const MAX_PROPOSAL_MSGS: usize = 1 << BITS_RESERVED_FOR_MSG_INDEX;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -60,6 +76,9 @@ pub fn instantiate(
         owner: main_dao,
         overrule_pre_propose,
         subdao: subdao_core,
+        min_timelock_duration: msg.min_timelock_duration.filter(|d| !is_zero_duration(d)),
+        execution_bounty: None,
+        execution_deadline: msg.execution_deadline.filter(|d| !is_zero_duration(d)),
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -90,10 +109,31 @@ pub fn execute(
         ExecuteMsg::OverruleProposal { proposal_id } => {
             execute_overrule_proposal(deps, info, proposal_id)
         }
+        ExecuteMsg::RetryFailedMessages { proposal_id } => {
+            execute_retry_failed_messages(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::UpdateExecutionBounty { execution_bounty } => {
+            execute_update_execution_bounty(deps, info, execution_bounty)
+        }
         ExecuteMsg::UpdateConfig {
             owner,
             overrule_pre_propose,
-        } => execute_update_config(deps, info, owner, overrule_pre_propose),
+            min_timelock_duration,
+            execution_deadline,
+        } => execute_update_config(
+            deps,
+            info,
+            owner,
+            overrule_pre_propose,
+            min_timelock_duration,
+            execution_deadline,
+        ),
+        ExecuteMsg::ExpireProposal { proposal_id } => {
+            execute_expire_proposal(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::CancelTimelockedProposals {} => {
+            execute_cancel_timelocked_proposals(deps, env, info)
+        }
     }
 }
 
@@ -112,14 +152,32 @@ pub fn execute_timelock_proposal(
 
     // We expect only one specific message `ExecuteMsg::ExecuteTimelockedMsgs` inside
     let msg = verify_msg(msgs)?;
+    let (_, inner_msgs) = unwrap_timelocked_msgs(&msg)?;
+    if inner_msgs.len() > MAX_PROPOSAL_MSGS {
+        return Err(ContractError::TooManyMessages {
+            len: inner_msgs.len(),
+            max: MAX_PROPOSAL_MSGS,
+        });
+    }
 
     let proposal = SingleChoiceProposal {
         id: proposal_id,
         msgs: vec![msg],
         status: ProposalStatus::Timelocked,
+        timelocked_until: config
+            .min_timelock_duration
+            .map(|duration| duration.after(&env.block)),
+        msg_statuses: vec![MessageStatus::Pending; inner_msgs.len()],
+        execution_deadline: config
+            .execution_deadline
+            .map(|duration| duration.after(&env.block)),
     };
 
+    let previous_status = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .map(|p| p.status);
     PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+    update_proposal_status(deps.storage, proposal_id, previous_status, proposal.status)?;
 
     let create_overrule_proposal = WasmMsg::Execute {
         contract_addr: config.overrule_pre_propose.to_string(),
@@ -159,44 +217,196 @@ pub fn execute_execute_proposal(
         });
     }
 
-    if !is_overrule_proposal_declined(&deps, &env, &config.overrule_pre_propose, proposal.id)? {
+    // Stale proposals must not be executed against a possibly different chain state
+    if is_execution_deadline_passed(&env, &proposal) {
+        return Err(ContractError::ExecutionDeadlinePassed { id: proposal_id });
+    }
+
+    // The minimum timelock duration applies on top of the overrule outcome
+    if let Some(until) = proposal.timelocked_until {
+        if !until.is_expired(&env.block) {
+            return Err(ContractError::MinTimelockNotElapsed { until });
+        }
+    }
+
+    let overrule_proposal_module =
+        query_overrule_proposal_module(deps.as_ref(), &config.overrule_pre_propose)?;
+    if !is_overrule_proposal_declined(
+        deps.as_ref(),
+        &env,
+        &config.overrule_pre_propose,
+        &overrule_proposal_module,
+        proposal.id,
+    )? {
         return Err(ContractError::TimeLocked {});
     }
 
     // Update proposal status
     proposal.status = ProposalStatus::Executed;
+    let msgs = prepare_msgs_execution(&mut proposal, MessageStatus::Pending)?;
     PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+    update_proposal_status(
+        deps.storage,
+        proposal_id,
+        Some(ProposalStatus::Timelocked),
+        proposal.status,
+    )?;
 
-    let response: Response<NeutronMsg> = {
-        // In order to get config.close_proposal_on_execution_failure on proposal module,
-        // we have to query subdao to get proposal module address and then it's config
-        let proposal_module: Addr = deps.querier.query_wasm_smart(
-            config.subdao,
-            &SubdaoQuery::TimelockProposalModuleAddress {
-                timelock: env.contract.address.to_string(),
-            },
-        )?;
-        let proposal_config: ProposalConfig = deps
-            .querier
-            .query_wasm_smart(proposal_module, &ProposalQueryMsg::Config {})?;
-
-        // We expect only one specific message `ExecuteMsg::ExecuteTimelockedMsgs` inside
-        let msg = verify_msg(proposal.msgs)?;
+    let execution_bounty = config.execution_bounty.clone();
+    let mut response = execution_response(&deps, &env, config, proposal_id, msgs)?;
 
-        match proposal_config.close_proposal_on_execution_failure {
-            true => Response::default().add_submessage(SubMsg::reply_on_error(msg, proposal_id)),
-            false => Response::default().add_message(msg),
+    // The bounty is only paid while the contract has enough funds for it,
+    // the proposal is executed either way.
+    if let Some(bounty) = execution_bounty {
+        let balance = deps
+            .querier
+            .query_balance(&env.contract.address, &bounty.denom)?;
+        if balance.amount >= bounty.amount {
+            response = response
+                .add_message(BankMsg::Send {
+                    to_address: info.sender.to_string(),
+                    amount: vec![bounty.clone()],
+                })
+                .add_attribute("execution_bounty", bounty.to_string());
         }
-    };
+    }
 
-    // Note: we add the proposal messages as submessages to change the status to ExecutionFailed
-    // in the reply handler if any of the submessages fail.
     Ok(response
         .add_attribute("action", "execute_proposal")
         .add_attribute("sender", info.sender)
         .add_attribute("proposal_id", proposal_id.to_string()))
 }
 
+pub fn execute_retry_failed_messages(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+
+    // Only the failed remainder of an already executed proposal can be retried
+    if proposal.status != ProposalStatus::ExecutionFailed {
+        return Err(ContractError::WrongStatus {
+            status: proposal.status.to_string(),
+        });
+    }
+
+    // The deadline applies to retries too, since they run against the current chain state
+    if is_execution_deadline_passed(&env, &proposal) {
+        return Err(ContractError::RetryDeadlinePassed { id: proposal_id });
+    }
+
+    proposal.status = ProposalStatus::Executed;
+    let msgs = prepare_msgs_execution(&mut proposal, MessageStatus::ExecutionFailed)?;
+    if msgs.is_empty() {
+        return Err(ContractError::NoFailedMessages { id: proposal_id });
+    }
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+    update_proposal_status(
+        deps.storage,
+        proposal_id,
+        Some(ProposalStatus::ExecutionFailed),
+        proposal.status,
+    )?;
+    // Errors of the retried messages are written again by the reply handler if they fail
+    for (index, _) in msgs.iter() {
+        PROPOSAL_MSG_EXECUTION_ERRORS.remove(deps.storage, (proposal_id, *index));
+    }
+
+    let retried_msgs = msgs
+        .iter()
+        .map(|(index, _)| index.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+    let response = execution_response(&deps, &env, config, proposal_id, msgs)?;
+
+    Ok(response
+        .add_attribute("action", "retry_failed_messages")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("retried_msgs", retried_msgs))
+}
+
+/// Marks the proposal messages with the given `status` as executed and wraps each of them
+/// into a separate `ExecuteTimelockedMsgs` message, so they can fail independently.
+fn prepare_msgs_execution(
+    proposal: &mut SingleChoiceProposal,
+    status: MessageStatus,
+) -> Result<Vec<(u32, CosmosMsg<NeutronMsg>)>, ContractError> {
+    // We expect only one specific message `ExecuteMsg::ExecuteTimelockedMsgs` inside
+    let msg = verify_msg(proposal.msgs.clone())?;
+    let (subdao_core, inner_msgs) = unwrap_timelocked_msgs(&msg)?;
+
+    // proposals timelocked before per-message statuses were introduced have none stored
+    if proposal.msg_statuses.len() != inner_msgs.len() {
+        proposal.msg_statuses = vec![MessageStatus::Pending; inner_msgs.len()];
+    }
+
+    let mut msgs = vec![];
+    for (index, inner_msg) in inner_msgs.into_iter().enumerate() {
+        if proposal.msg_statuses[index] != status {
+            continue;
+        }
+        proposal.msg_statuses[index] = MessageStatus::Executed;
+        msgs.push((
+            index as u32,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: subdao_core.clone(),
+                msg: to_json_binary(&CoreExecuteMsg::ExecuteTimelockedMsgs {
+                    msgs: vec![inner_msg],
+                })?,
+                funds: vec![],
+            }),
+        ));
+    }
+
+    Ok(msgs)
+}
+
+fn execution_response(
+    deps: &DepsMut,
+    env: &Env,
+    config: Config,
+    proposal_id: u64,
+    msgs: Vec<(u32, CosmosMsg<NeutronMsg>)>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    // In order to get config.close_proposal_on_execution_failure on proposal module,
+    // we have to query subdao to get proposal module address and then it's config
+    let proposal_module = query_proposal_module(deps.as_ref(), env, &config.subdao)?;
+    let proposal_config: ProposalConfig = deps
+        .querier
+        .query_wasm_smart(proposal_module, &ProposalQueryMsg::Config {})?;
+
+    // Note: we add the proposal messages as submessages to change the status of the failed
+    // message and the proposal to ExecutionFailed in the reply handler.
+    Ok(match proposal_config.close_proposal_on_execution_failure {
+        true => Response::default().add_submessages(msgs.into_iter().map(|(index, msg)| {
+            SubMsg::reply_on_error(msg, mask_msg_execution_reply_id(proposal_id, index))
+        })),
+        false => Response::default().add_messages(msgs.into_iter().map(|(_, msg)| msg)),
+    })
+}
+
+/// This function can drop bits, if you have more than `u(64-[`BITS_RESERVED_FOR_MSG_INDEX`])` proposals.
+pub(crate) const fn mask_msg_execution_reply_id(proposal_id: u64, msg_index: u32) -> u64 {
+    (proposal_id << BITS_RESERVED_FOR_MSG_INDEX) | (msg_index as u64 & MSG_INDEX_MASK)
+}
+
+/// Returns the proposal id and the message index packed into the reply id.
+const fn unmask_msg_execution_reply_id(id: u64) -> (u64, u32) {
+    (
+        id >> BITS_RESERVED_FOR_MSG_INDEX,
+        (id & MSG_INDEX_MASK) as u32,
+    )
+}
+
+fn is_zero_duration(duration: &Duration) -> bool {
+    matches!(duration, Duration::Height(0) | Duration::Time(0))
+}
+
 /// `verify_msg` checks that there is only one message inside `msgs`
 /// and verifies type inside of `CoreExecuteMsg::ExecuteTimelockedMsgs`
 fn verify_msg(msgs: Vec<CosmosMsg<NeutronMsg>>) -> Result<CosmosMsg<NeutronMsg>, ContractError> {
@@ -211,19 +421,89 @@ fn verify_msg(msgs: Vec<CosmosMsg<NeutronMsg>>) -> Result<CosmosMsg<NeutronMsg>,
         .ok_or(ContractError::CanOnlyExecuteOneMsg { len: msgs_len })?;
 
     // Expect only `ExecuteMsg::ExecuteTimelockedMsgs`
-    match &msg {
-        &CosmosMsg::Wasm(WasmMsg::Execute {
-            msg: ref core_execute_msg,
-            contract_addr: _,
+    unwrap_timelocked_msgs(&msg)?;
+
+    Ok(msg)
+}
+
+/// `unwrap_timelocked_msgs` returns the subDAO core address and the messages
+/// wrapped into `CoreExecuteMsg::ExecuteTimelockedMsgs`
+fn unwrap_timelocked_msgs(
+    msg: &CosmosMsg<NeutronMsg>,
+) -> Result<(String, Vec<CosmosMsg<NeutronMsg>>), ContractError> {
+    match msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            msg: core_execute_msg,
+            contract_addr,
             funds: _,
         }) => match from_json::<CoreExecuteMsg>(core_execute_msg) {
-            Ok(CoreExecuteMsg::ExecuteTimelockedMsgs { msgs: _ }) => {}
-            _ => return Err(ContractError::CanOnlyExecuteExecuteTimelockedMsgs {}),
+            Ok(CoreExecuteMsg::ExecuteTimelockedMsgs { msgs }) => Ok((contract_addr.clone(), msgs)),
+            _ => Err(ContractError::CanOnlyExecuteExecuteTimelockedMsgs {}),
         },
-        _ => return Err(ContractError::CanOnlyExecuteExecuteTimelockedMsgs {}),
+        _ => Err(ContractError::CanOnlyExecuteExecuteTimelockedMsgs {}),
     }
+}
 
-    Ok(msg)
+pub fn execute_expire_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+
+    // Check if proposal is timelocked
+    if proposal.status != ProposalStatus::Timelocked {
+        return Err(ContractError::WrongStatus {
+            status: proposal.status.to_string(),
+        });
+    }
+
+    if !is_execution_deadline_passed(&env, &proposal) {
+        return Err(ContractError::ExecutionDeadlineNotPassed { id: proposal_id });
+    }
+
+    // Update proposal status
+    proposal.status = ProposalStatus::Expired;
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+    update_proposal_status(
+        deps.storage,
+        proposal_id,
+        Some(ProposalStatus::Timelocked),
+        proposal.status,
+    )?;
+
+    // Let the proposal module update its proposal status, so its hooks see the expiry too
+    let proposal_module = query_proposal_module(deps.as_ref(), &env, &config.subdao)?;
+    let notify_proposal_module = WasmMsg::Execute {
+        contract_addr: proposal_module.to_string(),
+        msg: to_json_binary(&ProposalExecuteMsg::TimelockedProposalExpired { proposal_id })?,
+        funds: vec![],
+    };
+
+    Ok(Response::default()
+        .add_message(notify_proposal_module)
+        .add_attribute("action", "expire_proposal")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+fn is_execution_deadline_passed(env: &Env, proposal: &SingleChoiceProposal) -> bool {
+    proposal
+        .execution_deadline
+        .is_some_and(|deadline| deadline.is_expired(&env.block))
+}
+
+/// Returns the subDAO proposal module this timelock belongs to.
+fn query_proposal_module(deps: Deps, env: &Env, subdao: &Addr) -> StdResult<Addr> {
+    deps.querier.query_wasm_smart(
+        subdao,
+        &SubdaoQuery::TimelockProposalModuleAddress {
+            timelock: env.contract.address.to_string(),
+        },
+    )
 }
 
 pub fn execute_overrule_proposal(
@@ -240,16 +520,26 @@ pub fn execute_overrule_proposal(
 
     let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
 
-    // Check if proposal is timelocked
+    // A proposal that is no longer timelocked is skipped rather than failing, since an
+    // overrule proposal can overrule several proposals, and the others still need to be
+    // overruled.
     if proposal.status != ProposalStatus::Timelocked {
-        return Err(ContractError::WrongStatus {
-            status: proposal.status.to_string(),
-        });
+        return Ok(Response::default()
+            .add_attribute("action", "overrule_proposal")
+            .add_attribute("sender", info.sender)
+            .add_attribute("proposal_id", proposal_id.to_string())
+            .add_attribute("skipped_status", proposal.status.to_string()));
     }
 
     // Update proposal status
     proposal.status = ProposalStatus::Overruled;
     PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+    update_proposal_status(
+        deps.storage,
+        proposal_id,
+        Some(ProposalStatus::Timelocked),
+        proposal.status,
+    )?;
 
     Ok(Response::default()
         .add_attribute("action", "overrule_proposal")
@@ -257,11 +547,83 @@ pub fn execute_overrule_proposal(
         .add_attribute("proposal_id", proposal_id.to_string()))
 }
 
+pub fn execute_cancel_timelocked_proposals(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only the subDAO can cancel its proposals.
+    if config.subdao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let timelocked_ids = PROPOSALS_BY_STATUS
+        .prefix(ProposalStatus::Timelocked.to_string())
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u64>>>()?;
+
+    // Let the proposal module update its proposal statuses, as it's done on expiry
+    let proposal_module = query_proposal_module(deps.as_ref(), &env, &config.subdao)?;
+    let mut notify_proposal_module = Vec::with_capacity(timelocked_ids.len());
+    let mut cancelled_ids = Vec::with_capacity(timelocked_ids.len());
+    for proposal_id in timelocked_ids {
+        let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+        proposal.status = ProposalStatus::Overruled;
+        PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+        update_proposal_status(
+            deps.storage,
+            proposal_id,
+            Some(ProposalStatus::Timelocked),
+            proposal.status,
+        )?;
+        notify_proposal_module.push(WasmMsg::Execute {
+            contract_addr: proposal_module.to_string(),
+            msg: to_json_binary(&ProposalExecuteMsg::TimelockedProposalExpired { proposal_id })?,
+            funds: vec![],
+        });
+        cancelled_ids.push(proposal_id.to_string());
+    }
+
+    Ok(Response::default()
+        .add_messages(notify_proposal_module)
+        .add_attribute("action", "cancel_timelocked_proposals")
+        .add_attribute("sender", info.sender)
+        .add_attribute("cancelled_proposal_ids", cancelled_ids.join(",")))
+}
+
+pub fn execute_update_execution_bounty(
+    deps: DepsMut,
+    info: MessageInfo,
+    execution_bounty: Option<Coin>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let mut config: Config = CONFIG.load(deps.storage)?;
+    // The bounty is paid from the funds provided by the subDAO, so the subDAO can manage it too
+    if info.sender != config.owner && info.sender != config.subdao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    config.execution_bounty = execution_bounty.filter(|bounty| !bounty.amount.is_zero());
+
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new()
+        .add_attribute("action", "update_execution_bounty")
+        .add_attribute(
+            "execution_bounty",
+            config
+                .execution_bounty
+                .map_or("none".to_string(), |bounty| bounty.to_string()),
+        ))
+}
+
 pub fn execute_update_config(
     deps: DepsMut,
     info: MessageInfo,
     new_owner: Option<String>,
     new_overrule_pre_propose: Option<String>,
+    new_min_timelock_duration: Option<Duration>,
+    new_execution_deadline: Option<Duration>,
 ) -> Result<Response<NeutronMsg>, ContractError> {
     let mut config: Config = CONFIG.load(deps.storage)?;
     if info.sender != config.owner {
@@ -280,6 +642,16 @@ pub fn execute_update_config(
         config.overrule_pre_propose = deps.api.addr_validate(&overrule_pre_propose)?;
     }
 
+    if let Some(min_timelock_duration) = new_min_timelock_duration {
+        // zero duration disables the minimum timelock
+        config.min_timelock_duration = Some(min_timelock_duration).filter(|d| !is_zero_duration(d));
+    }
+
+    if let Some(execution_deadline) = new_execution_deadline {
+        // zero duration disables the execution deadline
+        config.execution_deadline = Some(execution_deadline).filter(|d| !is_zero_duration(d));
+    }
+
     CONFIG.save(deps.storage, &config)?;
     Ok(Response::new()
         .add_attribute("action", "update_config")
@@ -287,21 +659,83 @@ pub fn execute_update_config(
         .add_attribute(
             "overrule_pre_propose",
             config.overrule_pre_propose.to_string(),
+        )
+        .add_attribute(
+            "min_timelock_duration",
+            config
+                .min_timelock_duration
+                .map_or("none".to_string(), |d| d.to_string()),
+        )
+        .add_attribute(
+            "execution_deadline",
+            config
+                .execution_deadline
+                .map_or("none".to_string(), |d| d.to_string()),
         ))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
         QueryMsg::Proposal { proposal_id } => query_proposal(deps, proposal_id),
-        QueryMsg::ListProposals { start_after, limit } => {
-            query_list_proposals(deps, start_after, limit)
+        QueryMsg::ListProposals {
+            start_after,
+            limit,
+            status,
+        } => query_list_proposals(deps, start_after, limit, status),
+        QueryMsg::ReverseProposals {
+            start_before,
+            limit,
+            status,
+        } => query_reverse_proposals(deps, start_before, limit, status),
+        QueryMsg::ExecutableProposals { start_after, limit } => {
+            query_executable_proposals(deps, env, start_after, limit)
         }
         QueryMsg::ProposalExecutionError { proposal_id } => {
             query_proposal_execution_error(deps, proposal_id)
         }
+        QueryMsg::ProposalMessages { proposal_id } => query_proposal_messages(deps, proposal_id),
+        QueryMsg::BountyBalance {} => query_bounty_balance(deps, env),
+        QueryMsg::ProposalCount { status } => query_proposal_count(deps, status),
+    }
+}
+
+pub fn query_proposal_count(deps: Deps, status: Option<ProposalStatus>) -> StdResult<Binary> {
+    let count = match status {
+        Some(status) => PROPOSAL_COUNTS
+            .may_load(deps.storage, status.to_string())?
+            .unwrap_or_default(),
+        None => PROPOSAL_COUNTS
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, count)| count))
+            .sum::<StdResult<u64>>()?,
+    };
+    to_json_binary(&count)
+}
+
+/// Moves a proposal from the `from` status to the `to` one in the status counters and
+/// index, `from` is `None` for new proposals.
+pub(crate) fn update_proposal_status(
+    storage: &mut dyn Storage,
+    proposal_id: u64,
+    from: Option<ProposalStatus>,
+    to: ProposalStatus,
+) -> StdResult<()> {
+    if from == Some(to) {
+        return Ok(());
     }
+    if let Some(from) = from {
+        PROPOSAL_COUNTS.update(storage, from.to_string(), |count| -> StdResult<_> {
+            Ok(count.unwrap_or_default().saturating_sub(1))
+        })?;
+        PROPOSALS_BY_STATUS.remove(storage, (from.to_string(), proposal_id));
+    }
+    PROPOSAL_COUNTS.update(storage, to.to_string(), |count| -> StdResult<_> {
+        Ok(count.unwrap_or_default() + 1)
+    })?;
+    PROPOSALS_BY_STATUS.save(storage, (to.to_string(), proposal_id), &Empty {})?;
+    Ok(())
 }
 
 pub fn query_proposal(deps: Deps, id: u64) -> StdResult<Binary> {
@@ -313,18 +747,121 @@ pub fn query_list_proposals(
     deps: Deps,
     start_after: Option<u64>,
     limit: Option<u64>,
+    status: Option<ProposalStatus>,
 ) -> StdResult<Binary> {
     let min = start_after.map(Bound::exclusive);
     let limit = limit.unwrap_or(DEFAULT_LIMIT);
-    let props: Vec<SingleChoiceProposal> = PROPOSALS
-        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+    to_json_binary(&list_proposals(
+        deps,
+        min,
+        None,
+        Order::Ascending,
+        limit,
+        status,
+    )?)
+}
+
+pub fn query_reverse_proposals(
+    deps: Deps,
+    start_before: Option<u64>,
+    limit: Option<u64>,
+    status: Option<ProposalStatus>,
+) -> StdResult<Binary> {
+    let max = start_before.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    to_json_binary(&list_proposals(
+        deps,
+        None,
+        max,
+        Order::Descending,
+        limit,
+        status,
+    )?)
+}
+
+fn list_proposals(
+    deps: Deps,
+    min: Option<Bound<u64>>,
+    max: Option<Bound<u64>>,
+    order: Order,
+    limit: u64,
+    status: Option<ProposalStatus>,
+) -> StdResult<ProposalListResponse> {
+    let proposals = match status {
+        Some(status) => PROPOSALS_BY_STATUS
+            .prefix(status.to_string())
+            .keys(deps.storage, min, max, order)
+            .take(limit as usize)
+            .map(|id| PROPOSALS.load(deps.storage, id?))
+            .collect::<StdResult<Vec<SingleChoiceProposal>>>()?,
+        None => PROPOSALS
+            .range(deps.storage, min, max, order)
+            .take(limit as usize)
+            .map(|item| item.map(|(_, proposal)| proposal))
+            .collect::<StdResult<Vec<SingleChoiceProposal>>>()?,
+    };
+    // A full page may be followed by more proposals
+    let last_scanned = proposals
+        .last()
+        .map(|proposal| proposal.id)
+        .filter(|_| proposals.len() as u64 >= limit);
+
+    Ok(ProposalListResponse {
+        proposals,
+        last_scanned,
+    })
+}
+
+pub fn query_executable_proposals(
+    deps: Deps,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+
+    // Every timelocked proposal scanned may take cross-contract queries, so at most
+    // `limit` of them are looked at, and the caller continues from `last_scanned`.
+    let timelocked_ids = PROPOSALS_BY_STATUS
+        .prefix(ProposalStatus::Timelocked.to_string())
+        .keys(deps.storage, min, None, Order::Ascending)
         .take(limit as usize)
-        .collect::<Result<Vec<(u64, SingleChoiceProposal)>, _>>()?
-        .into_iter()
-        .map(|(_, proposal)| proposal)
-        .collect();
+        .collect::<StdResult<Vec<u64>>>()?;
+    let last_scanned = timelocked_ids
+        .last()
+        .copied()
+        .filter(|_| timelocked_ids.len() as u64 >= limit);
+
+    let overrule_proposal_module =
+        query_overrule_proposal_module(deps, &config.overrule_pre_propose)?;
+    let mut props: Vec<SingleChoiceProposal> = vec![];
+    for proposal_id in timelocked_ids {
+        let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+        if is_execution_deadline_passed(&env, &proposal) {
+            continue;
+        }
+        if let Some(until) = proposal.timelocked_until {
+            if !until.is_expired(&env.block) {
+                continue;
+            }
+        }
+        if is_overrule_proposal_declined(
+            deps,
+            &env,
+            &config.overrule_pre_propose,
+            &overrule_proposal_module,
+            proposal.id,
+        )? {
+            props.push(proposal);
+        }
+    }
 
-    to_json_binary(&ProposalListResponse { proposals: props })
+    to_json_binary(&ProposalListResponse {
+        proposals: props,
+        last_scanned,
+    })
 }
 
 pub fn query_proposal_execution_error(deps: Deps, proposal_id: u64) -> StdResult<Binary> {
@@ -332,6 +869,52 @@ pub fn query_proposal_execution_error(deps: Deps, proposal_id: u64) -> StdResult
     to_json_binary(&error)
 }
 
+pub fn query_bounty_balance(deps: Deps, env: Env) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let balance = config
+        .execution_bounty
+        .as_ref()
+        .map(|bounty| {
+            deps.querier
+                .query_balance(&env.contract.address, &bounty.denom)
+        })
+        .transpose()?;
+
+    to_json_binary(&BountyBalanceResponse {
+        execution_bounty: config.execution_bounty,
+        balance,
+    })
+}
+
+pub fn query_proposal_messages(deps: Deps, proposal_id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    let inner_msgs = match proposal.msgs.first().map(unwrap_timelocked_msgs) {
+        Some(Ok((_, inner_msgs))) => inner_msgs,
+        _ => vec![],
+    };
+
+    let messages = inner_msgs
+        .into_iter()
+        .enumerate()
+        .map(|(index, msg)| {
+            let index = index as u32;
+            Ok(ProposalMessage {
+                index,
+                msg,
+                status: proposal
+                    .msg_statuses
+                    .get(index as usize)
+                    .copied()
+                    .unwrap_or(MessageStatus::Pending),
+                error: PROPOSAL_MSG_EXECUTION_ERRORS
+                    .may_load(deps.storage, (proposal_id, index))?,
+            })
+        })
+        .collect::<StdResult<Vec<ProposalMessage>>>()?;
+
+    to_json_binary(&ProposalMessagesResponse { messages })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     // Set contract to version to latest
@@ -377,17 +960,43 @@ pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, Co
         }
     }
 
+    // Older versions didn't count nor index proposals per status, so both are rebuilt
+    let statuses = PROPOSALS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(id, proposal)| (id, proposal.status)))
+        .collect::<StdResult<Vec<(u64, ProposalStatus)>>>()?;
+    for (id, status) in statuses.iter() {
+        PROPOSALS_BY_STATUS.save(deps.storage, (status.to_string(), *id), &Empty {})?;
+    }
+    for status in [
+        ProposalStatus::Timelocked,
+        ProposalStatus::Overruled,
+        ProposalStatus::Executed,
+        ProposalStatus::ExecutionFailed,
+        ProposalStatus::Expired,
+    ] {
+        let count = statuses.iter().filter(|(_, s)| *s == status).count() as u64;
+        PROPOSAL_COUNTS.save(deps.storage, status.to_string(), &count)?;
+    }
+
     Ok(Response::default().add_attribute("migrated_proposal_ids", migrated_ids.join(",")))
 }
 
+/// Returns the main DAO proposal module the overrule proposals are created in.
+fn query_overrule_proposal_module(deps: Deps, overrule_pre_propose: &Addr) -> StdResult<Addr> {
+    deps.querier
+        .query_wasm_smart(overrule_pre_propose, &OverruleQueryMsg::ProposalModule {})
+}
+
 // Returns true if overrule proposal for this subdao proposal
 // was declined. (voting is over, and overrule is not accepted).
 fn is_overrule_proposal_declined(
-    deps: &DepsMut,
+    deps: Deps,
     env: &Env,
     overrule_pre_propose: &Addr,
+    overrule_proposal_module: &Addr,
     subdao_proposal_id: u64,
-) -> Result<bool, ContractError> {
+) -> StdResult<bool> {
     let overrule_proposal_id: u64 = deps.querier.query_wasm_smart(
         overrule_pre_propose,
         &OverruleQueryMsg::QueryExtension {
@@ -397,33 +1006,39 @@ fn is_overrule_proposal_declined(
             },
         },
     )?;
-    let propose: Addr = deps
-        .querier
-        .query_wasm_smart(overrule_pre_propose, &OverruleQueryMsg::ProposalModule {})?;
     let overrule_proposal: MainDaoProposalResponse = deps.querier.query_wasm_smart(
-        propose,
+        overrule_proposal_module,
         &MainDaoProposalModuleQueryMsg::Proposal {
             proposal_id: overrule_proposal_id,
         },
     )?;
     // we check for both Rejected and Closed status
-    // since anybody can close rejected overrule proposals
+    // since anybody can close rejected overrule proposals.
+    // Vetoed overrule proposals are declined as well.
     Ok(overrule_proposal.proposal.status == Status::Rejected
-        || overrule_proposal.proposal.status == Status::Closed)
+        || overrule_proposal.proposal.status == Status::Closed
+        || overrule_proposal.proposal.status == Status::Vetoed)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
-    let proposal_id = msg.id;
+    let (proposal_id, msg_index) = unmask_msg_execution_reply_id(msg.id);
 
-    PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
-        Some(mut prop) => {
-            prop.status = ProposalStatus::ExecutionFailed;
-
-            Ok(prop)
-        }
-        None => Err(ContractError::NoSuchProposal { id: proposal_id }),
-    })?;
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+    let previous_status = prop.status;
+    prop.status = ProposalStatus::ExecutionFailed;
+    if let Some(status) = prop.msg_statuses.get_mut(msg_index as usize) {
+        *status = MessageStatus::ExecutionFailed;
+    }
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    update_proposal_status(
+        deps.storage,
+        proposal_id,
+        Some(previous_status),
+        prop.status,
+    )?;
 
     // Error is reduced before cosmwasm reply and is expected in form of "codespace=? code=?"
     let error = msg.result.into_result().err().ok_or_else(|| {
@@ -433,9 +1048,12 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
         ))
     })?;
     PROPOSAL_EXECUTION_ERRORS.save(deps.storage, proposal_id, &error)?;
+    PROPOSAL_MSG_EXECUTION_ERRORS.save(deps.storage, (proposal_id, msg_index), &error)?;
 
-    Ok(Response::new().add_attribute(
-        "timelocked_proposal_execution_failed",
-        proposal_id.to_string(),
-    ))
+    Ok(Response::new()
+        .add_attribute(
+            "timelocked_proposal_execution_failed",
+            proposal_id.to_string(),
+        )
+        .add_attribute("failed_msg_index", msg_index.to_string()))
 }
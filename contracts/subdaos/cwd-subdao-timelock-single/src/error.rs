@@ -1,4 +1,5 @@
 use cosmwasm_std::StdError;
+use cw_utils::Expiration;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -15,6 +16,20 @@ pub enum ContractError {
     #[error("Proposal is timelocked")]
     TimeLocked {},
 
+    #[error("Proposal is timelocked until {until}")]
+    MinTimelockNotElapsed { until: Expiration },
+
+    #[error("Execution deadline of proposal ({id}) has passed, it can only be expired")]
+    ExecutionDeadlinePassed { id: u64 },
+
+    #[error(
+        "Execution deadline of proposal ({id}) has passed, its failed messages can't be retried"
+    )]
+    RetryDeadlinePassed { id: u64 },
+
+    #[error("Execution deadline of proposal ({id}) has not passed yet")]
+    ExecutionDeadlineNotPassed { id: u64 },
+
     #[error("Wrong proposal status ({status})")]
     WrongStatus { status: String },
 
@@ -29,4 +44,10 @@ pub enum ContractError {
 
     #[error("Can only execute msg of ExecuteTimelockedMsgs type")]
     CanOnlyExecuteExecuteTimelockedMsgs {},
+
+    #[error("Too many messages inside ExecuteTimelockedMsgs: got {len}, max is {max}")]
+    TooManyMessages { len: usize, max: usize },
+
+    #[error("Proposal ({id}) has no failed messages to retry")]
+    NoFailedMessages { id: u64 },
 }
@@ -1,3 +1,4 @@
+use cosmwasm_std::Empty;
 use cw_storage_plus::{Item, Map};
 use neutron_subdao_timelock_single::types::{Config, SingleChoiceProposal};
 
@@ -6,5 +7,14 @@ pub const DEFAULT_LIMIT: u64 = 30;
 
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const PROPOSALS: Map<u64, SingleChoiceProposal> = Map::new("proposals");
+/// Number of proposals per status, keyed by the status name, so that proposals
+/// don't have to be iterated to count them.
+pub const PROPOSAL_COUNTS: Map<String, u64> = Map::new("proposal_counts");
+/// Ids of the proposals per status, keyed by (status name, proposal id), so that the
+/// proposals with a given status can be found without going over all of them.
+pub const PROPOSALS_BY_STATUS: Map<(String, u64), Empty> = Map::new("proposals_by_status");
 /// Execution errors for proposals that do not close on failure (Config.close_proposal_on_execution_failure set to false)
 pub const PROPOSAL_EXECUTION_ERRORS: Map<u64, String> = Map::new("proposal_execution_errors");
+/// Execution errors of the separate proposal messages, keyed by (proposal id, message index)
+pub const PROPOSAL_MSG_EXECUTION_ERRORS: Map<(u64, u32), String> =
+    Map::new("proposal_msg_execution_errors");
@@ -5,8 +5,8 @@ use std::rc::Rc;
 use cosmwasm_std::{
     from_json,
     testing::{MockApi, MockQuerier, MockStorage},
-    to_json_binary, Addr, ContractResult, Empty, OwnedDeps, Querier, QuerierResult, QueryRequest,
-    SystemError, SystemResult, Uint128, WasmQuery,
+    to_json_binary, Addr, Coin, ContractResult, Empty, OwnedDeps, Querier, QuerierResult,
+    QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
 };
 use cw_utils::Duration;
 use cwd_proposal_single::{
@@ -127,6 +127,8 @@ impl WasmMockQuerier {
                             allow_revoting: false,
                             dao: Addr::unchecked(MOCK_MAIN_DAO_ADDR),
                             close_proposal_on_execution_failure: false,
+                            veto_threshold: None,
+                            amendment_period: None,
                         }),
                         ProposeQuery::Proposal { .. } => to_json_binary(&MainDaoProposalResponse {
                             id: 1,
@@ -148,8 +150,11 @@ impl WasmMockQuerier {
                                     yes: Default::default(),
                                     no: Default::default(),
                                     abstain: Default::default(),
+                                    no_with_veto: Default::default(),
                                 },
                                 allow_revoting: false,
+                                veto_threshold: None,
+                                voting_start: None,
                             },
                         }),
                         ProposeQuery::ListProposals { .. } => todo!(),
@@ -193,6 +198,10 @@ impl WasmMockQuerier {
     pub fn set_close_proposal_on_execution_failure(&mut self, v: bool) {
         self.close_proposal_on_execution_failure = v
     }
+
+    pub fn update_balance(&mut self, addr: impl Into<String>, balance: Vec<Coin>) {
+        self.base.update_balance(addr, balance);
+    }
 }
 
 impl WasmMockQuerier {
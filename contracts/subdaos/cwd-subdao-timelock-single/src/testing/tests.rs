@@ -1,16 +1,22 @@
 use crate::contract::query_proposal_execution_error;
 use cosmwasm_std::testing::MOCK_CONTRACT_ADDR;
 use cosmwasm_std::{
-    from_json,
+    coin, coins, from_json,
     testing::{mock_env, mock_info},
-    to_json_binary, Addr, Attribute, CosmosMsg, Reply, SubMsg, SubMsgResult, WasmMsg,
+    to_json_binary, Addr, Attribute, BankMsg, Binary, CosmosMsg, Deps, Reply, SubMsg, SubMsgResult,
+    WasmMsg,
 };
+use cw_utils::{Duration, Expiration};
 use cwd_voting::status::Status;
 use neutron_sdk::bindings::msg::NeutronMsg;
 use neutron_subdao_core::msg::ExecuteMsg as CoreExecuteMsg;
+use neutron_subdao_proposal_single::msg::ExecuteMsg as ProposalExecuteMsg;
 use neutron_subdao_timelock_single::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
-    types::{Config, ProposalListResponse, ProposalStatus, SingleChoiceProposal},
+    types::{
+        BountyBalanceResponse, Config, MessageStatus, ProposalListResponse, ProposalMessage,
+        ProposalMessagesResponse, ProposalStatus, SingleChoiceProposal,
+    },
 };
 
 use std::cell::RefCell;
@@ -18,7 +24,9 @@ use std::rc::Rc;
 
 use crate::testing::mock_querier::{MOCK_MAIN_DAO_ADDR, MOCK_OVERRULE_PREPROPOSAL};
 use crate::{
-    contract::{execute, instantiate, query, reply},
+    contract::{
+        execute, instantiate, mask_msg_execution_reply_id, query, reply, update_proposal_status,
+    },
     state::{CONFIG, DEFAULT_LIMIT, PROPOSALS},
     testing::mock_querier::MOCK_TIMELOCK_INITIALIZER,
 };
@@ -26,7 +34,7 @@ use neutron_dao_pre_propose_overrule::msg::{
     ExecuteMsg as OverruleExecuteMsg, ProposeMessage as OverruleProposeMessage,
 };
 
-use super::mock_querier::{mock_dependencies, MOCK_SUBDAO_CORE_ADDR};
+use super::mock_querier::{mock_dependencies, MOCK_PROPOSAL_ADDR, MOCK_SUBDAO_CORE_ADDR};
 
 #[test]
 fn test_instantiate_test() {
@@ -36,6 +44,8 @@ fn test_instantiate_test() {
     let info = mock_info("neutron1unknownsender", &[]);
     let msg = InstantiateMsg {
         overrule_pre_propose: MOCK_OVERRULE_PREPROPOSAL.to_string(),
+        min_timelock_duration: None,
+        execution_deadline: None,
     };
     let res = instantiate(deps.as_mut(), env.clone(), info, msg);
     assert_eq!(
@@ -47,6 +57,8 @@ fn test_instantiate_test() {
 
     let msg = InstantiateMsg {
         overrule_pre_propose: MOCK_OVERRULE_PREPROPOSAL.to_string(),
+        min_timelock_duration: None,
+        execution_deadline: None,
     };
     let res = instantiate(deps.as_mut(), env.clone(), info.clone(), msg.clone());
     let res_ok = res.unwrap();
@@ -61,11 +73,16 @@ fn test_instantiate_test() {
         owner: Addr::unchecked(MOCK_MAIN_DAO_ADDR),
         overrule_pre_propose: Addr::unchecked(msg.overrule_pre_propose),
         subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+        min_timelock_duration: None,
+        execution_bounty: None,
+        execution_deadline: None,
     };
     assert_eq!(expected_config, config);
 
     let msg = InstantiateMsg {
         overrule_pre_propose: MOCK_OVERRULE_PREPROPOSAL.to_string(),
+        min_timelock_duration: None,
+        execution_deadline: None,
     };
     let res = instantiate(deps.as_mut(), env, info, msg.clone());
     let res_ok = res.unwrap();
@@ -80,6 +97,9 @@ fn test_instantiate_test() {
         owner: Addr::unchecked(MOCK_MAIN_DAO_ADDR),
         overrule_pre_propose: Addr::unchecked(msg.overrule_pre_propose),
         subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+        min_timelock_duration: None,
+        execution_bounty: None,
+        execution_deadline: None,
     };
     assert_eq!(expected_config, config);
 }
@@ -111,6 +131,9 @@ fn test_execute_timelock_proposal() {
         owner: Addr::unchecked("owner"),
         overrule_pre_propose: Addr::unchecked(MOCK_OVERRULE_PREPROPOSAL),
         subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+        min_timelock_duration: None,
+        execution_bounty: None,
+        execution_deadline: None,
     };
     CONFIG.save(deps.as_mut().storage, &config).unwrap();
 
@@ -183,9 +206,16 @@ fn test_execute_timelock_proposal() {
         id: 10,
         msgs: vec![correct_proposal_msg()],
         status: ProposalStatus::Timelocked,
+        timelocked_until: None,
+        msg_statuses: vec![MessageStatus::Pending],
+        execution_deadline: None,
     };
     let prop = PROPOSALS.load(deps.as_mut().storage, 10u64).unwrap();
     assert_eq!(expected_proposal, prop);
+    assert_eq!(
+        1,
+        proposal_count(deps.as_ref(), Some(ProposalStatus::Timelocked))
+    );
 }
 
 #[test]
@@ -207,6 +237,9 @@ fn test_execute_proposal() {
         owner: Addr::unchecked("owner"),
         overrule_pre_propose: Addr::unchecked(MOCK_OVERRULE_PREPROPOSAL),
         subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+        min_timelock_duration: None,
+        execution_bounty: None,
+        execution_deadline: None,
     };
     CONFIG.save(deps.as_mut().storage, &config).unwrap();
     let res = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone());
@@ -225,6 +258,9 @@ fn test_execute_proposal() {
             id: 10,
             msgs: vec![correct_proposal_msg()],
             status: s,
+            timelocked_until: None,
+            msg_statuses: vec![],
+            execution_deadline: None,
         };
         PROPOSALS
             .save(deps.as_mut().storage, proposal.id, &proposal)
@@ -242,6 +278,9 @@ fn test_execute_proposal() {
         id: 10,
         msgs: vec![correct_proposal_msg()],
         status: ProposalStatus::Timelocked,
+        timelocked_until: None,
+        msg_statuses: vec![],
+        execution_deadline: None,
     };
     PROPOSALS
         .save(deps.as_mut().storage, proposal.id, &proposal)
@@ -259,12 +298,12 @@ fn test_execute_proposal() {
         Attribute::new("proposal_id", "10"),
     ];
     assert_eq!(expected_attributes, res.attributes);
+    // the only inner message is wrapped into its own `ExecuteTimelockedMsgs`
     assert_eq!(
-        proposal
-            .msgs
-            .iter()
-            .map(|msg| SubMsg::reply_on_error(msg.clone(), proposal.id))
-            .collect::<Vec<SubMsg<NeutronMsg>>>(),
+        vec![SubMsg::reply_on_error(
+            correct_proposal_msg(),
+            mask_msg_execution_reply_id(proposal.id, 0)
+        )],
         res.messages
     );
     let updated_prop = PROPOSALS.load(deps.as_mut().storage, 10).unwrap();
@@ -276,6 +315,9 @@ fn test_execute_proposal() {
         id: 11,
         msgs: vec![correct_proposal_msg()],
         status: ProposalStatus::Timelocked,
+        timelocked_until: None,
+        msg_statuses: vec![],
+        execution_deadline: None,
     };
     PROPOSALS
         .save(deps.as_mut().storage, proposal.id, &proposal)
@@ -293,12 +335,12 @@ fn test_execute_proposal() {
         Attribute::new("proposal_id", "11"),
     ];
     assert_eq!(expected_attributes, res.attributes);
+    // the only inner message is wrapped into its own `ExecuteTimelockedMsgs`
     assert_eq!(
-        proposal
-            .msgs
-            .iter()
-            .map(|msg| SubMsg::reply_on_error(msg.clone(), proposal.id))
-            .collect::<Vec<SubMsg<NeutronMsg>>>(),
+        vec![SubMsg::reply_on_error(
+            correct_proposal_msg(),
+            mask_msg_execution_reply_id(proposal.id, 0)
+        )],
         res.messages
     );
     let updated_prop = PROPOSALS.load(deps.as_mut().storage, 11).unwrap();
@@ -309,6 +351,9 @@ fn test_execute_proposal() {
         id: 10,
         msgs: vec![correct_proposal_msg(), correct_proposal_msg()],
         status: ProposalStatus::Timelocked,
+        timelocked_until: None,
+        msg_statuses: vec![],
+        execution_deadline: None,
     };
     PROPOSALS
         .save(deps.as_mut().storage, proposal.id, &proposal)
@@ -324,6 +369,9 @@ fn test_execute_proposal() {
         id: 10,
         msgs: vec![NeutronMsg::remove_interchain_query(1).into()],
         status: ProposalStatus::Timelocked,
+        timelocked_until: None,
+        msg_statuses: vec![],
+        execution_deadline: None,
     };
     PROPOSALS
         .save(deps.as_mut().storage, proposal.id, &proposal)
@@ -339,6 +387,9 @@ fn test_execute_proposal() {
         id: 10,
         msgs: vec![],
         status: ProposalStatus::Timelocked,
+        timelocked_until: None,
+        msg_statuses: vec![],
+        execution_deadline: None,
     };
     PROPOSALS
         .save(deps.as_mut().storage, proposal.id, &proposal)
@@ -355,6 +406,9 @@ fn test_execute_proposal() {
         id: 10,
         msgs: vec![correct_proposal_msg()],
         status: ProposalStatus::Timelocked,
+        timelocked_until: None,
+        msg_statuses: vec![],
+        execution_deadline: None,
     };
     PROPOSALS
         .save(deps.as_mut().storage, proposal2.id, &proposal2)
@@ -377,6 +431,245 @@ fn test_execute_proposal() {
     assert_eq!(ProposalStatus::Executed, updated_prop_2.status);
 }
 
+#[test]
+fn test_execute_proposal_min_timelock_duration() {
+    let overrule_proposal_status = Rc::new(RefCell::new(Status::Rejected));
+    let mut deps = mock_dependencies(Rc::clone(&overrule_proposal_status));
+    deps.querier.set_close_proposal_on_execution_failure(true);
+    let mut env = mock_env();
+
+    let config = Config {
+        owner: Addr::unchecked("owner"),
+        overrule_pre_propose: Addr::unchecked(MOCK_OVERRULE_PREPROPOSAL),
+        subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+        min_timelock_duration: Some(Duration::Height(10)),
+        execution_bounty: None,
+        execution_deadline: None,
+    };
+    CONFIG.save(deps.as_mut().storage, &config).unwrap();
+
+    let msg = ExecuteMsg::TimelockProposal {
+        proposal_id: 10,
+        msgs: vec![correct_proposal_msg()],
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MOCK_SUBDAO_CORE_ADDR, &[]),
+        msg,
+    )
+    .unwrap();
+
+    // the expiration is shown in the proposal query
+    let until = Expiration::AtHeight(env.block.height + 10);
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::Proposal { proposal_id: 10 },
+    )
+    .unwrap();
+    let queried_prop: SingleChoiceProposal = from_json(res).unwrap();
+    assert_eq!(Some(until), queried_prop.timelocked_until);
+
+    // overrule is already declined, but the minimum timelock has not elapsed yet
+    let info = mock_info("neutron1unknownsender", &[]);
+    let msg = ExecuteMsg::ExecuteProposal { proposal_id: 10 };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap_err();
+    assert_eq!(
+        format!("Proposal is timelocked until {}", until),
+        err.to_string()
+    );
+
+    env.block.height += 10;
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    let updated_prop = PROPOSALS.load(deps.as_mut().storage, 10).unwrap();
+    assert_eq!(ProposalStatus::Executed, updated_prop.status);
+
+    // zero duration disables the minimum timelock
+    let msg = ExecuteMsg::UpdateConfig {
+        owner: None,
+        overrule_pre_propose: None,
+        min_timelock_duration: Some(Duration::Time(0)),
+        execution_deadline: None,
+    };
+    execute(deps.as_mut(), env, mock_info("owner", &[]), msg).unwrap();
+    let updated_config = CONFIG.load(deps.as_mut().storage).unwrap();
+    assert_eq!(None, updated_config.min_timelock_duration);
+}
+
+#[test]
+fn test_execution_bounty() {
+    let overrule_proposal_status = Rc::new(RefCell::new(Status::Rejected));
+    let mut deps = mock_dependencies(Rc::clone(&overrule_proposal_status));
+    let env = mock_env();
+
+    let config = Config {
+        owner: Addr::unchecked("owner"),
+        overrule_pre_propose: Addr::unchecked(MOCK_OVERRULE_PREPROPOSAL),
+        subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+        min_timelock_duration: None,
+        execution_bounty: None,
+        execution_deadline: None,
+    };
+    CONFIG.save(deps.as_mut().storage, &config).unwrap();
+
+    let msg = ExecuteMsg::UpdateExecutionBounty {
+        execution_bounty: Some(coin(100, "untrn")),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("neutron1unknownsender", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!("Unauthorized", err.to_string());
+
+    // the subDAO funds the bounty, so it can configure it
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MOCK_SUBDAO_CORE_ADDR, &[]),
+        msg,
+    )
+    .unwrap();
+    assert_eq!(
+        vec![
+            Attribute::new("action", "update_execution_bounty"),
+            Attribute::new("execution_bounty", "100untrn"),
+        ],
+        res.attributes
+    );
+
+    deps.querier
+        .update_balance(MOCK_CONTRACT_ADDR, coins(150, "untrn"));
+    let res = query(deps.as_ref(), env.clone(), QueryMsg::BountyBalance {}).unwrap();
+    let queried: BountyBalanceResponse = from_json(res).unwrap();
+    assert_eq!(
+        BountyBalanceResponse {
+            execution_bounty: Some(coin(100, "untrn")),
+            balance: Some(coin(150, "untrn")),
+        },
+        queried
+    );
+
+    for id in 1..=2 {
+        let prop = SingleChoiceProposal {
+            id,
+            msgs: vec![correct_proposal_msg()],
+            status: ProposalStatus::Timelocked,
+            timelocked_until: None,
+            msg_statuses: vec![],
+            execution_deadline: None,
+        };
+        PROPOSALS.save(deps.as_mut().storage, id, &prop).unwrap();
+    }
+
+    // the executor of a matured proposal is paid
+    let info = mock_info("neutron1keeper", &[]);
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ExecuteMsg::ExecuteProposal { proposal_id: 1 },
+    )
+    .unwrap();
+    assert_eq!(
+        SubMsg::new(BankMsg::Send {
+            to_address: "neutron1keeper".to_string(),
+            amount: coins(100, "untrn"),
+        }),
+        res.messages[1]
+    );
+
+    // not enough funds left: the proposal is executed without the bounty
+    deps.querier
+        .update_balance(MOCK_CONTRACT_ADDR, coins(50, "untrn"));
+    let res = execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::ExecuteProposal { proposal_id: 2 },
+    )
+    .unwrap();
+    assert_eq!(1, res.messages.len());
+    let prop = PROPOSALS.load(deps.as_mut().storage, 2).unwrap();
+    assert_eq!(ProposalStatus::Executed, prop.status);
+}
+
+#[test]
+fn test_expire_proposal() {
+    let overrule_proposal_status = Rc::new(RefCell::new(Status::Rejected));
+    let mut deps = mock_dependencies(Rc::clone(&overrule_proposal_status));
+    let mut env = mock_env();
+
+    let config = Config {
+        owner: Addr::unchecked("owner"),
+        overrule_pre_propose: Addr::unchecked(MOCK_OVERRULE_PREPROPOSAL),
+        subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+        min_timelock_duration: None,
+        execution_bounty: None,
+        execution_deadline: Some(Duration::Height(5)),
+    };
+    CONFIG.save(deps.as_mut().storage, &config).unwrap();
+
+    let msg = ExecuteMsg::TimelockProposal {
+        proposal_id: 1,
+        msgs: vec![correct_proposal_msg()],
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MOCK_SUBDAO_CORE_ADDR, &[]),
+        msg,
+    )
+    .unwrap();
+    let prop = PROPOSALS.load(deps.as_mut().storage, 1).unwrap();
+    assert_eq!(
+        Some(Expiration::AtHeight(env.block.height + 5)),
+        prop.execution_deadline
+    );
+
+    let info = mock_info("neutron1unknownsender", &[]);
+    let msg = ExecuteMsg::ExpireProposal { proposal_id: 1 };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap_err();
+    assert_eq!(
+        "Execution deadline of proposal (1) has not passed yet",
+        err.to_string()
+    );
+
+    // the stale proposal can no longer be executed
+    env.block.height += 5;
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ExecuteMsg::ExecuteProposal { proposal_id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(
+        "Execution deadline of proposal (1) has passed, it can only be expired",
+        err.to_string()
+    );
+
+    // expiry is propagated to the subDAO proposal module
+    let res = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
+    assert_eq!(
+        vec![SubMsg::new(WasmMsg::Execute {
+            contract_addr: MOCK_PROPOSAL_ADDR.to_string(),
+            msg: to_json_binary(&ProposalExecuteMsg::TimelockedProposalExpired { proposal_id: 1 })
+                .unwrap(),
+            funds: vec![],
+        })],
+        res.messages
+    );
+    let prop = PROPOSALS.load(deps.as_mut().storage, 1).unwrap();
+    assert_eq!(ProposalStatus::Expired, prop.status);
+
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert_eq!("Wrong proposal status (expired)", err.to_string());
+}
+
 #[test]
 fn test_overrule_proposal() {
     let overrule_proposal_status = Rc::new(RefCell::new(Status::Open));
@@ -396,6 +689,9 @@ fn test_overrule_proposal() {
         owner: Addr::unchecked("owner"),
         overrule_pre_propose: Addr::unchecked(MOCK_OVERRULE_PREPROPOSAL),
         subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+        min_timelock_duration: None,
+        execution_bounty: None,
+        execution_deadline: None,
     };
     CONFIG.save(deps.as_mut().storage, &config).unwrap();
     let res = execute(deps.as_mut(), env.clone(), info, msg.clone());
@@ -403,31 +699,46 @@ fn test_overrule_proposal() {
 
     let info = mock_info("owner", &[]);
 
+    // Proposals that are no longer timelocked are skipped and left as they are.
     let wrong_prop_statuses = vec![
         ProposalStatus::Executed,
         ProposalStatus::ExecutionFailed,
         ProposalStatus::Overruled,
+        ProposalStatus::Expired,
     ];
     for s in wrong_prop_statuses {
         let proposal = SingleChoiceProposal {
             id: 10,
             msgs: vec![correct_proposal_msg()],
             status: s,
+            timelocked_until: None,
+            msg_statuses: vec![],
+            execution_deadline: None,
         };
         PROPOSALS
             .save(deps.as_mut().storage, proposal.id, &proposal)
             .unwrap();
-        let res = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone());
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
         assert_eq!(
-            format!("Wrong proposal status ({})", s),
-            res.unwrap_err().to_string()
-        )
+            vec![
+                Attribute::new("action", "overrule_proposal"),
+                Attribute::new("sender", info.sender.clone()),
+                Attribute::new("proposal_id", "10"),
+                Attribute::new("skipped_status", s.to_string()),
+            ],
+            res.attributes
+        );
+        let prop = PROPOSALS.load(deps.as_mut().storage, 10).unwrap();
+        assert_eq!(s, prop.status);
     }
 
     let proposal = SingleChoiceProposal {
         id: 10,
         msgs: vec![correct_proposal_msg()],
         status: ProposalStatus::Timelocked,
+        timelocked_until: None,
+        msg_statuses: vec![],
+        execution_deadline: None,
     };
     PROPOSALS
         .save(deps.as_mut().storage, proposal.id, &proposal)
@@ -444,6 +755,164 @@ fn test_overrule_proposal() {
     assert_eq!(ProposalStatus::Overruled, updated_prop.status);
 }
 
+/// A batch overrule proposal overrules the proposals that are still timelocked, even if
+/// one of them expired in the meantime.
+#[test]
+fn test_overrule_batch_with_expired_proposal() {
+    let overrule_proposal_status = Rc::new(RefCell::new(Status::Open));
+    let mut deps = mock_dependencies(Rc::clone(&overrule_proposal_status));
+    let env = mock_env();
+    let info = mock_info("owner", &[]);
+
+    let config = Config {
+        owner: Addr::unchecked("owner"),
+        overrule_pre_propose: Addr::unchecked(MOCK_OVERRULE_PREPROPOSAL),
+        subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+        min_timelock_duration: None,
+        execution_bounty: None,
+        execution_deadline: None,
+    };
+    CONFIG.save(deps.as_mut().storage, &config).unwrap();
+
+    let statuses = [
+        ProposalStatus::Timelocked,
+        ProposalStatus::Expired,
+        ProposalStatus::Timelocked,
+    ];
+    for (i, s) in statuses.iter().enumerate() {
+        let proposal = SingleChoiceProposal {
+            id: i as u64 + 1,
+            msgs: vec![correct_proposal_msg()],
+            status: *s,
+            timelocked_until: None,
+            msg_statuses: vec![],
+            execution_deadline: None,
+        };
+        PROPOSALS
+            .save(deps.as_mut().storage, proposal.id, &proposal)
+            .unwrap();
+    }
+
+    // The messages of the batch overrule proposal are executed one after the other.
+    for proposal_id in 1..=3 {
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::OverruleProposal { proposal_id },
+        )
+        .unwrap();
+    }
+
+    let expected = [
+        ProposalStatus::Overruled,
+        ProposalStatus::Expired,
+        ProposalStatus::Overruled,
+    ];
+    for (i, s) in expected.iter().enumerate() {
+        let prop = PROPOSALS.load(deps.as_mut().storage, i as u64 + 1).unwrap();
+        assert_eq!(*s, prop.status);
+    }
+}
+
+#[test]
+fn test_cancel_timelocked_proposals() {
+    let overrule_proposal_status = Rc::new(RefCell::new(Status::Open));
+    let mut deps = mock_dependencies(Rc::clone(&overrule_proposal_status));
+    let env = mock_env();
+    let msg = ExecuteMsg::CancelTimelockedProposals {};
+
+    let config = Config {
+        owner: Addr::unchecked("owner"),
+        overrule_pre_propose: Addr::unchecked(MOCK_OVERRULE_PREPROPOSAL),
+        subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+        min_timelock_duration: None,
+        execution_bounty: None,
+        execution_deadline: None,
+    };
+    CONFIG.save(deps.as_mut().storage, &config).unwrap();
+
+    let statuses = vec![
+        ProposalStatus::Timelocked,
+        ProposalStatus::Executed,
+        ProposalStatus::Timelocked,
+        ProposalStatus::ExecutionFailed,
+    ];
+    for (i, s) in statuses.iter().enumerate() {
+        let proposal = SingleChoiceProposal {
+            id: i as u64 + 1,
+            msgs: vec![correct_proposal_msg()],
+            status: *s,
+            timelocked_until: None,
+            msg_statuses: vec![],
+            execution_deadline: None,
+        };
+        PROPOSALS
+            .save(deps.as_mut().storage, proposal.id, &proposal)
+            .unwrap();
+        update_proposal_status(deps.as_mut().storage, proposal.id, None, *s).unwrap();
+    }
+
+    // the owner isn't allowed to cancel proposals, only the subdao is
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        msg.clone(),
+    );
+    assert_eq!("Unauthorized", res.unwrap_err().to_string());
+
+    let info = mock_info(MOCK_SUBDAO_CORE_ADDR, &[]);
+    let res = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
+    // the cancellation is propagated to the subDAO proposal module
+    let expected_messages: Vec<SubMsg<NeutronMsg>> = [1, 3]
+        .into_iter()
+        .map(|proposal_id| {
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: MOCK_PROPOSAL_ADDR.to_string(),
+                msg: to_json_binary(&ProposalExecuteMsg::TimelockedProposalExpired { proposal_id })
+                    .unwrap(),
+                funds: vec![],
+            })
+        })
+        .collect();
+    assert_eq!(expected_messages, res.messages);
+    let expected_attributes = vec![
+        Attribute::new("action", "cancel_timelocked_proposals"),
+        Attribute::new("sender", info.sender),
+        Attribute::new("cancelled_proposal_ids", "1,3"),
+    ];
+    assert_eq!(expected_attributes, res.attributes);
+
+    let expected_statuses = vec![
+        ProposalStatus::Overruled,
+        ProposalStatus::Executed,
+        ProposalStatus::Overruled,
+        ProposalStatus::ExecutionFailed,
+    ];
+    for (i, s) in expected_statuses.into_iter().enumerate() {
+        let proposal = PROPOSALS.load(deps.as_ref().storage, i as u64 + 1).unwrap();
+        assert_eq!(s, proposal.status);
+    }
+    assert_eq!(
+        0,
+        proposal_count(deps.as_ref(), Some(ProposalStatus::Timelocked))
+    );
+    assert_eq!(
+        2,
+        proposal_count(deps.as_ref(), Some(ProposalStatus::Overruled))
+    );
+    assert_eq!(4, proposal_count(deps.as_ref(), None));
+
+    // only the proposals that are still timelocked are cancelled
+    let res = execute(deps.as_mut(), env, info, msg).unwrap();
+    assert_eq!(0, res.messages.len());
+    assert_eq!(
+        Attribute::new("cancelled_proposal_ids", ""),
+        res.attributes[2]
+    );
+}
+
 #[test]
 fn execute_update_config() {
     let overrule_proposal_status = Rc::new(RefCell::new(Status::Open));
@@ -454,6 +923,8 @@ fn execute_update_config() {
     let msg = ExecuteMsg::UpdateConfig {
         owner: None,
         overrule_pre_propose: Some("neutron1someotheroverrule".to_string()),
+        min_timelock_duration: None,
+        execution_deadline: None,
     };
 
     let res = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone());
@@ -466,6 +937,9 @@ fn execute_update_config() {
         owner: Addr::unchecked("owner"),
         overrule_pre_propose: Addr::unchecked(MOCK_OVERRULE_PREPROPOSAL),
         subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+        min_timelock_duration: None,
+        execution_bounty: None,
+        execution_deadline: None,
     };
     CONFIG.save(deps.as_mut().storage, &config).unwrap();
     let res = execute(deps.as_mut(), env.clone(), info, msg.clone());
@@ -476,6 +950,9 @@ fn execute_update_config() {
         owner: Addr::unchecked("none"),
         overrule_pre_propose: Addr::unchecked(MOCK_OVERRULE_PREPROPOSAL),
         subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+        min_timelock_duration: None,
+        execution_bounty: None,
+        execution_deadline: None,
     };
     CONFIG.save(deps.as_mut().storage, &config).unwrap();
     let res = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone());
@@ -485,6 +962,9 @@ fn execute_update_config() {
         owner: Addr::unchecked("owner"),
         overrule_pre_propose: Addr::unchecked(MOCK_OVERRULE_PREPROPOSAL),
         subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+        min_timelock_duration: None,
+        execution_bounty: None,
+        execution_deadline: None,
     };
     CONFIG.save(deps.as_mut().storage, &config).unwrap();
     let res_ok = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
@@ -493,6 +973,8 @@ fn execute_update_config() {
         Attribute::new("action", "update_config"),
         Attribute::new("owner", "owner"),
         Attribute::new("overrule_pre_propose", "neutron1someotheroverrule"),
+        Attribute::new("min_timelock_duration", "none"),
+        Attribute::new("execution_deadline", "none"),
     ];
     assert_eq!(expected_attributes, res_ok.attributes);
     let updated_config = CONFIG.load(deps.as_mut().storage).unwrap();
@@ -502,13 +984,18 @@ fn execute_update_config() {
         Config {
             owner: Addr::unchecked("owner"),
             overrule_pre_propose: Addr::unchecked(some_other_prepropose),
-            subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR)
+            subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+            min_timelock_duration: None,
+            execution_bounty: None,
+            execution_deadline: None,
         }
     );
 
     let msg = ExecuteMsg::UpdateConfig {
         owner: Some("neutron1newowner".to_string()),
         overrule_pre_propose: None,
+        min_timelock_duration: None,
+        execution_deadline: None,
     };
 
     let res_ok = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
@@ -517,6 +1004,8 @@ fn execute_update_config() {
         Attribute::new("action", "update_config"),
         Attribute::new("owner", "neutron1newowner"),
         Attribute::new("overrule_pre_propose", some_other_prepropose),
+        Attribute::new("min_timelock_duration", "none"),
+        Attribute::new("execution_deadline", "none"),
     ];
     assert_eq!(expected_attributes, res_ok.attributes);
     let updated_config = CONFIG.load(deps.as_mut().storage).unwrap();
@@ -525,13 +1014,32 @@ fn execute_update_config() {
         Config {
             owner: Addr::unchecked("neutron1newowner"),
             overrule_pre_propose: Addr::unchecked(some_other_prepropose),
-            subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR)
+            subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+            min_timelock_duration: None,
+            execution_bounty: None,
+            execution_deadline: None,
         }
     );
 
     // old owner
-    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
     assert_eq!("Unauthorized", err.to_string());
+
+    let msg = ExecuteMsg::UpdateConfig {
+        owner: None,
+        overrule_pre_propose: None,
+        min_timelock_duration: Some(Duration::Height(10)),
+        execution_deadline: Some(Duration::Time(3600)),
+    };
+    let res_ok = execute(deps.as_mut(), env, mock_info("neutron1newowner", &[]), msg).unwrap();
+    let expected_attributes = vec![
+        Attribute::new("action", "update_config"),
+        Attribute::new("owner", "neutron1newowner"),
+        Attribute::new("overrule_pre_propose", some_other_prepropose),
+        Attribute::new("min_timelock_duration", "height: 10"),
+        Attribute::new("execution_deadline", "time: 3600"),
+    ];
+    assert_eq!(expected_attributes, res_ok.attributes);
 }
 
 #[test]
@@ -542,6 +1050,9 @@ fn test_query_config() {
         owner: Addr::unchecked("owner"),
         overrule_pre_propose: Addr::unchecked(MOCK_OVERRULE_PREPROPOSAL),
         subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+        min_timelock_duration: None,
+        execution_bounty: None,
+        execution_deadline: None,
     };
     CONFIG.save(deps.as_mut().storage, &config).unwrap();
     let query_msg = QueryMsg::Config {};
@@ -559,6 +1070,9 @@ fn test_query_proposals() {
             id: i,
             msgs: vec![correct_proposal_msg()],
             status: ProposalStatus::Timelocked,
+            timelocked_until: None,
+            msg_statuses: vec![],
+            execution_deadline: None,
         };
         PROPOSALS.save(deps.as_mut().storage, i, &prop).unwrap();
     }
@@ -570,6 +1084,9 @@ fn test_query_proposals() {
             id: i,
             msgs: vec![correct_proposal_msg()],
             status: ProposalStatus::Timelocked,
+            timelocked_until: None,
+            msg_statuses: vec![],
+            execution_deadline: None,
         };
         assert_eq!(expected_prop, queried_prop)
     }
@@ -577,6 +1094,7 @@ fn test_query_proposals() {
     let query_msg = QueryMsg::ListProposals {
         start_after: None,
         limit: None,
+        status: None,
     };
     let res = query(deps.as_ref(), mock_env(), query_msg).unwrap();
     let queried_props: ProposalListResponse = from_json(&res).unwrap();
@@ -585,6 +1103,9 @@ fn test_query_proposals() {
             id: i,
             msgs: vec![correct_proposal_msg()],
             status: ProposalStatus::Timelocked,
+            timelocked_until: None,
+            msg_statuses: vec![],
+            execution_deadline: None,
         };
         assert_eq!(expected_prop, *p);
     }
@@ -593,6 +1114,7 @@ fn test_query_proposals() {
     let query_msg = QueryMsg::ListProposals {
         start_after: None,
         limit: Some(100),
+        status: None,
     };
     let res = query(deps.as_ref(), mock_env(), query_msg).unwrap();
     let queried_props: ProposalListResponse = from_json(&res).unwrap();
@@ -601,6 +1123,9 @@ fn test_query_proposals() {
             id: i,
             msgs: vec![correct_proposal_msg()],
             status: ProposalStatus::Timelocked,
+            timelocked_until: None,
+            msg_statuses: vec![],
+            execution_deadline: None,
         };
         assert_eq!(expected_prop, *p);
     }
@@ -609,6 +1134,7 @@ fn test_query_proposals() {
     let query_msg = QueryMsg::ListProposals {
         start_after: None,
         limit: Some(10),
+        status: None,
     };
     let res = query(deps.as_ref(), mock_env(), query_msg).unwrap();
     let queried_props: ProposalListResponse = from_json(&res).unwrap();
@@ -617,6 +1143,9 @@ fn test_query_proposals() {
             id: i,
             msgs: vec![correct_proposal_msg()],
             status: ProposalStatus::Timelocked,
+            timelocked_until: None,
+            msg_statuses: vec![],
+            execution_deadline: None,
         };
         assert_eq!(expected_prop, *p);
     }
@@ -625,6 +1154,7 @@ fn test_query_proposals() {
     let query_msg = QueryMsg::ListProposals {
         start_after: Some(50),
         limit: None,
+        status: None,
     };
     let res = query(deps.as_ref(), mock_env(), query_msg).unwrap();
     let queried_props: ProposalListResponse = from_json(&res).unwrap();
@@ -633,6 +1163,9 @@ fn test_query_proposals() {
             id: i,
             msgs: vec![correct_proposal_msg()],
             status: ProposalStatus::Timelocked,
+            timelocked_until: None,
+            msg_statuses: vec![],
+            execution_deadline: None,
         };
         assert_eq!(expected_prop, *p);
     }
@@ -641,6 +1174,7 @@ fn test_query_proposals() {
     let query_msg = QueryMsg::ListProposals {
         start_after: Some(90),
         limit: None,
+        status: None,
     };
     let res = query(deps.as_ref(), mock_env(), query_msg).unwrap();
     let queried_props: ProposalListResponse = from_json(&res).unwrap();
@@ -649,18 +1183,166 @@ fn test_query_proposals() {
             id: i,
             msgs: vec![correct_proposal_msg()],
             status: ProposalStatus::Timelocked,
+            timelocked_until: None,
+            msg_statuses: vec![],
+            execution_deadline: None,
         };
         assert_eq!(expected_prop, *p);
     }
     assert_eq!(queried_props.proposals.len(), 10);
 }
 
+#[test]
+fn test_query_filtered_proposals() {
+    let overrule_proposal_status = Rc::new(RefCell::new(Status::Rejected));
+    let mut deps = mock_dependencies(Rc::clone(&overrule_proposal_status));
+    let env = mock_env();
+    let config = Config {
+        owner: Addr::unchecked("owner"),
+        overrule_pre_propose: Addr::unchecked(MOCK_OVERRULE_PREPROPOSAL),
+        subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+        min_timelock_duration: None,
+        execution_bounty: None,
+        execution_deadline: None,
+    };
+    CONFIG.save(deps.as_mut().storage, &config).unwrap();
+
+    let statuses = [
+        ProposalStatus::Timelocked,
+        ProposalStatus::Overruled,
+        ProposalStatus::Executed,
+        ProposalStatus::ExecutionFailed,
+    ];
+    for i in 1..=8 {
+        let prop = SingleChoiceProposal {
+            id: i,
+            msgs: vec![correct_proposal_msg()],
+            status: statuses[(i as usize - 1) % statuses.len()],
+            // proposal 5 is still within its minimum timelock duration
+            timelocked_until: match i {
+                5 => Some(Expiration::AtHeight(env.block.height + 1)),
+                _ => None,
+            },
+            msg_statuses: vec![],
+            execution_deadline: None,
+        };
+        PROPOSALS.save(deps.as_mut().storage, i, &prop).unwrap();
+        update_proposal_status(deps.as_mut().storage, i, None, prop.status).unwrap();
+    }
+    let ids = |res: Binary| {
+        from_json::<ProposalListResponse>(res)
+            .unwrap()
+            .proposals
+            .iter()
+            .map(|p| p.id)
+            .collect::<Vec<u64>>()
+    };
+
+    let query_msg = QueryMsg::ListProposals {
+        start_after: Some(1),
+        limit: None,
+        status: Some(ProposalStatus::Executed),
+    };
+    let res = query(deps.as_ref(), env.clone(), query_msg).unwrap();
+    assert_eq!(vec![3, 7], ids(res));
+
+    let query_msg = QueryMsg::ProposalCount {
+        status: Some(ProposalStatus::Timelocked),
+    };
+    let res = query(deps.as_ref(), env.clone(), query_msg).unwrap();
+    assert_eq!(2, from_json::<u64>(res).unwrap());
+
+    let query_msg = QueryMsg::ProposalCount { status: None };
+    let res = query(deps.as_ref(), env.clone(), query_msg).unwrap();
+    assert_eq!(8, from_json::<u64>(res).unwrap());
+
+    let query_msg = QueryMsg::ReverseProposals {
+        start_before: None,
+        limit: Some(3),
+        status: None,
+    };
+    let res = query(deps.as_ref(), env.clone(), query_msg).unwrap();
+    assert_eq!(vec![8, 7, 6], ids(res.clone()));
+    assert_eq!(
+        Some(6),
+        from_json::<ProposalListResponse>(res).unwrap().last_scanned
+    );
+
+    let query_msg = QueryMsg::ReverseProposals {
+        start_before: Some(8),
+        limit: None,
+        status: Some(ProposalStatus::Overruled),
+    };
+    let res = query(deps.as_ref(), env.clone(), query_msg).unwrap();
+    assert_eq!(vec![6, 2], ids(res.clone()));
+    assert_eq!(
+        None,
+        from_json::<ProposalListResponse>(res).unwrap().last_scanned
+    );
+
+    let query_msg = QueryMsg::ExecutableProposals {
+        start_after: None,
+        limit: None,
+    };
+    let res = query(deps.as_ref(), env.clone(), query_msg.clone()).unwrap();
+    assert_eq!(vec![1], ids(res));
+
+    // only `limit` timelocked proposals are looked at, the cursor tells where to continue
+    let last_scanned = |res: Binary| from_json::<ProposalListResponse>(res).unwrap().last_scanned;
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::ExecutableProposals {
+            start_after: None,
+            limit: Some(1),
+        },
+    )
+    .unwrap();
+    assert_eq!(vec![1], ids(res.clone()));
+    assert_eq!(Some(1), last_scanned(res));
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::ExecutableProposals {
+            start_after: Some(1),
+            limit: Some(1),
+        },
+    )
+    .unwrap();
+    assert!(ids(res.clone()).is_empty());
+    assert_eq!(Some(5), last_scanned(res));
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::ExecutableProposals {
+            start_after: Some(5),
+            limit: Some(1),
+        },
+    )
+    .unwrap();
+    assert!(ids(res.clone()).is_empty());
+    assert_eq!(None, last_scanned(res));
+
+    let mut later_env = env.clone();
+    later_env.block.height += 1;
+    let res = query(deps.as_ref(), later_env, query_msg.clone()).unwrap();
+    assert_eq!(vec![1, 5], ids(res));
+
+    // nothing is executable while the overrule voting is open
+    {
+        let mut data_mut_ref = overrule_proposal_status.borrow_mut();
+        *data_mut_ref = Status::Open;
+    }
+    let res = query(deps.as_ref(), env, query_msg).unwrap();
+    assert!(ids(res).is_empty());
+}
+
 #[test]
 fn test_reply() {
     let overrule_proposal_status = Rc::new(RefCell::new(Status::Open));
     let mut deps = mock_dependencies(Rc::clone(&overrule_proposal_status));
     let msg = Reply {
-        id: 10,
+        id: mask_msg_execution_reply_id(10, 0),
         result: SubMsgResult::Err("error".to_string()),
     };
     let err = reply(deps.as_mut(), mock_env(), msg.clone()).unwrap_err();
@@ -670,23 +1352,185 @@ fn test_reply() {
         id: 10,
         msgs: vec![correct_proposal_msg()],
         status: ProposalStatus::Timelocked,
+        timelocked_until: None,
+        msg_statuses: vec![],
+        execution_deadline: None,
     };
     let env = mock_env();
     PROPOSALS.save(deps.as_mut().storage, 10, &prop).unwrap();
     let res_ok = reply(deps.as_mut(), env, msg).unwrap();
     assert_eq!(0, res_ok.messages.len());
-    let expected_attributes = vec![Attribute::new("timelocked_proposal_execution_failed", "10")];
+    let expected_attributes = vec![
+        Attribute::new("timelocked_proposal_execution_failed", "10"),
+        Attribute::new("failed_msg_index", "0"),
+    ];
     assert_eq!(expected_attributes, res_ok.attributes);
     // reply writes the failed proposal error
     let query_res = query_proposal_execution_error(deps.as_ref(), 10).unwrap();
     let error: Option<String> = from_json(query_res).unwrap();
     assert_eq!(error, Some("error".to_string()));
+    let updated_prop = PROPOSALS.load(deps.as_mut().storage, 10).unwrap();
+    assert_eq!(ProposalStatus::ExecutionFailed, updated_prop.status);
+}
+
+#[test]
+fn test_retry_failed_messages() {
+    let overrule_proposal_status = Rc::new(RefCell::new(Status::Rejected));
+    let mut deps = mock_dependencies(Rc::clone(&overrule_proposal_status));
+    deps.querier.set_close_proposal_on_execution_failure(true);
+    let env = mock_env();
+    let info = mock_info("neutron1unknownsender", &[]);
+
+    let config = Config {
+        owner: Addr::unchecked("owner"),
+        overrule_pre_propose: Addr::unchecked(MOCK_OVERRULE_PREPROPOSAL),
+        subdao: Addr::unchecked(MOCK_SUBDAO_CORE_ADDR),
+        min_timelock_duration: None,
+        execution_bounty: None,
+        execution_deadline: None,
+    };
+    CONFIG.save(deps.as_mut().storage, &config).unwrap();
+
+    let inner_msgs = vec![inner_msg(1), inner_msg(2), inner_msg(3)];
+    let msg = ExecuteMsg::TimelockProposal {
+        proposal_id: 1,
+        msgs: vec![timelocked_msgs(inner_msgs.clone())],
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MOCK_SUBDAO_CORE_ADDR, &[]),
+        msg,
+    )
+    .unwrap();
+
+    // nothing has failed yet
+    let msg = ExecuteMsg::RetryFailedMessages { proposal_id: 1 };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap_err();
+    assert_eq!("Wrong proposal status (timelocked)", err.to_string());
+
+    // every inner message is executed separately
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ExecuteMsg::ExecuteProposal { proposal_id: 1 },
+    )
+    .unwrap();
+    assert_eq!(
+        inner_msgs
+            .iter()
+            .enumerate()
+            .map(|(i, msg)| SubMsg::reply_on_error(
+                timelocked_msgs(vec![msg.clone()]),
+                mask_msg_execution_reply_id(1, i as u32)
+            ))
+            .collect::<Vec<SubMsg<NeutronMsg>>>(),
+        res.messages
+    );
+
+    // the second message fails
+    reply(
+        deps.as_mut(),
+        env.clone(),
+        Reply {
+            id: mask_msg_execution_reply_id(1, 1),
+            result: SubMsgResult::Err("codespace=bank code=5".to_string()),
+        },
+    )
+    .unwrap();
+    let prop = PROPOSALS.load(deps.as_mut().storage, 1).unwrap();
+    assert_eq!(ProposalStatus::ExecutionFailed, prop.status);
+
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::ProposalMessages { proposal_id: 1 },
+    )
+    .unwrap();
+    let queried: ProposalMessagesResponse = from_json(res).unwrap();
+    assert_eq!(
+        vec![
+            ProposalMessage {
+                index: 0,
+                msg: inner_msg(1),
+                status: MessageStatus::Executed,
+                error: None,
+            },
+            ProposalMessage {
+                index: 1,
+                msg: inner_msg(2),
+                status: MessageStatus::ExecutionFailed,
+                error: Some("codespace=bank code=5".to_string()),
+            },
+            ProposalMessage {
+                index: 2,
+                msg: inner_msg(3),
+                status: MessageStatus::Executed,
+                error: None,
+            },
+        ],
+        queried.messages
+    );
+
+    // failed messages can't be retried once the execution deadline has passed
+    let mut stale_prop = prop.clone();
+    stale_prop.execution_deadline = Some(Expiration::AtHeight(env.block.height));
+    PROPOSALS
+        .save(deps.as_mut().storage, 1, &stale_prop)
+        .unwrap();
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap_err();
+    assert_eq!(
+        "Execution deadline of proposal (1) has passed, its failed messages can't be retried",
+        err.to_string()
+    );
+    PROPOSALS.save(deps.as_mut().storage, 1, &prop).unwrap();
+
+    // only the failed message is re-executed
+    let res = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
+    assert_eq!(
+        vec![SubMsg::reply_on_error(
+            timelocked_msgs(vec![inner_msg(2)]),
+            mask_msg_execution_reply_id(1, 1)
+        )],
+        res.messages
+    );
+    let prop = PROPOSALS.load(deps.as_mut().storage, 1).unwrap();
+    assert_eq!(ProposalStatus::Executed, prop.status);
+    assert_eq!(vec![MessageStatus::Executed; 3], prop.msg_statuses);
+
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::ProposalMessages { proposal_id: 1 },
+    )
+    .unwrap();
+    let queried: ProposalMessagesResponse = from_json(res).unwrap();
+    assert_eq!(None, queried.messages[1].error);
+
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert_eq!("Wrong proposal status (executed)", err.to_string());
+}
+
+fn proposal_count(deps: Deps, status: Option<ProposalStatus>) -> u64 {
+    from_json(query(deps, mock_env(), QueryMsg::ProposalCount { status }).unwrap()).unwrap()
 }
 
 fn correct_proposal_msg() -> CosmosMsg<NeutronMsg> {
+    timelocked_msgs(vec![inner_msg(1)])
+}
+
+fn timelocked_msgs(msgs: Vec<CosmosMsg<NeutronMsg>>) -> CosmosMsg<NeutronMsg> {
     CosmosMsg::Wasm(WasmMsg::Execute {
         contract_addr: "".to_string(),
-        msg: to_json_binary(&CoreExecuteMsg::ExecuteTimelockedMsgs { msgs: vec![] }).unwrap(),
+        msg: to_json_binary(&CoreExecuteMsg::ExecuteTimelockedMsgs { msgs }).unwrap(),
         funds: vec![],
     })
 }
+
+fn inner_msg(amount: u128) -> CosmosMsg<NeutronMsg> {
+    CosmosMsg::Bank(BankMsg::Send {
+        to_address: "neutron1receiver".to_string(),
+        amount: coins(amount, "untrn"),
+    })
+}
@@ -26,7 +26,10 @@ use cwd_voting::status::Status;
 use cwd_voting::threshold::Threshold;
 use cwd_voting::voting::{get_total_power, get_voting_power, validate_voting_period, Vote, Votes};
 use neutron_sdk::bindings::msg::NeutronMsg;
-use neutron_subdao_pre_propose_single::msg::ExecuteMsg as PreProposeExecuteMsg;
+use neutron_subdao_pre_propose_single::msg::{
+    ExecuteMsg as PreProposeExecuteMsg, QueryExt as PreProposeQueryExt,
+    QueryMsg as PreProposeQueryMsg,
+};
 use neutron_subdao_proposal_single::{
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
     proposal::SingleChoiceProposal,
@@ -127,6 +130,9 @@ pub fn execute(
         ExecuteMsg::RemoveVoteHook { address } => {
             execute_remove_vote_hook(deps, env, info, address)
         }
+        ExecuteMsg::TimelockedProposalExpired { proposal_id } => {
+            execute_timelocked_proposal_expired(deps, info, proposal_id)
+        }
     }
 }
 
@@ -345,6 +351,10 @@ pub fn execute_vote(
     if prop.current_status(&env.block) != Status::Open {
         return Err(ContractError::NotOpen { id: proposal_id });
     }
+    // SubDAO proposals can't be vetoed, so such a vote would silently count as a no.
+    if vote == Vote::NoWithVeto {
+        return Err(ContractError::VetoNotSupported {});
+    }
 
     let vote_power = get_voting_power(
         deps.as_ref(),
@@ -478,6 +488,54 @@ pub fn execute_close(
         .add_attribute("proposal_id", proposal_id.to_string()))
 }
 
+pub fn execute_timelocked_proposal_expired(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    // Only the timelock module of the pre-propose module may expire proposals.
+    let timelock: Addr = match CREATION_POLICY.load(deps.storage)? {
+        ProposalCreationPolicy::Anyone {} => return Err(ContractError::Unauthorized {}),
+        ProposalCreationPolicy::Module { addr } => deps.querier.query_wasm_smart(
+            addr,
+            &PreProposeQueryMsg::QueryExtension {
+                msg: PreProposeQueryExt::TimelockAddress {},
+            },
+        )?,
+    };
+    if info.sender != timelock {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    // Proposals are sent to the timelock when they are executed.
+    if prop.status != Status::Executed {
+        return Err(ContractError::NotExecuted {});
+    }
+
+    let old_status = prop.status;
+
+    prop.status = Status::Expired;
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    let hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        prop.status.to_string(),
+    )?;
+
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_attribute("action", "timelocked_proposal_expired")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn execute_update_config(
     deps: DepsMut,
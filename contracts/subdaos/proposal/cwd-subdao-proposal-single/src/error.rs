@@ -44,12 +44,18 @@ pub enum ContractError {
     #[error("already cast a vote with that option. change your vote to revote")]
     AlreadyCast {},
 
+    #[error("this proposal module has no veto threshold, no_with_veto votes are not supported")]
+    VetoNotSupported {},
+
     #[error("proposal is not in 'passed' state")]
     NotPassed {},
 
     #[error("only rejected proposals may be closed")]
     WrongCloseStatus {},
 
+    #[error("proposal is not in 'executed' state")]
+    NotExecuted {},
+
     #[error(
         "pre-propose modules must specify a proposer. lacking one, no proposer should be specified"
     )]
@@ -34,7 +34,7 @@ pub enum PreProposeError {
     #[error("Nothing to withdraw")]
     NothingToWithdraw {},
 
-    #[error("Proposal status ({status}) not closed or executed")]
+    #[error("Proposal status ({status}) not closed, executed or vetoed")]
     NotClosedOrExecuted { status: Status },
 
     #[error("Multiple voting modules during instantiation.")]
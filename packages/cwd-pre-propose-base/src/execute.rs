@@ -245,7 +245,10 @@ where
         }
 
         // These are the only proposal statuses we handle deposits for.
-        if new_status != Status::Closed && new_status != Status::Executed {
+        if new_status != Status::Closed
+            && new_status != Status::Executed
+            && new_status != Status::Vetoed
+        {
             return Err(PreProposeError::NotClosedOrExecuted { status: new_status });
         }
 
@@ -253,7 +256,8 @@ where
             Some((deposit_info, proposer)) => {
                 let messages = if let Some(ref deposit_info) = deposit_info {
                     // Refund can be issued if proposal if it is going to
-                    // closed or executed.
+                    // closed or executed. Deposits of vetoed proposals
+                    // are always slashed.
                     let should_refund_to_proposer = (new_status == Status::Closed
                         && deposit_info.refund_policy == DepositRefundPolicy::Always)
                         || (new_status == Status::Executed
@@ -0,0 +1,247 @@
+//! Vote delegation for proposal modules. A delegator may delegate
+//! its vote to a representative which then votes with the summed
+//! voting power of its delegators. Delegations are snapshotted at
+//! every height so that votes on a proposal use the delegations as
+//! of the proposal's start height. Delegation is not transitive.
+
+use cosmwasm_std::{Addr, Deps, StdResult, Storage, Uint128};
+use cw_storage_plus::{SnapshotMap, Strategy};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::VotingError;
+use crate::voting::get_voting_power;
+
+/// The maximum number of delegators a delegate may have. Voting
+/// queries the voting power of every delegator, so this bounds the
+/// gas cost of a delegate's vote.
+pub const MAX_DELEGATORS: usize = 50;
+
+/// Maps a delegator to its delegate.
+pub const DELEGATIONS: SnapshotMap<&Addr, Addr> = SnapshotMap::new(
+    "delegations",
+    "delegations__checkpoints",
+    "delegations__changelog",
+    Strategy::EveryBlock,
+);
+/// Maps a delegate to its delegators.
+pub const DELEGATORS: SnapshotMap<&Addr, Vec<Addr>> = SnapshotMap::new(
+    "delegators",
+    "delegators__checkpoints",
+    "delegators__changelog",
+    Strategy::EveryBlock,
+);
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DelegationResponse {
+    /// The delegate of the delegator, if any.
+    pub delegate: Option<Addr>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DelegatorsResponse {
+    pub delegators: Vec<Addr>,
+}
+
+/// Delegates the vote of DELEGATOR to DELEGATE, replacing its
+/// previous delegation if there is one.
+pub fn delegate(
+    storage: &mut dyn Storage,
+    height: u64,
+    delegator: &Addr,
+    delegate: &Addr,
+) -> Result<(), VotingError> {
+    if delegator == delegate {
+        return Err(VotingError::SelfDelegation {});
+    }
+    match DELEGATIONS.may_load(storage, delegator)? {
+        Some(current) if &current == delegate => {
+            return Err(VotingError::AlreadyDelegated {
+                delegate: current.into_string(),
+            })
+        }
+        Some(current) => remove_delegator(storage, height, &current, delegator)?,
+        None => (),
+    }
+
+    let mut delegators = DELEGATORS.may_load(storage, delegate)?.unwrap_or_default();
+    if delegators.len() >= MAX_DELEGATORS {
+        return Err(VotingError::TooManyDelegators {
+            max: MAX_DELEGATORS as u64,
+        });
+    }
+    delegators.push(delegator.clone());
+    DELEGATORS.save(storage, delegate, &delegators, height)?;
+    DELEGATIONS.save(storage, delegator, delegate, height)?;
+    Ok(())
+}
+
+/// Checks that DELEGATOR has voting power in DAO at HEIGHT. A
+/// delegator without voting power adds nothing to the vote of its
+/// delegate, but would still take one of its MAX_DELEGATORS slots.
+pub fn check_delegator_power(
+    deps: Deps,
+    delegator: &Addr,
+    dao: Addr,
+    height: u64,
+) -> Result<(), VotingError> {
+    if get_voting_power(deps, delegator.clone(), dao, Some(height))?.is_zero() {
+        return Err(VotingError::NoDelegatorPower {});
+    }
+    Ok(())
+}
+
+/// Removes the delegation of DELEGATOR and returns its former
+/// delegate.
+pub fn undelegate(
+    storage: &mut dyn Storage,
+    height: u64,
+    delegator: &Addr,
+) -> Result<Addr, VotingError> {
+    let delegate = DELEGATIONS
+        .may_load(storage, delegator)?
+        .ok_or(VotingError::NotDelegated {})?;
+    remove_delegator(storage, height, &delegate, delegator)?;
+    DELEGATIONS.remove(storage, delegator, height)?;
+    Ok(delegate)
+}
+
+fn remove_delegator(
+    storage: &mut dyn Storage,
+    height: u64,
+    delegate: &Addr,
+    delegator: &Addr,
+) -> StdResult<()> {
+    let mut delegators = DELEGATORS.may_load(storage, delegate)?.unwrap_or_default();
+    delegators.retain(|d| d != delegator);
+    if delegators.is_empty() {
+        DELEGATORS.remove(storage, delegate, height)
+    } else {
+        DELEGATORS.save(storage, delegate, &delegators, height)
+    }
+}
+
+/// Returns the delegate of DELEGATOR at HEIGHT. A height of None
+/// returns the current delegate.
+pub fn delegate_at_height(
+    storage: &dyn Storage,
+    delegator: &Addr,
+    height: Option<u64>,
+) -> StdResult<Option<Addr>> {
+    match height {
+        Some(height) => DELEGATIONS.may_load_at_height(storage, delegator, height),
+        None => DELEGATIONS.may_load(storage, delegator),
+    }
+}
+
+/// Returns the delegators of DELEGATE at HEIGHT. A height of None
+/// returns the current delegators.
+pub fn delegators_at_height(
+    storage: &dyn Storage,
+    delegate: &Addr,
+    height: Option<u64>,
+) -> StdResult<Vec<Addr>> {
+    Ok(match height {
+        Some(height) => DELEGATORS.may_load_at_height(storage, delegate, height)?,
+        None => DELEGATORS.may_load(storage, delegate)?,
+    }
+    .unwrap_or_default())
+}
+
+/// Sums the voting power at HEIGHT of the delegators of DELEGATE at
+/// HEIGHT, skipping delegators for which HAS_VOTED returns true as
+/// they override their delegate by voting directly.
+pub fn get_delegated_power(
+    deps: Deps,
+    dao: Addr,
+    delegate: &Addr,
+    height: u64,
+    has_voted: impl Fn(&Addr) -> bool,
+) -> StdResult<Uint128> {
+    delegators_at_height(deps.storage, delegate, Some(height))?
+        .into_iter()
+        .filter(|delegator| !has_voted(delegator))
+        .try_fold(Uint128::zero(), |power, delegator| {
+            Ok(power + get_voting_power(deps, delegator, dao.clone(), Some(height))?)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn delegation_snapshots() {
+        let mut deps = mock_dependencies();
+        let storage = deps.as_mut().storage;
+        let alice = Addr::unchecked("alice");
+        let bob = Addr::unchecked("bob");
+        let carol = Addr::unchecked("carol");
+
+        assert_eq!(
+            delegate(storage, 10, &alice, &alice),
+            Err(VotingError::SelfDelegation {})
+        );
+        delegate(storage, 10, &alice, &bob).unwrap();
+        assert_eq!(
+            delegate(storage, 10, &alice, &bob),
+            Err(VotingError::AlreadyDelegated {
+                delegate: "bob".to_string()
+            })
+        );
+        delegate(storage, 12, &alice, &carol).unwrap();
+
+        // Delegations take effect from the next height.
+        assert_eq!(delegate_at_height(storage, &alice, Some(10)).unwrap(), None);
+        assert_eq!(
+            delegate_at_height(storage, &alice, Some(11)).unwrap(),
+            Some(bob.clone())
+        );
+        assert_eq!(
+            delegators_at_height(storage, &bob, Some(12)).unwrap(),
+            vec![alice.clone()]
+        );
+        assert_eq!(
+            delegators_at_height(storage, &bob, None).unwrap(),
+            Vec::<Addr>::new()
+        );
+        assert_eq!(
+            delegators_at_height(storage, &carol, None).unwrap(),
+            vec![alice.clone()]
+        );
+
+        assert_eq!(undelegate(storage, 14, &alice).unwrap(), carol);
+        assert_eq!(
+            undelegate(storage, 14, &alice),
+            Err(VotingError::NotDelegated {})
+        );
+        assert_eq!(
+            delegate_at_height(storage, &alice, Some(14)).unwrap(),
+            Some(carol.clone())
+        );
+        assert_eq!(delegate_at_height(storage, &alice, Some(15)).unwrap(), None);
+    }
+
+    #[test]
+    fn max_delegators() {
+        let mut deps = mock_dependencies();
+        let storage = deps.as_mut().storage;
+        let delegate_addr = Addr::unchecked("delegate");
+        for i in 0..MAX_DELEGATORS {
+            delegate(
+                storage,
+                1,
+                &Addr::unchecked(format!("delegator{i}")),
+                &delegate_addr,
+            )
+            .unwrap();
+        }
+        assert_eq!(
+            delegate(storage, 1, &Addr::unchecked("one_more"), &delegate_addr),
+            Err(VotingError::TooManyDelegators {
+                max: MAX_DELEGATORS as u64
+            })
+        );
+    }
+}
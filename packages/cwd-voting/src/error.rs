@@ -11,4 +11,22 @@ pub enum VotingError {
 
     #[error("Min voting period must be less than or equal to max voting period")]
     InvalidMinVotingPeriod {},
+
+    #[error("Weighted vote must have non-zero weights for distinct positions that sum up to one")]
+    InvalidWeightedVote {},
+
+    #[error("Can not delegate to self")]
+    SelfDelegation {},
+
+    #[error("Already delegated to ({delegate})")]
+    AlreadyDelegated { delegate: String },
+
+    #[error("Not delegated")]
+    NotDelegated {},
+
+    #[error("Delegate can not have more than ({max}) delegators")]
+    TooManyDelegators { max: u64 },
+
+    #[error("Can not delegate without voting power")]
+    NoDelegatorPower {},
 }
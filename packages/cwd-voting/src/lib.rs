@@ -1,3 +1,4 @@
+pub mod delegation;
 pub mod deposit;
 pub mod error;
 pub mod multiple_choice;
@@ -18,6 +18,12 @@ pub enum Status {
     Closed,
     /// The proposal's execution failed.
     ExecutionFailed,
+    /// The proposal has been executed into a timelock, but expired
+    /// there before its messages were executed.
+    Expired,
+    /// The proposal's no with veto votes crossed its veto threshold.
+    /// A proposal deposit has been slashed if applicable.
+    Vetoed,
 }
 
 impl std::fmt::Display for Status {
@@ -29,6 +35,8 @@ impl std::fmt::Display for Status {
             Status::Executed => write!(f, "executed"),
             Status::Closed => write!(f, "closed"),
             Status::ExecutionFailed => write!(f, "execution_failed"),
+            Status::Expired => write!(f, "expired"),
+            Status::Vetoed => write!(f, "vetoed"),
         }
     }
 }
@@ -11,6 +11,9 @@ pub enum ThresholdError {
 
     #[error("Not possible to reach required (passing) threshold")]
     UnreachableThreshold {},
+
+    #[error("Dynamic quorum must satisfy 0 < min_quorum <= quorum <= max_quorum <= 1")]
+    InvalidQuorumBounds {},
 }
 
 /// A percentage of voting power that must vote yes for a proposal to
@@ -68,10 +71,22 @@ pub enum Threshold {
     /// An absolute number of votes needed for something to cross the
     /// threshold. Useful for multisig style voting.
     AbsoluteCount { threshold: Uint128 },
+
+    /// Like `ThresholdQuorum`, but the quorum follows the turnout of
+    /// recent proposals within `min_quorum` and `max_quorum`.
+    /// Proposal modules that track turnout set the `quorum` of each
+    /// proposal when it is created, otherwise `quorum` is used as
+    /// is.
+    DynamicQuorum {
+        threshold: PercentageThreshold,
+        quorum: PercentageThreshold,
+        min_quorum: Decimal,
+        max_quorum: Decimal,
+    },
 }
 
 /// Asserts that the 0.0 < percent <= 1.0
-fn validate_percentage(percent: &PercentageThreshold) -> Result<(), ThresholdError> {
+pub fn validate_percentage(percent: &PercentageThreshold) -> Result<(), ThresholdError> {
     if let PercentageThreshold::Percent(percent) = percent {
         if percent.is_zero() {
             Err(ThresholdError::ZeroThreshold {})
@@ -121,6 +136,52 @@ impl Threshold {
                     Ok(())
                 }
             }
+            Threshold::DynamicQuorum {
+                threshold,
+                quorum,
+                min_quorum,
+                max_quorum,
+            } => {
+                validate_percentage(threshold)?;
+                validate_quorum(quorum)?;
+                let within_bounds = match quorum {
+                    PercentageThreshold::Majority {} => true,
+                    PercentageThreshold::Percent(quorum) => {
+                        min_quorum <= quorum && quorum <= max_quorum
+                    }
+                };
+                // A zero quorum would let proposals pass without votes
+                // once turnout drops.
+                if within_bounds
+                    && !min_quorum.is_zero()
+                    && min_quorum <= max_quorum
+                    && *max_quorum <= Decimal::one()
+                {
+                    Ok(())
+                } else {
+                    Err(ThresholdError::InvalidQuorumBounds {})
+                }
+            }
+        }
+    }
+
+    /// Sets the quorum of a dynamic quorum threshold to TURNOUT
+    /// bounded by its floor and ceiling. Other thresholds are
+    /// returned unchanged.
+    pub fn with_turnout(self, turnout: Decimal) -> Self {
+        match self {
+            Threshold::DynamicQuorum {
+                threshold,
+                quorum: _,
+                min_quorum,
+                max_quorum,
+            } => Threshold::DynamicQuorum {
+                threshold,
+                quorum: PercentageThreshold::Percent(turnout.max(min_quorum).min(max_quorum)),
+                min_quorum,
+                max_quorum,
+            },
+            threshold => threshold,
         }
     }
 }
@@ -181,5 +242,68 @@ mod tests {
             t.validate().unwrap_err(),
             ThresholdError::UnreachableThreshold {}
         );
+
+        let t = Threshold::DynamicQuorum {
+            threshold: p!(50),
+            quorum: p!(20),
+            min_quorum: Decimal::percent(10),
+            max_quorum: Decimal::percent(40),
+        };
+        t.validate().unwrap();
+
+        let t = Threshold::DynamicQuorum {
+            threshold: p!(50),
+            quorum: p!(5),
+            min_quorum: Decimal::percent(10),
+            max_quorum: Decimal::percent(40),
+        };
+        assert_eq!(
+            t.validate().unwrap_err(),
+            ThresholdError::InvalidQuorumBounds {}
+        );
+
+        let t = Threshold::DynamicQuorum {
+            threshold: p!(50),
+            quorum: p!(20),
+            min_quorum: Decimal::percent(10),
+            max_quorum: Decimal::percent(101),
+        };
+        assert_eq!(
+            t.validate().unwrap_err(),
+            ThresholdError::InvalidQuorumBounds {}
+        );
+
+        let t = Threshold::DynamicQuorum {
+            threshold: p!(50),
+            quorum: p!(20),
+            min_quorum: Decimal::zero(),
+            max_quorum: Decimal::percent(40),
+        };
+        assert_eq!(
+            t.validate().unwrap_err(),
+            ThresholdError::InvalidQuorumBounds {}
+        );
+    }
+
+    #[test]
+    fn test_dynamic_quorum_turnout() {
+        let t = Threshold::DynamicQuorum {
+            threshold: p!(50),
+            quorum: p!(20),
+            min_quorum: Decimal::percent(10),
+            max_quorum: Decimal::percent(40),
+        };
+        let quorum = |turnout| match t.clone().with_turnout(turnout) {
+            Threshold::DynamicQuorum { quorum, .. } => quorum,
+            _ => panic!("threshold kind changed"),
+        };
+        assert_eq!(quorum(Decimal::percent(25)), p!(25));
+        assert_eq!(quorum(Decimal::percent(5)), p!(10));
+        assert_eq!(quorum(Decimal::percent(90)), p!(40));
+
+        let t = Threshold::AbsoluteCount {
+            threshold: Uint128::new(3),
+        };
+        assert_eq!(t.clone().with_turnout(Decimal::percent(25)), t);
     }
 }
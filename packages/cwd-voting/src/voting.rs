@@ -4,6 +4,7 @@ use cwd_interface::voting;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::error::VotingError;
 use crate::threshold::PercentageThreshold;
 
 // We multiply by this when calculating needed_votes in order to round
@@ -15,10 +16,13 @@ pub struct Votes {
     pub yes: Uint128,
     pub no: Uint128,
     pub abstain: Uint128,
+    /// The part of the no votes that was cast with veto.
+    #[serde(default)]
+    pub no_with_veto: Uint128,
 }
 
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, JsonSchema, Debug)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 #[repr(u8)]
 pub enum Vote {
     /// Marks support for the proposal.
@@ -28,6 +32,64 @@ pub enum Vote {
     /// Marks participation but does not count towards the ratio of
     /// support / opposed.
     Abstain,
+    /// Marks opposition to the proposal and counts towards its veto
+    /// threshold.
+    NoWithVeto,
+}
+
+/// A share of the voting power given to a position in a weighted vote.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, JsonSchema, Debug)]
+pub struct WeightedVoteOption {
+    pub vote: Vote,
+    pub weight: Decimal,
+}
+
+/// Checks that the weighted vote options are not empty, have distinct
+/// positions and non-zero weights that sum up to one.
+pub fn validate_weighted_vote(options: &[WeightedVoteOption]) -> Result<(), VotingError> {
+    let mut sum = Decimal::zero();
+    for (i, option) in options.iter().enumerate() {
+        if option.weight.is_zero() || options[..i].iter().any(|o| o.vote == option.vote) {
+            return Err(VotingError::InvalidWeightedVote {});
+        }
+        sum = sum
+            .checked_add(option.weight)
+            .map_err(|_| VotingError::InvalidWeightedVote {})?;
+    }
+    if sum != Decimal::one() {
+        return Err(VotingError::InvalidWeightedVote {});
+    }
+    Ok(())
+}
+
+/// Splits the voting power between the weighted vote options. Shares
+/// are rounded down and the rounding remainder goes to the last
+/// option, so the shares always sum up to the voting power.
+pub fn split_weighted_power(
+    options: &[WeightedVoteOption],
+    power: Uint128,
+) -> Vec<(Vote, Uint128)> {
+    let mut remaining = power;
+    let mut shares: Vec<(Vote, Uint128)> = Vec::with_capacity(options.len());
+    for (i, option) in options.iter().enumerate() {
+        let share = if i + 1 == options.len() {
+            remaining
+        } else {
+            (power * option.weight).min(remaining)
+        };
+        remaining -= share;
+        shares.push((option.vote, share));
+    }
+    shares
+}
+
+/// Formats the weighted vote options as `yes=0.6,no=0.4`.
+pub fn weighted_vote_to_string(options: &[WeightedVoteOption]) -> String {
+    options
+        .iter()
+        .map(|o| format!("{}={}", o.vote, o.weight))
+        .collect::<Vec<_>>()
+        .join(",")
 }
 
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, JsonSchema, Debug)]
@@ -180,6 +242,7 @@ impl Votes {
             yes: Uint128::zero(),
             no: Uint128::zero(),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         }
     }
 
@@ -191,6 +254,7 @@ impl Votes {
             yes,
             no: Uint128::zero(),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         }
     }
 
@@ -200,6 +264,10 @@ impl Votes {
             Vote::Yes => self.yes += power,
             Vote::No => self.no += power,
             Vote::Abstain => self.abstain += power,
+            Vote::NoWithVeto => {
+                self.no += power;
+                self.no_with_veto += power;
+            }
         }
     }
 
@@ -211,10 +279,32 @@ impl Votes {
             Vote::Yes => self.yes -= power,
             Vote::No => self.no -= power,
             Vote::Abstain => self.abstain -= power,
+            Vote::NoWithVeto => {
+                self.no -= power;
+                self.no_with_veto -= power;
+            }
+        }
+    }
+
+    /// Adds a vote with the power split between the weighted vote
+    /// options.
+    pub fn add_weighted_vote(&mut self, options: &[WeightedVoteOption], power: Uint128) {
+        for (vote, share) in split_weighted_power(options, power) {
+            self.add_vote(vote, share);
         }
     }
 
-    /// Computes the total number of votes cast.
+    /// Removes a weighted vote from the votes. The vote being removed
+    /// must have been previously added with the same power or this
+    /// method will cause an overflow.
+    pub fn remove_weighted_vote(&mut self, options: &[WeightedVoteOption], power: Uint128) {
+        for (vote, share) in split_weighted_power(options, power) {
+            self.remove_vote(vote, share);
+        }
+    }
+
+    /// Computes the total number of votes cast. No with veto votes
+    /// are part of the no votes.
     ///
     /// NOTE: The total number of votes avaliable from a voting module
     /// is a `Uint128`. As it is not possible to vote twice we know
@@ -232,6 +322,7 @@ impl std::fmt::Display for Vote {
             Vote::Yes => write!(f, "yes"),
             Vote::No => write!(f, "no"),
             Vote::Abstain => write!(f, "abstain"),
+            Vote::NoWithVeto => write!(f, "no_with_veto"),
         }
     }
 }
@@ -302,6 +393,61 @@ mod test {
         assert_eq!(votes.abstain, Uint128::new(40));
     }
 
+    #[test]
+    fn count_weighted_votes() {
+        let options = vec![
+            WeightedVoteOption {
+                vote: Vote::Yes,
+                weight: Decimal::percent(60),
+            },
+            WeightedVoteOption {
+                vote: Vote::No,
+                weight: Decimal::percent(30),
+            },
+            WeightedVoteOption {
+                vote: Vote::Abstain,
+                weight: Decimal::percent(10),
+            },
+        ];
+        assert!(validate_weighted_vote(&options).is_ok());
+        assert_eq!(
+            weighted_vote_to_string(&options),
+            "yes=0.6,no=0.3,abstain=0.1"
+        );
+
+        let mut votes = Votes::zero();
+        votes.add_weighted_vote(&options, Uint128::new(7));
+        // Rounding remainders go to the last option.
+        assert_eq!(votes.yes, Uint128::new(4));
+        assert_eq!(votes.no, Uint128::new(2));
+        assert_eq!(votes.abstain, Uint128::new(1));
+        assert_eq!(votes.total(), Uint128::new(7));
+
+        votes.add_vote(Vote::Yes, Uint128::new(3));
+        votes.remove_weighted_vote(&options, Uint128::new(7));
+        assert_eq!(votes, Votes::with_yes(Uint128::new(3)));
+    }
+
+    #[test]
+    fn invalid_weighted_votes() {
+        let option = |vote, percent| WeightedVoteOption {
+            vote,
+            weight: Decimal::percent(percent),
+        };
+        for options in [
+            vec![],
+            vec![option(Vote::Yes, 50), option(Vote::No, 40)],
+            vec![option(Vote::Yes, 50), option(Vote::Yes, 50)],
+            vec![option(Vote::Yes, 100), option(Vote::No, 0)],
+            vec![option(Vote::Yes, 60), option(Vote::No, 60)],
+        ] {
+            assert_eq!(
+                validate_weighted_vote(&options),
+                Err(VotingError::InvalidWeightedVote {})
+            );
+        }
+    }
+
     #[test]
     fn vote_comparisons() {
         assert!(!compare_vote_count(
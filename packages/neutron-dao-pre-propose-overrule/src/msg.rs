@@ -1,4 +1,7 @@
-use cwd_pre_propose_base::msg::{ExecuteMsg as ExecuteBase, QueryMsg as QueryBase};
+use cw_denom::UncheckedDenom;
+use cw_utils::Duration;
+use cwd_pre_propose_base::msg::QueryMsg as QueryBase;
+use cwd_voting::{deposit::UncheckedDepositInfo, status::Status, threshold::Threshold};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -9,9 +12,62 @@ pub enum ProposeMessage {
         timelock_contract: String,
         proposal_id: u64,
     },
+    /// Creates a single overrule proposal for several timelocked proposals
+    /// of the same timelock contract. Proposals that already have an open
+    /// overrule proposal are indexed to the new one.
+    ProposeBatchOverrule {
+        timelock_contract: String,
+        proposal_ids: Vec<u64>,
+    },
 }
 
-pub type ExecuteMsg = ExecuteBase<ProposeMessage>;
+/// Mirrors the base pre-propose `ExecuteMsg` and extends it with
+/// the overrule specific messages.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Creates a new overrule proposal.
+    Propose { msg: ProposeMessage },
+
+    /// Unsupported, kept for compatibility with the base pre-propose module.
+    UpdateConfig {
+        deposit_info: Option<UncheckedDepositInfo>,
+        open_proposal_submission: bool,
+    },
+
+    /// Unsupported, kept for compatibility with the base pre-propose module.
+    Withdraw { denom: Option<UncheckedDenom> },
+
+    /// Handles proposal hook fired by the associated proposal
+    /// module when a proposal is created.
+    ProposalCreatedHook { proposal_id: u64, proposer: String },
+
+    /// Handles proposal hook fired by the associated proposal
+    /// module when a proposal is completed (ie executed or rejected).
+    ProposalCompletedHook {
+        proposal_id: u64,
+        new_status: Status,
+    },
+
+    /// Sets the parameters of the overrule proposals created for the
+    /// proposals of the given timelock contract. `None` makes them use
+    /// the proposal module config again. Only the DAO may call this.
+    UpdateOverruleParams {
+        timelock_contract: String,
+        params: Option<OverruleParams>,
+    },
+}
+
+/// Parameters applied to the overrule proposals of a single timelock
+/// contract instead of the proposal module config.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct OverruleParams {
+    /// The threshold an overrule proposal must reach to pass.
+    pub threshold: Threshold,
+    /// The maximum amount of time an overrule proposal may be voted on.
+    pub max_voting_period: Duration,
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -24,6 +80,8 @@ pub enum QueryExt {
         timelock_address: String,
         subdao_proposal_id: u64,
     },
+    /// Returns the `Option<OverruleParams>` set for the timelock contract.
+    OverruleParams { timelock_address: String },
 }
 
 pub type QueryMsg = QueryBase<QueryExt>;
@@ -10,4 +10,10 @@ pub enum ContractError {
 
     #[error("config DAO URI cannot be empty.")]
     DaoUriIsEmpty {},
+
+    #[error("spending limit denom cannot be empty.")]
+    SpendingLimitDenomIsEmpty {},
+
+    #[error("spending limit epoch length cannot be zero.")]
+    ZeroEpochLength {},
 }
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 
 use cwd_macros::{info_query, pausable, pausable_query, voting_query};
 
-use crate::types::SubDao;
+use crate::types::{SpendingLimit, SubDao};
 
 /// Information about an item to be stored in the items list.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -93,6 +93,22 @@ pub enum ExecuteMsg {
         to_add: Vec<SubDao>,
         to_remove: Vec<String>,
     },
+    /// Callable by the main DAO only, even if the subDAO is paused. Pauses
+    /// the subDAO for `pause_duration` blocks, cancels the timelocked
+    /// proposals of all its timelock contracts and replaces the voting
+    /// module. If `proposal_modules` is set, all the active proposal
+    /// modules are disabled and replaced with the new ones.
+    EmergencyRecovery {
+        pause_duration: u64,
+        voting_module: ModuleInstantiateInfo,
+        proposal_modules: Option<Vec<ModuleInstantiateInfo>>,
+    },
+    /// Callable by the main DAO only. Sets and removes per-denom caps on the
+    /// funds the subDAO can send with `BankMsg::Send` and `WasmMsg` per epoch.
+    UpdateSpendingLimits {
+        to_set: Vec<SpendingLimit>,
+        to_remove: Vec<String>,
+    },
 }
 
 #[pausable_query]
@@ -158,6 +174,17 @@ pub enum QueryMsg {
     /// Returns proposal module address for timelock contract if it's correct
     #[returns(Addr)]
     TimelockProposalModuleAddress { timelock: String },
+    /// Returns the spending limit of the denom along with the budget left for
+    /// the current epoch. Returns `Option<SpendingBudgetResponse>`.
+    #[returns(Option<crate::types::SpendingBudgetResponse>)]
+    SpendingBudget { denom: String },
+    /// Lists the spending limits along with the budgets left for the current
+    /// epoch.
+    #[returns(Vec<crate::types::SpendingBudgetResponse>)]
+    SpendingBudgets {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
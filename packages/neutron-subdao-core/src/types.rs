@@ -93,6 +93,80 @@ impl Config {
     }
 }
 
+/// Caps the amount of a denom the subDAO can spend per epoch.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct SpendingLimit {
+    /// The native denom the limit is applied to.
+    pub denom: String,
+    /// The max amount of the denom that can be spent per epoch.
+    pub amount: Uint128,
+    /// The length of an epoch in blocks. Epochs start at heights
+    /// divisible by the epoch length.
+    pub epoch_length: u64,
+}
+
+impl SpendingLimit {
+    /// checks whether the spending limit fields are valid.
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if self.denom.is_empty() {
+            return Err(ContractError::SpendingLimitDenomIsEmpty {});
+        }
+        if self.epoch_length == 0 {
+            return Err(ContractError::ZeroEpochLength {});
+        }
+        Ok(())
+    }
+
+    /// Returns the number of the epoch the given height belongs to.
+    pub fn epoch(&self, height: u64) -> u64 {
+        height / self.epoch_length
+    }
+
+    /// Returns the amount spent in the epoch of the given height.
+    pub fn spent_at(&self, spent: Option<&SpentAmount>, height: u64) -> Uint128 {
+        match spent {
+            Some(spent) if spent.epoch == self.epoch(height) => spent.amount,
+            _ => Uint128::zero(),
+        }
+    }
+}
+
+/// The amount of a denom spent by the subDAO during an epoch.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct SpentAmount {
+    /// The number of the epoch.
+    pub epoch: u64,
+    /// The amount spent during the epoch.
+    pub amount: Uint128,
+}
+
+/// Returned by the `SpendingBudget` and `SpendingBudgets` queries.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct SpendingBudgetResponse {
+    /// The spending limit of the denom.
+    pub limit: SpendingLimit,
+    /// The amount spent during the current epoch.
+    pub spent: Uint128,
+    /// The amount that can still be spent during the current epoch.
+    pub remaining: Uint128,
+    /// The height the current epoch ends at.
+    pub epoch_end_height: u64,
+}
+
+impl SpendingBudgetResponse {
+    pub fn new(limit: SpendingLimit, spent: Option<&SpentAmount>, height: u64) -> Self {
+        let spent = limit.spent_at(spent, height);
+        let remaining = limit.amount.saturating_sub(spent);
+        let epoch_end_height = (limit.epoch(height) + 1).saturating_mul(limit.epoch_length);
+        Self {
+            limit,
+            spent,
+            remaining,
+            epoch_end_height,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 /// Top level type describing a proposal module.
 pub struct ProposalModule {
@@ -114,9 +188,98 @@ pub enum ProposalModuleStatus {
 
 #[cfg(test)]
 mod tests {
-    use super::Config;
+    use super::{Config, SpendingBudgetResponse, SpendingLimit, SpentAmount};
     use crate::error::ContractError;
-    use cosmwasm_std::Addr;
+    use cosmwasm_std::{Addr, Uint128};
+
+    #[test]
+    fn test_spending_limit_validate() {
+        let limit = SpendingLimit {
+            denom: String::from("untrn"),
+            amount: Uint128::new(100),
+            epoch_length: 10,
+        };
+        assert_eq!(limit.validate(), Ok(()));
+
+        let limit_empty_denom = SpendingLimit {
+            denom: String::from(""),
+            ..limit.clone()
+        };
+        assert_eq!(
+            limit_empty_denom.validate(),
+            Err(ContractError::SpendingLimitDenomIsEmpty {})
+        );
+
+        let limit_zero_epoch = SpendingLimit {
+            epoch_length: 0,
+            ..limit
+        };
+        assert_eq!(
+            limit_zero_epoch.validate(),
+            Err(ContractError::ZeroEpochLength {})
+        );
+    }
+
+    #[test]
+    fn test_spending_budget() {
+        let limit = SpendingLimit {
+            denom: String::from("untrn"),
+            amount: Uint128::new(100),
+            epoch_length: 10,
+        };
+        let spent = SpentAmount {
+            epoch: 1,
+            amount: Uint128::new(30),
+        };
+
+        // nothing spent yet
+        assert_eq!(
+            SpendingBudgetResponse::new(limit.clone(), None, 15),
+            SpendingBudgetResponse {
+                limit: limit.clone(),
+                spent: Uint128::zero(),
+                remaining: Uint128::new(100),
+                epoch_end_height: 20,
+            }
+        );
+
+        // spent during the current epoch
+        assert_eq!(
+            SpendingBudgetResponse::new(limit.clone(), Some(&spent), 19),
+            SpendingBudgetResponse {
+                limit: limit.clone(),
+                spent: Uint128::new(30),
+                remaining: Uint128::new(70),
+                epoch_end_height: 20,
+            }
+        );
+
+        // the budget is reset in the next epoch
+        assert_eq!(
+            SpendingBudgetResponse::new(limit.clone(), Some(&spent), 20),
+            SpendingBudgetResponse {
+                limit: limit.clone(),
+                spent: Uint128::zero(),
+                remaining: Uint128::new(100),
+                epoch_end_height: 30,
+            }
+        );
+
+        // the limit was lowered below the spent amount
+        let lowered = SpendingLimit {
+            amount: Uint128::new(10),
+            ..limit
+        };
+        assert_eq!(
+            SpendingBudgetResponse::new(lowered.clone(), Some(&spent), 15),
+            SpendingBudgetResponse {
+                limit: lowered,
+                spent: Uint128::new(30),
+                remaining: Uint128::zero(),
+                epoch_end_height: 20,
+            }
+        );
+    }
 
     #[test]
     fn test_config_validate() {
@@ -64,7 +64,8 @@ pub enum ExecuteMsg {
     Vote {
         /// The ID of the proposal to vote on.
         proposal_id: u64,
-        /// The senders position on the proposal.
+        /// The senders position on the proposal. `no_with_veto` is
+        /// not supported.
         vote: Vote,
     },
     /// Causes the messages associated with a passed proposal to be
@@ -130,6 +131,10 @@ pub enum ExecuteMsg {
     AddVoteHook { address: String },
     /// Removed a consumer of vote hooks.
     RemoveVoteHook { address: String },
+    /// Marks an executed proposal as expired. Called by the timelock
+    /// module when the proposal was not executed before its execution
+    /// deadline, or was cancelled by the subDAO's emergency recovery.
+    TimelockedProposalExpired { proposal_id: u64 },
 }
 
 #[proposal_module_query]
@@ -94,7 +94,10 @@ impl SingleChoiceProposal {
                 let options = self.total_power - self.votes.abstain;
                 does_vote_count_pass(self.votes.yes, options, percentage)
             }
-            Threshold::ThresholdQuorum { threshold, quorum } => {
+            Threshold::ThresholdQuorum { threshold, quorum }
+            | Threshold::DynamicQuorum {
+                threshold, quorum, ..
+            } => {
                 if !does_vote_count_pass(self.votes.total(), self.total_power, quorum) {
                     return false;
                 }
@@ -152,7 +155,10 @@ impl SingleChoiceProposal {
 
                 does_vote_count_fail(self.votes.no, options, percentage_needed)
             }
-            Threshold::ThresholdQuorum { threshold, quorum } => {
+            Threshold::ThresholdQuorum { threshold, quorum }
+            | Threshold::DynamicQuorum {
+                threshold, quorum, ..
+            } => {
                 match (
                     does_vote_count_pass(self.votes.total(), self.total_power, quorum),
                     self.expiration.is_expired(block),
@@ -316,6 +322,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(4),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // 15 total votes. 7 yes and 2 abstain. Majority threshold. This
@@ -358,6 +365,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(4),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // Does not pass if min voting period is not expired.
@@ -399,6 +407,7 @@ mod test {
             yes: Uint128::new(4),
             no: Uint128::new(7),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // Proposal has not passed.
@@ -433,6 +442,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(4),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // 15 total votes. 7 yes and 2 abstain. Majority threshold. This
@@ -468,6 +478,7 @@ mod test {
             yes: Uint128::new(4),
             no: Uint128::new(7),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // Not expired, revoting allowed => no rejection.
@@ -505,6 +516,7 @@ mod test {
                 yes: Uint128::new(10),
                 no: Uint128::zero(),
                 abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(100),
             false,
@@ -517,7 +529,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(10),
             false,
@@ -530,7 +543,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(11),
             false,
@@ -543,7 +557,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(11),
             false,
@@ -566,6 +581,7 @@ mod test {
                 yes: Uint128::new(10),
                 no: Uint128::zero(),
                 abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(100),
             false,
@@ -578,6 +594,7 @@ mod test {
                 yes: Uint128::new(10),
                 no: Uint128::zero(),
                 abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(100),
             true,
@@ -590,7 +607,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(10),
             false,
@@ -602,7 +620,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(10),
             true,
@@ -620,6 +639,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(6),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         };
         assert!(check_is_passed(
             threshold,
@@ -640,6 +660,7 @@ mod test {
             yes: Uint128::new(6),
             no: Uint128::new(7),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         };
         assert!(check_is_passed(
             threshold.clone(),
@@ -668,6 +689,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(6),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         };
         assert!(check_is_passed(
             threshold.clone(),
@@ -698,6 +720,7 @@ mod test {
             yes: Uint128::new(4),
             no: Uint128::new(7),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // 15 total voting power
@@ -769,18 +792,21 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(3),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
         // abstain votes are not counted for threshold => yes / (yes + no + veto)
         let passes_ignoring_abstain = Votes {
             yes: Uint128::new(6),
             no: Uint128::new(6),
             abstain: Uint128::new(5),
+            no_with_veto: Uint128::zero(),
         };
         // fails any way you look at it
         let failing = Votes {
             yes: Uint128::new(6),
             no: Uint128::new(7),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // first, expired (voting period over)
@@ -881,18 +907,21 @@ mod test {
             yes: Uint128::new(3),
             no: Uint128::new(8),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
         // abstain votes are not counted for threshold => yes / (yes + no)
         let rejected_ignoring_abstain = Votes {
             yes: Uint128::new(4),
             no: Uint128::new(8),
             abstain: Uint128::new(5),
+            no_with_veto: Uint128::zero(),
         };
         // fails any way you look at it
         let failing = Votes {
             yes: Uint128::new(5),
             no: Uint128::new(8),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // first, expired (voting period over)
@@ -1014,6 +1043,7 @@ mod test {
             yes: Uint128::new(9),
             no: Uint128::new(1),
             abstain: Uint128::new(0),
+            no_with_veto: Uint128::zero(),
         };
         assert!(!check_is_passed(
             quorum.clone(),
@@ -1037,6 +1067,7 @@ mod test {
             yes: Uint128::new(8),
             no: Uint128::new(4),
             abstain: Uint128::new(0),
+            no_with_veto: Uint128::zero(),
         };
         assert!(!check_is_passed(
             quorum.clone(),
@@ -1060,6 +1091,7 @@ mod test {
             yes: Uint128::new(9),
             no: Uint128::new(3),
             abstain: Uint128::new(0),
+            no_with_veto: Uint128::zero(),
         };
         assert!(check_is_passed(
             quorum.clone(),
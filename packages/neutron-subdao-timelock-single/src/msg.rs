@@ -1,5 +1,6 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::CosmosMsg;
+use cosmwasm_std::{Coin, CosmosMsg};
+use cw_utils::Duration;
 use neutron_sdk::bindings::msg::NeutronMsg;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -8,6 +9,10 @@ use serde::{Deserialize, Serialize};
 pub struct InstantiateMsg {
     // Overrule pre proposal module from the main DAO
     pub overrule_pre_propose: String,
+    // Minimum amount of time a proposal stays timelocked, regardless of the overrule outcome
+    pub min_timelock_duration: Option<Duration>,
+    // Amount of time after timelocking during which a proposal can be executed
+    pub execution_deadline: Option<Duration>,
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
@@ -20,13 +25,39 @@ pub enum ExecuteMsg {
     ExecuteProposal {
         proposal_id: u64,
     },
+    /// Callable by the owner only. Moves a timelocked proposal to the `Overruled`
+    /// status. A proposal that is no longer timelocked is left as it is, so that a
+    /// batch overrule proposal still overrules its other members.
     OverruleProposal {
         proposal_id: u64,
     },
+    /// Re-executes only the messages of a proposal whose execution failed,
+    /// as long as its execution deadline has not passed.
+    RetryFailedMessages {
+        proposal_id: u64,
+    },
+    /// Sets the bounty paid to whoever executes a matured proposal. Can be called
+    /// by the owner or the subDAO. A zero amount or `None` disables the bounty.
+    UpdateExecutionBounty {
+        execution_bounty: Option<Coin>,
+    },
     UpdateConfig {
         owner: Option<String>,
         overrule_pre_propose: Option<String>,
+        /// Sets the minimum timelock duration. A zero duration disables it.
+        min_timelock_duration: Option<Duration>,
+        /// Sets the execution deadline. A zero duration disables it.
+        execution_deadline: Option<Duration>,
+    },
+    /// Moves a timelocked proposal whose execution deadline has passed
+    /// to the `Expired` status and notifies the subDAO proposal module.
+    ExpireProposal {
+        proposal_id: u64,
     },
+    /// Moves all timelocked proposals to the `Overruled` status and notifies the
+    /// subDAO proposal module. Can be called by the subDAO only, as a part of its
+    /// emergency recovery.
+    CancelTimelockedProposals {},
 }
 
 #[cw_serde]
@@ -53,12 +84,56 @@ pub enum QueryMsg {
         /// query. If no limit is set a max of 30 proposals will be
         /// returned.
         limit: Option<u64>,
+        /// Only return proposals with this status.
+        status: Option<crate::types::ProposalStatus>,
+    },
+    /// Lists all of the proposals that have been cast in this module
+    /// in descending order of proposal ID. Returns
+    /// `query::ProposalListResponse`.
+    #[returns(crate::types::ProposalListResponse)]
+    ReverseProposals {
+        /// The proposal ID to start listing proposals before. For
+        /// example, if this is set to 6 proposals with IDs 5 and
+        /// lower will be returned.
+        start_before: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+        /// Only return proposals with this status.
+        status: Option<crate::types::ProposalStatus>,
+    },
+    /// Lists the proposals that can be executed right now: the overrule
+    /// proposal was declined, the minimum timelock duration has elapsed,
+    /// the execution deadline has not passed, and the proposal is still timelocked. Returns `query::ProposalListResponse`.
+    #[returns(crate::types::ProposalListResponse)]
+    ExecutableProposals {
+        /// The proposal ID to start listing proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of timelocked proposals to look at as part
+        /// of this query, only the executable ones are returned. If no limit
+        /// is set a max of 30 proposals will be looked at. Use `last_scanned`
+        /// of the response to continue.
+        limit: Option<u64>,
     },
     /// Returns errors of the failed proposal.
     /// Expected in the form of "codespace=? code=?".
     /// Returns `Option<String>`
     #[returns(Option<String>)]
     ProposalExecutionError { proposal_id: u64 },
+    /// Returns every message of the proposal together with its
+    /// execution status and error. Returns `types::ProposalMessagesResponse`.
+    #[returns(crate::types::ProposalMessagesResponse)]
+    ProposalMessages { proposal_id: u64 },
+    /// Returns the execution bounty and the contract balance it is paid from.
+    #[returns(crate::types::BountyBalanceResponse)]
+    BountyBalance {},
+    /// Returns the number of proposals, optionally only the ones with
+    /// the given status. Returns `u64`.
+    #[returns(u64)]
+    ProposalCount {
+        status: Option<crate::types::ProposalStatus>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
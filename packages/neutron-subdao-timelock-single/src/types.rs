@@ -1,5 +1,7 @@
 use cosmwasm_std::Addr;
+use cosmwasm_std::Coin;
 use cosmwasm_std::CosmosMsg;
+use cw_utils::{Duration, Expiration};
 use neutron_sdk::bindings::msg::NeutronMsg;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -10,6 +12,18 @@ pub struct Config {
     pub overrule_pre_propose: Addr,
     // subDAO core module can timelock proposals.
     pub subdao: Addr,
+    /// Minimum amount of time (in blocks or seconds) a proposal stays timelocked,
+    /// regardless of the outcome of its overrule proposal.
+    #[serde(default)]
+    pub min_timelock_duration: Option<Duration>,
+    /// Bounty paid from the contract balance to whoever executes a matured proposal.
+    /// The balance is expected to be funded by the subDAO.
+    #[serde(default)]
+    pub execution_bounty: Option<Coin>,
+    /// Amount of time (in blocks or seconds) after timelocking during which a proposal
+    /// can be executed. Once it passes, the proposal can only be expired.
+    #[serde(default)]
+    pub execution_deadline: Option<Duration>,
 }
 
 #[derive(Serialize, Deserialize, Clone, JsonSchema, Debug, Eq, PartialEq)]
@@ -21,6 +35,23 @@ pub struct SingleChoiceProposal {
     pub msgs: Vec<CosmosMsg<NeutronMsg>>,
 
     pub status: ProposalStatus,
+
+    /// The proposal can not be executed before this moment, even if its
+    /// overrule proposal has already been declined. `None` if no minimum
+    /// timelock duration was configured when the proposal was timelocked.
+    #[serde(default)]
+    pub timelocked_until: Option<Expiration>,
+
+    /// Execution status of every message wrapped into `ExecuteTimelockedMsgs`,
+    /// in the order they were proposed.
+    #[serde(default)]
+    pub msg_statuses: Vec<MessageStatus>,
+
+    /// The proposal can not be executed after this moment and can only be
+    /// expired. `None` if no execution deadline was configured when the
+    /// proposal was timelocked.
+    #[serde(default)]
+    pub execution_deadline: Option<Expiration>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema, Debug, Copy)]
@@ -35,6 +66,8 @@ pub enum ProposalStatus {
     Executed,
     /// The proposal's execution failed.
     ExecutionFailed,
+    /// The proposal has not been executed before its execution deadline.
+    Expired,
 }
 
 impl std::fmt::Display for ProposalStatus {
@@ -44,12 +77,65 @@ impl std::fmt::Display for ProposalStatus {
             ProposalStatus::Overruled => write!(f, "overruled"),
             ProposalStatus::Executed => write!(f, "executed"),
             ProposalStatus::ExecutionFailed => write!(f, "execution_failed"),
+            ProposalStatus::Expired => write!(f, "expired"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema, Debug, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageStatus {
+    /// The message has not been executed yet.
+    Pending,
+    /// The message has been executed.
+    Executed,
+    /// The message's execution failed.
+    ExecutionFailed,
+}
+
+impl std::fmt::Display for MessageStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MessageStatus::Pending => write!(f, "pending"),
+            MessageStatus::Executed => write!(f, "executed"),
+            MessageStatus::ExecutionFailed => write!(f, "execution_failed"),
         }
     }
 }
 
-/// A list of proposals returned by `ListProposals`.
+/// A single message of a timelocked proposal returned by `ProposalMessages`.
+#[derive(Serialize, Deserialize, Clone, JsonSchema, Debug, Eq, PartialEq)]
+pub struct ProposalMessage {
+    /// Position of the message inside `ExecuteTimelockedMsgs`.
+    pub index: u32,
+    pub msg: CosmosMsg<NeutronMsg>,
+    pub status: MessageStatus,
+    /// Error of the last failed execution, in the form of "codespace=? code=?".
+    pub error: Option<String>,
+}
+
+/// A list of proposal messages returned by `ProposalMessages`.
+#[derive(Serialize, Deserialize, Clone, JsonSchema, Debug)]
+pub struct ProposalMessagesResponse {
+    pub messages: Vec<ProposalMessage>,
+}
+
+/// The execution bounty and the contract balance it is paid from, returned by `BountyBalance`.
+#[derive(Serialize, Deserialize, Clone, JsonSchema, Debug, Eq, PartialEq)]
+pub struct BountyBalanceResponse {
+    pub execution_bounty: Option<Coin>,
+    /// Balance of the bounty denom, `None` if no bounty is configured.
+    pub balance: Option<Coin>,
+}
+
+/// A list of proposals returned by `ListProposals`, `ReverseProposals` and
+/// `ExecutableProposals`.
 #[derive(Serialize, Deserialize, Clone, JsonSchema, Debug)]
 pub struct ProposalListResponse {
     pub proposals: Vec<SingleChoiceProposal>,
+    /// The id of the last proposal looked at, to be passed as `start_after`
+    /// (`start_before` for `ReverseProposals`) to get the next page. `None` if
+    /// the page isn't full, as there are no more proposals to look at then.
+    #[serde(default)]
+    pub last_scanned: Option<u64>,
 }